@@ -0,0 +1,28 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{breaker, Error, MilestoneFund, Project};
+
+// --- Access Control ---
+// Every state-changing entrypoint reserved to a role opens with the same
+// guards: the circuit breaker must not be tripped, and the role holder
+// must have signed. These helpers bundle the two, so an entrypoint names
+// its role in one line and no role check can drift away from the breaker
+// check. Pausing lives in `emergency`, entry lifetimes in `ttl`.
+
+/// Fails with `ReadOnlyMode` while the breaker is tripped, then requires
+/// the admin's authorization. Returns the admin.
+pub(crate) fn require_admin(env: &Env) -> Result<Address, Error> {
+    breaker::ensure_writable(env)?;
+    let admin = MilestoneFund::get_admin(env.clone())?;
+    admin.require_auth();
+    Ok(admin)
+}
+
+/// Fails with `ReadOnlyMode` while the breaker is tripped, then requires
+/// the creator's authorization. Returns the project.
+pub(crate) fn require_creator(env: &Env) -> Result<Project, Error> {
+    breaker::ensure_writable(env)?;
+    let project = MilestoneFund::get_project(env)?;
+    project.creator.require_auth();
+    Ok(project)
+}
@@ -0,0 +1,59 @@
+use soroban_sdk::{contractimpl, Address, Env, IntoVal, Val, Vec};
+
+use crate::{ttl, DataKey, Error, MilestoneFund, MilestoneFundClient};
+
+// --- Call-Bound Authorization ---
+// Calls that move value are authorized over `(project, amount, nonce)`
+// instead of the bare invocation, where the project is this contract's
+// address and the nonce is a per-address counter consumed by every bound
+// call. A signature collected for one contribution therefore cannot be
+// replayed by a malicious frontend against another project, for another
+// amount, or a second time. Calls whose effect depends on more than the
+// amount (a target campaign, a token, a slippage floor) sign all of those
+// between the project and the nonce.
+
+fn nonce(env: &Env, address: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AuthNonce(address.clone()))
+        .unwrap_or(0)
+}
+
+/// Requires `address` to authorize moving `amount` in this project at its
+/// current nonce, then consumes the nonce.
+pub(crate) fn require_auth_for_amount<T: IntoVal<Env, Val>>(
+    env: &Env,
+    address: &Address,
+    amount: T,
+) {
+    require_auth_for_call(env, address, Vec::from_array(env, [amount.into_val(env)]));
+}
+
+/// Requires `address` to authorize `args`, the arguments the call's effect
+/// depends on, in this project at its current nonce, then consumes the
+/// nonce.
+pub(crate) fn require_auth_for_call(env: &Env, address: &Address, args: Vec<Val>) {
+    let nonce = nonce(env, address);
+    let mut signed = Vec::from_array(env, [env.current_contract_address().into_val(env)]);
+    signed.append(&args);
+    signed.push_back(nonce.into_val(env));
+    address.require_auth_for_args(signed);
+    ttl::set_persistent(env, &DataKey::AuthNonce(address.clone()), &(nonce + 1));
+}
+
+/// Consumes `nonce` for `address`, failing unless it is the current one.
+pub(crate) fn consume_nonce(env: &Env, address: &Address, nonce: u64) -> Result<(), Error> {
+    if nonce != self::nonce(env, address) {
+        return Err(Error::InvalidArgument);
+    }
+    ttl::set_persistent(env, &DataKey::AuthNonce(address.clone()), &(nonce + 1));
+    Ok(())
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (View) Gets the nonce `address` must sign its next value-moving call with.
+    pub fn get_auth_nonce(env: Env, address: Address) -> u64 {
+        nonce(&env, &address)
+    }
+}
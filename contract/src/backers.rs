@@ -0,0 +1,114 @@
+use soroban_sdk::{contractimpl, Address, Env, Map, Vec};
+
+use crate::{ttl, upgrade, DataKey, MilestoneFund, MilestoneFundClient, StorageKey};
+
+// --- Backer Ledger ---
+// Each backer's contribution lives in its own persistent entry, so funding
+// touches one small entry no matter how many backers a campaign has. The
+// subsystems that walk every backer (tallies, raffles, exports, ...) go
+// through a paged index of addresses in first-contribution order; only
+// the last page is rewritten when a backer joins. Every write also pushes
+// the entry's TTL out, so a backer who stays active never has their
+// balance archived, however long the campaign runs.
+
+/// Addresses per index page.
+const PAGE_SIZE: u32 = 100;
+
+fn page(env: &Env, index: u32) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::BackerPage(index))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Contribution booked for `backer`, or `None` if they never contributed.
+pub(crate) fn contribution(env: &Env, backer: &Address) -> Option<u128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Backer(backer.clone()))
+}
+
+/// Contribution booked for `backer`, zero if they never contributed.
+pub(crate) fn amount(env: &Env, backer: &Address) -> u128 {
+    contribution(env, backer).unwrap_or(0)
+}
+
+pub(crate) fn is_backer(env: &Env, backer: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Backer(backer.clone()))
+}
+
+/// Number of backers.
+pub(crate) fn count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&StorageKey::BackerCount)
+        .unwrap_or(0)
+}
+
+/// Books `amount` as `backer`'s contribution, indexing new backers.
+pub(crate) fn set(env: &Env, backer: &Address, amount: u128) {
+    upgrade::snapshot_weight(env, backer, self::amount(env, backer));
+    if !is_backer(env, backer) {
+        let count = count(env);
+        let mut last = page(env, count / PAGE_SIZE);
+        last.push_back(backer.clone());
+        let key = StorageKey::BackerPage(count / PAGE_SIZE);
+        ttl::set_persistent(env, &key, &last);
+        env.storage()
+            .instance()
+            .set(&StorageKey::BackerCount, &(count + 1));
+    }
+    let key = DataKey::Backer(backer.clone());
+    ttl::set_persistent(env, &key, &amount);
+}
+
+/// Pushes the TTL of every index page out.
+pub(crate) fn extend_index(env: &Env) {
+    for index in 0..count(env).div_ceil(PAGE_SIZE) {
+        ttl::extend_persistent(env, &StorageKey::BackerPage(index));
+    }
+}
+
+/// Every backer, in first-contribution order.
+pub(crate) fn all(env: &Env) -> Vec<Address> {
+    let mut backers = Vec::new(env);
+    for index in 0..count(env).div_ceil(PAGE_SIZE) {
+        backers.append(&page(env, index));
+    }
+    backers
+}
+
+/// Backers at positions `start..end` of the index, reading only the pages
+/// that hold them.
+pub(crate) fn range(env: &Env, start: u32, end: u32) -> Vec<Address> {
+    let mut backers = Vec::new(env);
+    let mut position = start;
+    while position < end {
+        let addresses = page(env, position / PAGE_SIZE);
+        let offset = position % PAGE_SIZE;
+        let taken = (end - position).min(PAGE_SIZE - offset);
+        backers.append(&addresses.slice(offset..offset + taken));
+        position += taken;
+    }
+    backers
+}
+
+/// Every backer with their contribution.
+pub(crate) fn contributions(env: &Env) -> Map<Address, u128> {
+    let mut contributions = Map::new(env);
+    for backer in all(env).iter() {
+        let amount = amount(env, &backer);
+        contributions.set(backer, amount);
+    }
+    contributions
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (View) Gets the number of distinct backers.
+    pub fn get_backer_count(env: Env) -> u32 {
+        count(&env)
+    }
+}
@@ -0,0 +1,76 @@
+use soroban_sdk::{
+    contractimpl, contracttype, symbol_short, xdr::ToXdr, Address, Bytes, BytesN, Env, Vec,
+};
+
+use crate::{auth, breaker, cast_vote, events, Error, MilestoneFund, MilestoneFundClient};
+
+// --- Signed Ballots ---
+// Large communities can vote without every backer paying for a
+// transaction: backers sign ballots off-chain with their account's ed25519
+// key and anyone relays them in batches. A ballot signs the XDR of
+// `("ballot", contract, milestone_index, approve, nonce)`, where the nonce
+// is the backer's call-bound auth nonce (`get_auth_nonce`), so a ballot
+// counts once, for this project only. A bad signature aborts the batch.
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SignedBallot {
+    pub backer: Address, // Must be an account (G...) address
+    pub milestone_index: u32,
+    pub approve: bool,
+    pub nonce: u64,
+    pub signature: BytesN<64>,
+}
+
+/// Bytes a backer signs to cast a ballot.
+fn ballot_message(env: &Env, ballot: &SignedBallot) -> Bytes {
+    (
+        symbol_short!("ballot"),
+        env.current_contract_address(),
+        ballot.milestone_index,
+        ballot.approve,
+        ballot.nonce,
+    )
+        .to_xdr(env)
+}
+
+/// The ed25519 public key behind an account address.
+///
+/// An account address encodes as `ScVal::Address(ScAddress::Account(
+/// PublicKey::Ed25519(key)))`: three 4-byte discriminants, then the key.
+fn account_key(env: &Env, address: &Address) -> Result<BytesN<32>, Error> {
+    let encoded = address.clone().to_xdr(env);
+    if encoded.len() != 44 || encoded.slice(4..12) != Bytes::from_array(env, &[0; 8]) {
+        return Err(Error::InvalidArgument); // Contracts cannot sign ballots
+    }
+    let mut key = [0u8; 32];
+    encoded.slice(12..44).copy_into_slice(&mut key);
+    Ok(BytesN::from_array(env, &key))
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// Verifies and tallies a batch of off-chain signed ballots. Anyone
+    /// can submit. Returns how many ballots were counted.
+    pub fn submit_ballots(env: Env, ballots: Vec<SignedBallot>) -> Result<u32, Error> {
+        breaker::ensure_writable(&env)?;
+        let project = Self::get_project(&env)?;
+
+        for ballot in ballots.iter() {
+            let key = account_key(&env, &ballot.backer)?;
+            env.crypto()
+                .ed25519_verify(&key, &ballot_message(&env, &ballot), &ballot.signature);
+            auth::consume_nonce(&env, &ballot.backer, ballot.nonce)?;
+            cast_vote(
+                &env,
+                &project,
+                &ballot.backer,
+                ballot.milestone_index,
+                ballot.approve,
+            )?;
+        }
+
+        events::publish(&env, (symbol_short!("ballots"),), ballots.len());
+        Ok(ballots.len())
+    }
+}
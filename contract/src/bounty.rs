@@ -0,0 +1,157 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env, Map};
+
+use crate::{
+    auth, backers, breaker, collect, events, evidence, pay_out, ttl, DataKey, Error, MilestoneFund,
+    MilestoneFundClient, Project,
+};
+
+// --- Milestone Bounties ---
+// Backers can put extra tokens on a milestone whose evidence deadline is
+// set, held apart from the campaign's own escrow. If the milestone is
+// released by that deadline, whole, downscoped, in another asset or by a
+// stream's first installment, the whole bounty goes to the creator with the
+// release; otherwise each contributor can take their part back once the
+// deadline has passed.
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MilestoneBounty {
+    pub contributions: Map<Address, u128>, // Contributor -> amount still held
+    pub total: u128,
+    pub paid: bool, // Paid to the creator with a timely release
+}
+
+fn load(env: &Env, milestone_index: u32) -> Option<MilestoneBounty> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Bounty(milestone_index))
+}
+
+fn save(env: &Env, milestone_index: u32, bounty: &MilestoneBounty) {
+    ttl::set_persistent(env, &DataKey::Bounty(milestone_index), bounty);
+}
+
+/// Bounty tokens the contract holds across all milestones.
+pub(crate) fn held(env: &Env, project: &Project) -> Result<u128, Error> {
+    let mut total: u128 = 0;
+    for index in 0..project.milestones.len() {
+        if let Some(bounty) = load(env, index) {
+            if !bounty.paid {
+                total = total.checked_add(bounty.total).ok_or(Error::Overflow)?;
+            }
+        }
+    }
+    Ok(total)
+}
+
+/// Pays a milestone's bounty to the creator if it was released on time.
+/// Called right after the release is persisted, by every release path (a
+/// stream settles it with its first installment paid by the deadline).
+pub(crate) fn settle(env: &Env, project: &Project, milestone_index: u32) -> Result<(), Error> {
+    let Some(mut bounty) = load(env, milestone_index) else {
+        return Ok(());
+    };
+    let Some(deadline) = evidence::load(env, milestone_index).deadline else {
+        return Ok(());
+    };
+    if bounty.paid || bounty.total == 0 || u64::from(env.ledger().sequence()) > deadline {
+        return Ok(());
+    }
+    bounty.paid = true;
+    save(env, milestone_index, &bounty);
+    pay_out(env, &project.token, &project.creator, bounty.total)?;
+
+    events::publish(
+        env,
+        (symbol_short!("bounty"), milestone_index),
+        bounty.total,
+    );
+    Ok(())
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Backer) Adds `amount` to the bounty on an open milestone, payable
+    /// to the creator if it is released by its evidence deadline.
+    pub fn attach_bounty(
+        env: Env,
+        backer: Address,
+        milestone_index: u32,
+        amount: u128,
+    ) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        let project = Self::get_project(&env)?;
+        auth::require_auth_for_amount(&env, &backer, amount);
+        if !backers::is_backer(&env, &backer) {
+            return Err(Error::NotABacker);
+        }
+        let milestone = project
+            .milestones
+            .get(milestone_index)
+            .ok_or(Error::MilestoneInvalidIndex)?;
+        if milestone.is_complete {
+            return Err(Error::MilestoneAlreadyCompleted);
+        }
+        let deadline = evidence::load(&env, milestone_index)
+            .deadline
+            .ok_or(Error::NotFound)?;
+        if u64::from(env.ledger().sequence()) > deadline {
+            return Err(Error::DeadlinePassed);
+        }
+        if amount == 0 {
+            return Err(Error::InvalidArgument);
+        }
+
+        collect(&env, &project.token, &backer, amount)?;
+        let mut bounty = load(&env, milestone_index).unwrap_or(MilestoneBounty {
+            contributions: Map::new(&env),
+            total: 0,
+            paid: false,
+        });
+        let current = bounty.contributions.get(backer.clone()).unwrap_or(0);
+        let contributed = current.checked_add(amount).ok_or(Error::Overflow)?;
+        bounty.contributions.set(backer.clone(), contributed);
+        bounty.total = bounty.total.checked_add(amount).ok_or(Error::Overflow)?;
+        save(&env, milestone_index, &bounty);
+
+        events::publish(
+            &env,
+            (symbol_short!("bounty"), milestone_index, backer),
+            amount,
+        );
+        Ok(())
+    }
+
+    /// (Backer) Takes back a bounty contribution once the milestone's
+    /// deadline has passed without a timely release.
+    pub fn reclaim_bounty(env: Env, backer: Address, milestone_index: u32) -> Result<u128, Error> {
+        breaker::ensure_writable(&env)?;
+        backer.require_auth();
+        let project = Self::get_project(&env)?;
+        let mut bounty = load(&env, milestone_index).ok_or(Error::NotFound)?;
+        if bounty.paid {
+            return Err(Error::NotEligible);
+        }
+        let deadline = evidence::load(&env, milestone_index)
+            .deadline
+            .ok_or(Error::NotFound)?;
+        if u64::from(env.ledger().sequence()) <= deadline {
+            return Err(Error::NotDue);
+        }
+        let amount = bounty.contributions.get(backer.clone()).unwrap_or(0);
+        if amount == 0 {
+            return Err(Error::NoRefundsToClaim);
+        }
+
+        bounty.contributions.remove(backer.clone());
+        bounty.total -= amount;
+        save(&env, milestone_index, &bounty);
+        pay_out(&env, &project.token, &backer, amount)?;
+        Ok(amount)
+    }
+
+    /// (View) Gets the bounty attached to a milestone.
+    pub fn get_bounty(env: Env, milestone_index: u32) -> Result<MilestoneBounty, Error> {
+        load(&env, milestone_index).ok_or(Error::NotFound)
+    }
+}
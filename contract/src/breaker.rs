@@ -0,0 +1,286 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, token, Address, Env, Vec};
+
+use crate::{
+    escrow, events, migration, save_project, ttl, upgrade, DataKey, Error, MilestoneFund,
+    MilestoneFundClient, Project, StorageKey,
+};
+
+// --- Circuit Breaker ---
+// Before any outbound transfer the contract compares its real token balance
+// with what its books say it should hold. A shortfall beyond the tolerance
+// (clawback, a buggy upgrade, ...) trips the breaker, which puts the whole
+// contract in read-only mode: every entrypoint but the views and the
+// reconciliation calls fails with `ReadOnlyMode` until the balance has been
+// reconciled. A surplus is harmless (anyone can send tokens to the
+// contract) and never trips it.
+//
+// The admin can lift the breaker alone once the balance has been topped
+// up. Correcting the books instead goes through `reconcile`, which needs
+// the approval of `threshold` of the reconcilers the admin has appointed.
+
+// A correction of one bucket of the books, signed
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AccountingAdjustment {
+    Surplus(i128),
+    RefundPool(i128),
+    Milestone(u32, i128), // Milestone index, change to its escrow allocation
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Reconcilers {
+    pub signers: Vec<Address>,
+    pub threshold: u32,
+}
+
+/// Tokens the contract should hold according to its internal accounting.
+pub(crate) fn expected_balance(env: &Env, project: &Project) -> Result<u128, Error> {
+    escrow::breakdown(env, project)?.total()
+}
+
+fn actual_balance(env: &Env, project: &Project) -> u128 {
+    let balance = token::Client::new(env, &project.token).balance(&env.current_contract_address());
+    u128::try_from(balance).unwrap_or(0)
+}
+
+pub(crate) fn tolerance(env: &Env) -> u128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::BreakerTolerance)
+        .unwrap_or(0)
+}
+
+pub(crate) fn is_tripped(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::BreakerTripped)
+        .unwrap_or(false)
+}
+
+/// Fails with `ReadOnlyMode` while the breaker is tripped, and keeps the
+/// instance alive, whatever layout it is stored in.
+pub(crate) fn ensure_live(env: &Env) -> Result<(), Error> {
+    if is_tripped(env) {
+        return Err(Error::ReadOnlyMode);
+    }
+    ttl::extend_instance(env);
+    Ok(())
+}
+
+/// Fails with `ReadOnlyMode` while the breaker is tripped and with
+/// `SchemaMismatch` while the stored layout awaits a migration. Every
+/// state-changing entrypoint opens with this check, so it also keeps the
+/// instance alive.
+pub(crate) fn ensure_writable(env: &Env) -> Result<(), Error> {
+    ensure_live(env)?;
+    upgrade::ensure_current(env)
+}
+
+/// The appointed reconcilers, or the admin alone when none are.
+fn reconcilers(env: &Env) -> Result<Reconcilers, Error> {
+    if let Some(reconcilers) = env.storage().instance().get(&StorageKey::Reconcilers) {
+        return Ok(reconcilers);
+    }
+    Ok(Reconcilers {
+        signers: Vec::from_array(env, [MilestoneFund::get_admin(env.clone())?]),
+        threshold: 1,
+    })
+}
+
+/// Requires `approvers` to be distinct reconcilers, all signing, and at
+/// least as many as the threshold.
+pub(crate) fn require_reconcilers(env: &Env, approvers: &Vec<Address>) -> Result<(), Error> {
+    let reconcilers = reconcilers(env)?;
+    for (index, approver) in approvers.iter().enumerate() {
+        if !reconcilers.signers.contains(&approver)
+            || approvers.first_index_of(&approver) != Some(index as u32)
+        {
+            return Err(Error::InvalidArgument);
+        }
+        approver.require_auth();
+    }
+    if approvers.len() < reconcilers.threshold {
+        return Err(Error::NotApproved);
+    }
+    Ok(())
+}
+
+fn adjust(value: u128, delta: i128) -> Result<u128, Error> {
+    let adjusted = if delta < 0 {
+        value.checked_sub(delta.unsigned_abs())
+    } else {
+        value.checked_add(delta as u128)
+    };
+    adjusted.ok_or(Error::InvalidArgument)
+}
+
+fn apply_adjustment(project: &mut Project, adjustment: &AccountingAdjustment) -> Result<(), Error> {
+    match adjustment {
+        AccountingAdjustment::Surplus(delta) => project.surplus = adjust(project.surplus, *delta)?,
+        AccountingAdjustment::RefundPool(delta) => {
+            project.refund_pool = adjust(project.refund_pool, *delta)?;
+            if project.refund_pool < project.refund_pool_claimed {
+                return Err(Error::InvalidArgument);
+            }
+        }
+        AccountingAdjustment::Milestone(index, delta) => {
+            let mut milestone = project
+                .milestones
+                .get(*index)
+                .ok_or(Error::MilestoneInvalidIndex)?;
+            milestone.escrow.allocated = adjust(milestone.escrow.allocated, *delta)?;
+            project.milestones.set(*index, milestone);
+        }
+    }
+    Ok(())
+}
+
+fn shortfall(env: &Env, project: &Project) -> Result<Option<(u128, u128)>, Error> {
+    let expected = expected_balance(env, project)?;
+    let actual = actual_balance(env, project);
+    if falls_short(actual, tolerance(env), expected)? {
+        Ok(Some((expected, actual)))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Whether a balance of `actual` is more than `tolerance` below `expected`.
+fn falls_short(actual: u128, tolerance: u128, expected: u128) -> Result<bool, Error> {
+    Ok(actual.checked_add(tolerance).ok_or(Error::Overflow)? < expected)
+}
+
+/// Guards an outbound transfer.
+///
+/// Fails with `CircuitBreakerTripped` if the breaker is already tripped, or
+/// with `Migrated` once the funds have moved to a rescue contract.
+/// Returns `Ok(true)` if this call detected a shortfall and tripped it: the
+/// caller must then return `Ok(())` without moving funds, so the trip is
+/// persisted instead of being rolled back with an error.
+pub(crate) fn trip_on_divergence(env: &Env, project: &Project) -> Result<bool, Error> {
+    migration::ensure_not_migrated(env)?;
+    if is_tripped(env) {
+        return Err(Error::CircuitBreakerTripped);
+    }
+    match shortfall(env, project)? {
+        Some((expected, actual)) => {
+            env.storage()
+                .instance()
+                .set(&DataKey::BreakerTripped, &true);
+            let topics = (symbol_short!("breaker"), symbol_short!("tripped"));
+            events::publish(env, topics, (expected, actual));
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Admin) Sets how far the token balance may fall below the books
+    /// before the circuit breaker trips.
+    pub fn set_breaker_tolerance(env: Env, tolerance: u128) -> Result<(), Error> {
+        Self::get_admin(env.clone())?.require_auth();
+        env.storage()
+            .instance()
+            .set(&DataKey::BreakerTolerance, &tolerance);
+        Ok(())
+    }
+
+    /// (Admin) Re-enables outbound transfers after the balance has been
+    /// reconciled. Fails with `BalanceDiverged` while the shortfall persists.
+    pub fn reset_breaker(env: Env) -> Result<(), Error> {
+        Self::get_admin(env.clone())?.require_auth();
+        let project = Self::get_project(&env)?;
+        if shortfall(&env, &project)?.is_some() {
+            return Err(Error::BalanceDiverged);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::BreakerTripped, &false);
+
+        let topics = (symbol_short!("breaker"), symbol_short!("reset"));
+        events::publish(&env, topics, expected_balance(&env, &project)?);
+        Ok(())
+    }
+
+    /// (Admin) Appoints the reconcilers, `threshold` of whom must approve a
+    /// `reconcile`.
+    pub fn set_reconcilers(env: Env, signers: Vec<Address>, threshold: u32) -> Result<(), Error> {
+        Self::get_admin(env.clone())?.require_auth();
+        if threshold == 0 || threshold > signers.len() {
+            return Err(Error::InvalidArgument);
+        }
+        let reconcilers = Reconcilers { signers, threshold };
+        env.storage()
+            .instance()
+            .set(&StorageKey::Reconcilers, &reconcilers);
+        Ok(())
+    }
+
+    /// (Reconcilers) Corrects the books of a tripped contract by
+    /// `adjustments` and lifts the breaker. Every address in `approvers`
+    /// must be a distinct reconciler and sign, and there must be at least
+    /// `threshold` of them. Fails with `BalanceDiverged` unless the
+    /// corrected books are covered by the balance.
+    pub fn reconcile(
+        env: Env,
+        approvers: Vec<Address>,
+        adjustments: Vec<AccountingAdjustment>,
+    ) -> Result<(), Error> {
+        if !is_tripped(&env) {
+            return Err(Error::NotEligible);
+        }
+        require_reconcilers(&env, &approvers)?;
+
+        let mut project = Self::get_project(&env)?;
+        for adjustment in adjustments.iter() {
+            apply_adjustment(&mut project, &adjustment)?;
+        }
+        if shortfall(&env, &project)?.is_some() {
+            return Err(Error::BalanceDiverged);
+        }
+        save_project(&env, &project);
+        env.storage()
+            .instance()
+            .set(&DataKey::BreakerTripped, &false);
+
+        let topics = (symbol_short!("breaker"), symbol_short!("reconcile"));
+        events::publish(
+            &env,
+            topics,
+            (adjustments, expected_balance(&env, &project)?),
+        );
+        Ok(())
+    }
+
+    /// (View) Checks whether outbound transfers are halted by the breaker.
+    pub fn is_breaker_tripped(env: Env) -> bool {
+        is_tripped(&env)
+    }
+
+    /// (View) Gets the configured divergence tolerance.
+    pub fn get_breaker_tolerance(env: Env) -> u128 {
+        tolerance(&env)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn falls_short_beyond_the_tolerance() {
+        assert_eq!(falls_short(90, 5, 100), Ok(true));
+        assert_eq!(falls_short(95, 5, 100), Ok(false));
+        assert_eq!(falls_short(u128::MAX - 1, 0, u128::MAX), Ok(true));
+        assert_eq!(falls_short(u128::MAX, 0, u128::MAX), Ok(false));
+    }
+
+    #[test]
+    fn falls_short_overflows_at_max() {
+        assert_eq!(falls_short(u128::MAX, 1, u128::MAX), Err(Error::Overflow));
+        assert_eq!(falls_short(1, u128::MAX, 0), Err(Error::Overflow));
+    }
+}
@@ -0,0 +1,438 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env, IntoVal, Vec};
+
+use crate::{
+    auth, breaker, check_contribution, claims, cleanup, collect, events, factory, pay_out,
+    record_contribution, ttl, DataKey, Error, MilestoneFund, MilestoneFundClient, StateKey,
+};
+
+// --- Institutional Budgets ---
+// An institution deposits a budget up front and allocates from it to the
+// campaign over time, up to a cap it chooses. Allocations are ordinary
+// contributions: the institution becomes a backer with the same voting and
+// refund rights as anyone else. Unallocated budget can be withdrawn at any time.
+//
+// On a factory, an institution keeps one budget per token and allocates
+// from it to the factory's campaigns by number with `allocate_to`, each
+// allocation capped per campaign if the institution chooses. The factory
+// pays the tokens over and the campaign books them as the institution's
+// contribution on its parent's word. `get_platform_statement` pages
+// through the campaigns for a consolidated view of every allocation, its
+// refunds and the campaign's outcome.
+
+/// Most campaigns covered by one `get_platform_statement` page.
+const MAX_STATEMENT_PAGE: u32 = 20;
+
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InstitutionBudget {
+    pub deposited: u128,
+    pub allocated: u128,
+    pub withdrawn: u128,
+    pub cap: Option<u128>, // Upper bound on total allocation to the campaign
+}
+
+impl InstitutionBudget {
+    pub fn available(&self) -> u128 {
+        self.deposited - self.allocated - self.withdrawn
+    }
+}
+
+// Consolidated view of an institution's budget and its campaign outcome
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BudgetStatement {
+    pub budget: InstitutionBudget,
+    pub available: u128,
+    pub refunds_claimed: u128,
+    pub refunds_claimable: u128,
+    pub goal_met: bool,
+    pub milestones_released: u32,
+    pub milestone_count: u32,
+}
+
+// An institution's allocations to one factory campaign
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CampaignAllocation {
+    pub allocated: u128,
+    pub cap: Option<u128>, // Upper bound on total allocation to the campaign
+}
+
+// One campaign's line in an institution's consolidated statement
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CampaignStatement {
+    pub id: u32,
+    pub campaign: Address,
+    pub token: Address,
+    pub allocation: CampaignAllocation,
+    pub refunds_claimed: u128,
+    pub refunds_claimable: u128,
+    pub goal_met: bool,
+    pub milestones_released: u32,
+    pub milestone_count: u32,
+}
+
+// A page of an institution's consolidated statement across campaigns
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlatformStatement {
+    pub campaigns: Vec<CampaignStatement>, // Campaigns the institution allocated to
+    pub next: u32,                         // Campaign number the next page starts at
+    pub done: bool,                        // Whether this page reached the last campaign
+}
+
+pub(crate) fn budgets_held(env: &Env) -> u128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::BudgetsHeld)
+        .unwrap_or(0)
+}
+
+fn adjust_budgets_held(env: &Env, added: u128, removed: u128) {
+    let held = budgets_held(env) + added - removed;
+    env.storage().instance().set(&DataKey::BudgetsHeld, &held);
+}
+
+fn load_budget(env: &Env, institution: &Address) -> InstitutionBudget {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Budget(institution.clone()))
+        .unwrap_or_default()
+}
+
+fn save_budget(env: &Env, institution: &Address, budget: &InstitutionBudget) {
+    ttl::set_persistent(env, &DataKey::Budget(institution.clone()), budget);
+}
+
+fn load_platform_budget(env: &Env, institution: &Address, token: &Address) -> InstitutionBudget {
+    env.storage()
+        .persistent()
+        .get(&StateKey::PlatformBudget(
+            institution.clone(),
+            token.clone(),
+        ))
+        .unwrap_or_default()
+}
+
+fn save_platform_budget(
+    env: &Env,
+    institution: &Address,
+    token: &Address,
+    budget: &InstitutionBudget,
+) {
+    let key = StateKey::PlatformBudget(institution.clone(), token.clone());
+    ttl::set_persistent(env, &key, budget);
+}
+
+fn load_allocation(env: &Env, institution: &Address, id: u32) -> Option<CampaignAllocation> {
+    env.storage()
+        .persistent()
+        .get(&StateKey::CampaignAllocation(institution.clone(), id))
+}
+
+fn save_allocation(env: &Env, institution: &Address, id: u32, allocation: &CampaignAllocation) {
+    let key = StateKey::CampaignAllocation(institution.clone(), id);
+    ttl::set_persistent(env, &key, allocation);
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// Deposits `amount` of the project token into `institution`'s budget.
+    pub fn deposit_budget(env: Env, institution: Address, amount: u128) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        auth::require_auth_for_amount(&env, &institution, amount);
+        let project = Self::get_project(&env)?;
+        if amount == 0 {
+            return Err(Error::FundingAmountTooLow);
+        }
+        collect(&env, &project.token, &institution, amount)?;
+
+        let mut budget = load_budget(&env, &institution);
+        budget.deposited = budget
+            .deposited
+            .checked_add(amount)
+            .ok_or(Error::Overflow)?;
+        save_budget(&env, &institution, &budget);
+        adjust_budgets_held(&env, amount, 0);
+
+        events::publish(&env, (symbol_short!("budget"), institution), amount);
+        Ok(())
+    }
+
+    /// Sets the most `institution` may allocate to the campaign in total.
+    /// `None` removes the cap.
+    pub fn set_allocation_cap(
+        env: Env,
+        institution: Address,
+        cap: Option<u128>,
+    ) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        institution.require_auth();
+        let mut budget = load_budget(&env, &institution);
+        budget.cap = cap;
+        save_budget(&env, &institution, &budget);
+        Ok(())
+    }
+
+    /// Contributes `amount` from `institution`'s budget to the campaign.
+    pub fn allocate(env: Env, institution: Address, amount: u128) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        auth::require_auth_for_amount(&env, &institution, amount);
+        let project = Self::get_project(&env)?;
+        check_contribution(&env, &project, &institution, amount)?;
+
+        let mut budget = load_budget(&env, &institution);
+        if amount > budget.available() {
+            return Err(Error::InsufficientFunds);
+        }
+        if let Some(cap) = budget.cap {
+            if budget.allocated + amount > cap {
+                return Err(Error::CapExceeded);
+            }
+        }
+
+        budget.allocated += amount;
+        save_budget(&env, &institution, &budget);
+        adjust_budgets_held(&env, 0, amount);
+
+        events::publish(
+            &env,
+            (symbol_short!("allocate"), institution.clone()),
+            amount,
+        );
+        record_contribution(&env, project, &institution, amount)
+    }
+
+    /// Returns `amount` of unallocated budget to `institution`.
+    pub fn withdraw_budget(env: Env, institution: Address, amount: u128) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        auth::require_auth_for_amount(&env, &institution, amount);
+        let project = Self::get_project(&env)?;
+        let mut budget = load_budget(&env, &institution);
+        if amount > budget.available() {
+            return Err(Error::InsufficientFunds);
+        }
+
+        if breaker::trip_on_divergence(&env, &project)? {
+            return Ok(());
+        }
+
+        budget.withdrawn += amount;
+        save_budget(&env, &institution, &budget);
+        adjust_budgets_held(&env, 0, amount);
+        pay_out(&env, &project.token, &institution, amount)?;
+
+        events::publish(&env, (symbol_short!("budget_wd"), institution), amount);
+        Ok(())
+    }
+
+    /// (View) Gets `institution`'s budget, refunds and the campaign outcome.
+    pub fn get_statement(env: Env, institution: Address) -> Result<BudgetStatement, Error> {
+        let project = Self::get_project(&env)?;
+        let budget = load_budget(&env, &institution);
+        let milestones_released = project
+            .milestones
+            .iter()
+            .filter(|milestone| milestone.is_complete)
+            .count() as u32;
+
+        Ok(BudgetStatement {
+            available: budget.available(),
+            budget,
+            refunds_claimed: claims::claimed(&env, &institution),
+            refunds_claimable: claims::claimable(&env, &project, &institution)?,
+            goal_met: project.goal_met,
+            milestones_released,
+            milestone_count: project.milestones.len(),
+        })
+    }
+
+    /// (Factory) Deposits `amount` of `token` into `institution`'s budget
+    /// for the factory's campaigns.
+    pub fn deposit_platform_budget(
+        env: Env,
+        institution: Address,
+        token: Address,
+        amount: u128,
+    ) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        auth::require_auth_for_call(&env, &institution, (token.clone(), amount).into_val(&env));
+        if !factory::is_factory(&env) {
+            return Err(Error::FeatureDisabled);
+        }
+        if amount == 0 {
+            return Err(Error::FundingAmountTooLow);
+        }
+        collect(&env, &token, &institution, amount)?;
+
+        let mut budget = load_platform_budget(&env, &institution, &token);
+        budget.deposited = budget
+            .deposited
+            .checked_add(amount)
+            .ok_or(Error::AmountTooLarge)?;
+        save_platform_budget(&env, &institution, &token, &budget);
+
+        events::publish(&env, (symbol_short!("budget"), institution, token), amount);
+        Ok(())
+    }
+
+    /// (Factory) Sets the most `institution` may allocate to campaign
+    /// number `id` in total. `None` removes the cap.
+    pub fn set_campaign_cap(
+        env: Env,
+        institution: Address,
+        id: u32,
+        cap: Option<u128>,
+    ) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        institution.require_auth();
+        Self::get_campaign(env.clone(), id).ok_or(Error::NotFound)?;
+        let mut allocation = load_allocation(&env, &institution, id).unwrap_or_default();
+        allocation.cap = cap;
+        save_allocation(&env, &institution, id, &allocation);
+        Ok(())
+    }
+
+    /// (Factory) Contributes `amount` from `institution`'s budget to
+    /// campaign number `id`, in that campaign's token.
+    pub fn allocate_to(env: Env, institution: Address, id: u32, amount: u128) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        auth::require_auth_for_call(&env, &institution, (id, amount).into_val(&env));
+        let campaign = Self::get_campaign(env.clone(), id).ok_or(Error::NotFound)?;
+        let client = MilestoneFundClient::new(&env, &campaign);
+        let token = client.get_project().token;
+
+        let mut budget = load_platform_budget(&env, &institution, &token);
+        if amount > budget.available() {
+            return Err(Error::InsufficientFunds);
+        }
+        let mut allocation = load_allocation(&env, &institution, id).unwrap_or_default();
+        let allocated = allocation
+            .allocated
+            .checked_add(amount)
+            .ok_or(Error::AmountTooLarge)?;
+        if allocation.cap.is_some_and(|cap| allocated > cap) {
+            return Err(Error::CapExceeded);
+        }
+
+        budget.allocated += amount;
+        save_platform_budget(&env, &institution, &token, &budget);
+        allocation.allocated = allocated;
+        save_allocation(&env, &institution, id, &allocation);
+        pay_out(&env, &token, &campaign, amount)?;
+        client.allocate_from_factory(&institution, &amount);
+        // The campaign cannot report the allocation back to us
+        Self::sync_campaign(env.clone(), id)?;
+
+        events::publish(
+            &env,
+            (symbol_short!("allocate"), institution, campaign),
+            amount,
+        );
+        Ok(())
+    }
+
+    /// (Factory) Returns `amount` of `institution`'s unallocated `token`
+    /// budget.
+    pub fn withdraw_platform_budget(
+        env: Env,
+        institution: Address,
+        token: Address,
+        amount: u128,
+    ) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        auth::require_auth_for_call(&env, &institution, (token.clone(), amount).into_val(&env));
+        let mut budget = load_platform_budget(&env, &institution, &token);
+        if amount > budget.available() {
+            return Err(Error::InsufficientFunds);
+        }
+
+        budget.withdrawn += amount;
+        save_platform_budget(&env, &institution, &token, &budget);
+        pay_out(&env, &token, &institution, amount)?;
+
+        events::publish(
+            &env,
+            (symbol_short!("budget_wd"), institution, token),
+            amount,
+        );
+        Ok(())
+    }
+
+    /// (Parent factory) Books `amount`, already paid over by the factory
+    /// from `institution`'s budget, as `institution`'s contribution.
+    pub fn allocate_from_factory(
+        env: Env,
+        institution: Address,
+        amount: u128,
+    ) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        cleanup::parent(&env)
+            .ok_or(Error::NotEligible)?
+            .require_auth();
+        let project = Self::get_project(&env)?;
+        check_contribution(&env, &project, &institution, amount)?;
+
+        events::publish(
+            &env,
+            (symbol_short!("allocate"), institution.clone()),
+            amount,
+        );
+        record_contribution(&env, project, &institution, amount)
+    }
+
+    /// (View) Gets `institution`'s `token` budget on this factory.
+    pub fn get_platform_budget(
+        env: Env,
+        institution: Address,
+        token: Address,
+    ) -> InstitutionBudget {
+        load_platform_budget(&env, &institution, &token)
+    }
+
+    /// (View) Gets `institution`'s consolidated statement for the factory's
+    /// campaigns from number `start` on, covering up to 20 campaigns: each
+    /// campaign it allocated to, with the allocation, refunds and outcome.
+    pub fn get_platform_statement(
+        env: Env,
+        institution: Address,
+        start: u32,
+        limit: u32,
+    ) -> Result<PlatformStatement, Error> {
+        let config = Self::get_factory(env.clone()).ok_or(Error::FeatureDisabled)?;
+        if limit > MAX_STATEMENT_PAGE {
+            return Err(Error::CapExceeded);
+        }
+        let end = config.campaigns.min(start.saturating_add(limit));
+        let mut campaigns = Vec::new(&env);
+        for id in start..end {
+            let Some(allocation) = load_allocation(&env, &institution, id) else {
+                continue;
+            };
+            let Some(campaign) = Self::get_campaign(env.clone(), id) else {
+                continue;
+            };
+            let client = MilestoneFundClient::new(&env, &campaign);
+            let Ok(Ok(statement)) = client.try_get_statement(&institution) else {
+                continue;
+            };
+            campaigns.push_back(CampaignStatement {
+                id,
+                token: client.get_project().token,
+                campaign,
+                allocation,
+                refunds_claimed: statement.refunds_claimed,
+                refunds_claimable: statement.refunds_claimable,
+                goal_met: statement.goal_met,
+                milestones_released: statement.milestones_released,
+                milestone_count: statement.milestone_count,
+            });
+        }
+        Ok(PlatformStatement {
+            campaigns,
+            next: end,
+            done: end >= config.campaigns,
+        })
+    }
+}
@@ -0,0 +1,57 @@
+use soroban_sdk::{contractimpl, symbol_short, Address, Env, Vec};
+
+use crate::{
+    access, breaker, cast_vote, events, DataKey, Error, MilestoneFund, MilestoneFundClient,
+};
+
+// --- Bulk Voting ---
+// Backers of projects with many open milestones can cast all their votes in
+// one transaction with a single auth. Every index is validated; whether a
+// milestone the backer already voted on (or that has been released) aborts
+// the batch or is skipped is decided by the admin-configured strictness flag.
+
+pub(crate) fn is_strict(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::BulkVoteStrict)
+        .unwrap_or(true)
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// Casts `backer`'s votes as `(milestone_index, approve)` pairs.
+    /// Returns how many votes were recorded.
+    pub fn vote_bulk(env: Env, backer: Address, votes: Vec<(u32, bool)>) -> Result<u32, Error> {
+        breaker::ensure_writable(&env)?;
+        backer.require_auth();
+        let project = Self::get_project(&env)?;
+        let strict = is_strict(&env);
+
+        let mut cast: u32 = 0;
+        for (milestone_index, approve) in votes.iter() {
+            match cast_vote(&env, &project, &backer, milestone_index, approve) {
+                Ok(()) => cast += 1,
+                Err(Error::AlreadyVoted | Error::MilestoneAlreadyCompleted) if !strict => {}
+                Err(error) => return Err(error),
+            }
+        }
+
+        events::publish(&env, (symbol_short!("vote_bulk"), backer), cast);
+        Ok(cast)
+    }
+
+    /// (Admin) Sets whether `vote_bulk` fails on milestones the backer
+    /// cannot vote on any more (`true`, the default) or skips them.
+    pub fn set_bulk_vote_strict(env: Env, strict: bool) -> Result<(), Error> {
+        access::require_admin(&env)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::BulkVoteStrict, &strict);
+        Ok(())
+    }
+
+    /// (View) Gets the `vote_bulk` strictness flag.
+    pub fn is_bulk_vote_strict(env: Env) -> bool {
+        is_strict(&env)
+    }
+}
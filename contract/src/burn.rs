@@ -0,0 +1,71 @@
+use soroban_sdk::{contractimpl, symbol_short, token, Env};
+
+use crate::{access, events, Error, MilestoneFund, MilestoneFundClient, Project, StorageKey};
+
+// --- Contribution Burn ---
+// Projects raising in their own deflationary token can burn a fixed share
+// of every contribution through the token's burn interface instead of
+// raising it. Only the rest counts towards `raised` and the backer's
+// pledge, and is what refunds and withdrawals can return. The share is set
+// by the admin before the first contribution and cannot change after.
+
+const BPS_DENOMINATOR: u128 = 10_000;
+
+fn burn_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&StorageKey::BurnBps)
+        .unwrap_or(0)
+}
+
+fn burned(env: &Env) -> u128 {
+    env.storage()
+        .instance()
+        .get(&StorageKey::Burned)
+        .unwrap_or(0)
+}
+
+/// Burns the configured share of a contribution of `amount` the contract
+/// already holds. Returns what is left to raise.
+pub(crate) fn burn_share(env: &Env, project: &Project, amount: u128) -> Result<u128, Error> {
+    let share = amount * u128::from(burn_bps(env)) / BPS_DENOMINATOR;
+    if share == 0 {
+        return Ok(amount);
+    }
+    let units = i128::try_from(share).map_err(|_| Error::AmountTooLarge)?;
+    token::Client::new(env, &project.token).burn(&env.current_contract_address(), &units);
+    env.storage()
+        .instance()
+        .set(&StorageKey::Burned, &(burned(env) + share));
+
+    events::publish(env, (symbol_short!("burn"),), share);
+    Ok(amount - share)
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Admin) Sets the share of every contribution burned instead of
+    /// raised, in basis points. Only possible before the first contribution.
+    pub fn set_burn_bps(env: Env, bps: u32) -> Result<(), Error> {
+        access::require_admin(&env)?;
+        if u128::from(bps) >= BPS_DENOMINATOR {
+            return Err(Error::InvalidArgument);
+        }
+        let project = Self::get_project(&env)?;
+        if project.raised > 0 || burned(&env) > 0 {
+            return Err(Error::TermsLocked);
+        }
+        env.storage().instance().set(&StorageKey::BurnBps, &bps);
+        Ok(())
+    }
+
+    /// (View) Gets the share of every contribution burned, in basis points.
+    pub fn get_burn_bps(env: Env) -> u32 {
+        burn_bps(&env)
+    }
+
+    /// (View) Gets the total burned from contributions so far.
+    pub fn get_burned(env: Env) -> u128 {
+        burned(&env)
+    }
+}
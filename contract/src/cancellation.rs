@@ -0,0 +1,290 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env, String, Vec};
+
+use crate::{
+    access, auth, breaker, claims, collect, events, governance, pay_out, platform, rewards,
+    save_project, stream, watch, DataKey, Error, MilestoneFund, MilestoneFundClient, Project,
+    ProjectState,
+};
+
+// --- Cancellation Fee Schedule ---
+// A creator who walks away after the goal is met owes backers more than
+// their remaining escrow back. A project initialized with a cancellation
+// schedule holds a stake from the creator; cancelling forfeits
+// `fee_bps_per_milestone` of that stake for every milestone not yet
+// completed, capped at the whole stake. The fee and every open milestone's
+// escrow go to the backers' refund pool and the rest of the stake returns
+// to the creator.
+//
+// Before anything has been paid out the creator can instead `cancel`
+// outright: the project is marked cancelled, funding stops and every
+// backer can claim their whole contribution back with `claim_refund`,
+// deadline or not. Cancelling after the goal is met still forfeits the fee
+// to the refund pool, and `claim_refund` pays each backer's share of it
+// along with the contribution.
+
+const BPS_DENOMINATOR: u128 = 10_000;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CancellationSchedule {
+    pub stake: u128,                // Held from the creator at initialization
+    pub fee_bps_per_milestone: u32, // Share of the stake forfeited per open milestone
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cancellation {
+    pub schedule: CancellationSchedule,
+    pub cancelled_at: Option<u64>,
+    pub fee: u128,          // Stake forfeited to backers on cancellation
+    pub full_refunds: bool, // Cancelled before any payout, refunding contributions
+}
+
+fn load(env: &Env) -> Option<Cancellation> {
+    env.storage().instance().get(&DataKey::Cancellation)
+}
+
+/// The stored cancellation, or an unscheduled one without stake.
+fn load_or_default(env: &Env) -> Cancellation {
+    load(env).unwrap_or(Cancellation {
+        schedule: CancellationSchedule {
+            stake: 0,
+            fee_bps_per_milestone: 0,
+        },
+        cancelled_at: None,
+        fee: 0,
+        full_refunds: false,
+    })
+}
+
+/// Whether the creator has cancelled the project.
+pub(crate) fn is_cancelled(env: &Env) -> bool {
+    load(env).is_some_and(|cancellation| cancellation.cancelled_at.is_some())
+}
+
+/// Whether the creator cancelled before any payout, so every backer can
+/// claim their contribution back.
+pub(crate) fn refunds_open(env: &Env) -> bool {
+    load(env).is_some_and(|cancellation| cancellation.full_refunds)
+}
+
+/// Stake the contract holds for a cancellation fee.
+pub(crate) fn stake_held(env: &Env) -> u128 {
+    match load(env) {
+        Some(cancellation) if cancellation.cancelled_at.is_none() => cancellation.schedule.stake,
+        _ => 0,
+    }
+}
+
+fn open_milestones(project: &Project) -> u32 {
+    project.milestones.iter().filter(|m| !m.is_complete).count() as u32
+}
+
+fn fee_for(schedule: &CancellationSchedule, open_milestones: u32) -> Result<u128, Error> {
+    let bps = u128::from(schedule.fee_bps_per_milestone) * u128::from(open_milestones);
+    let fee = schedule.stake.checked_mul(bps).ok_or(Error::Overflow)? / BPS_DENOMINATOR;
+    Ok(fee.min(schedule.stake))
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// Initializes a project like `initialize`, additionally taking
+    /// `schedule.stake` from the creator to back the cancellation fee.
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize_with_cancellation_fee(
+        env: Env,
+        admin: Address,
+        creator: Address,
+        token: Address,
+        goal: u128,
+        deadline: u64,
+        milestones: Vec<(String, u128)>,
+        schedule: CancellationSchedule,
+    ) -> Result<(), Error> {
+        if u128::from(schedule.fee_bps_per_milestone) > BPS_DENOMINATOR {
+            return Err(Error::InvalidArgument);
+        }
+        Self::initialize(
+            env.clone(),
+            admin,
+            creator.clone(),
+            token.clone(),
+            goal,
+            deadline,
+            milestones,
+            governance::DEFAULT_APPROVAL_THRESHOLD_BPS,
+            0,
+        )?;
+
+        if schedule.stake > 0 {
+            auth::require_auth_for_amount(&env, &creator, schedule.stake);
+            collect(&env, &token, &creator, schedule.stake)?;
+        }
+        let cancellation = Cancellation {
+            schedule,
+            cancelled_at: None,
+            fee: 0,
+            full_refunds: false,
+        };
+        env.storage()
+            .instance()
+            .set(&DataKey::Cancellation, &cancellation);
+        Ok(())
+    }
+
+    /// (Creator) Cancels a funded project. Every open milestone's escrow and
+    /// the cancellation fee go to the backers' refund pool, and the rest of
+    /// the stake returns to the creator. Returns the fee charged.
+    pub fn cancel_project(env: Env) -> Result<u128, Error> {
+        let mut project = access::require_creator(&env)?;
+        if !project.goal_met {
+            return Err(Error::GoalNotMet);
+        }
+        let mut cancellation = load_or_default(&env);
+        if cancellation.cancelled_at.is_some() {
+            return Err(Error::AlreadyExists);
+        }
+        let open = open_milestones(&project);
+        if open == 0 {
+            return Err(Error::NotEligible);
+        }
+        if breaker::trip_on_divergence(&env, &project)? {
+            return Ok(0);
+        }
+
+        let mut refunded: u128 = 0;
+        for index in 0..project.milestones.len() {
+            let mut milestone = project.milestones.get_unchecked(index);
+            if milestone.is_complete {
+                continue;
+            }
+            if stream::is_streaming(&env, index) {
+                return Err(Error::MilestoneStreaming);
+            }
+            let balance = milestone.escrow.balance()?;
+            milestone.is_complete = true;
+            milestone.escrow.refunded = milestone
+                .escrow
+                .refunded
+                .checked_add(balance)
+                .ok_or(Error::Overflow)?;
+            project.milestones.set(index, milestone);
+            refunded = refunded.checked_add(balance).ok_or(Error::Overflow)?;
+        }
+        let fee = fee_for(&cancellation.schedule, open)?;
+        claims::credit_refund_pool(&mut project, refunded + fee);
+        project.state = ProjectState::Cancelled;
+        save_project(&env, &project);
+        rewards::note_completion(&env, &project);
+
+        cancellation.cancelled_at = Some(u64::from(env.ledger().sequence()));
+        cancellation.fee = fee;
+        env.storage()
+            .instance()
+            .set(&DataKey::Cancellation, &cancellation);
+        let returned = cancellation.schedule.stake - fee;
+        if returned > 0 {
+            pay_out(&env, &project.token, &project.creator, returned)?;
+        }
+        platform::report(&env, &project);
+
+        events::publish(
+            &env,
+            (symbol_short!("cancel"), project.creator),
+            (refunded, fee),
+        );
+        watch::publish_transition(&env, symbol_short!("cancelled"), fee);
+        Ok(fee)
+    }
+
+    /// (Creator) Cancels the project before anything has been paid out:
+    /// funding stops and every backer can claim a full refund. After the
+    /// goal is met the cancellation fee still goes to the refund pool; the
+    /// rest of the stake returns to the creator. Returns the fee charged.
+    pub fn cancel(env: Env) -> Result<u128, Error> {
+        let mut project = access::require_creator(&env)?;
+        if project.state.is_final() {
+            return Err(Error::NotEligible);
+        }
+        let paid_out = project
+            .milestones
+            .iter()
+            .any(|m| m.is_complete || m.escrow.released > 0);
+        if paid_out {
+            return Err(Error::NotEligible);
+        }
+        let mut cancellation = load_or_default(&env);
+        if cancellation.cancelled_at.is_some() {
+            return Err(Error::AlreadyExists);
+        }
+        if breaker::trip_on_divergence(&env, &project)? {
+            return Ok(0);
+        }
+
+        let fee = if project.goal_met {
+            fee_for(&cancellation.schedule, open_milestones(&project))?
+        } else {
+            0
+        };
+        claims::credit_refund_pool(&mut project, fee);
+        project.state = ProjectState::Cancelled;
+        save_project(&env, &project);
+
+        cancellation.cancelled_at = Some(u64::from(env.ledger().sequence()));
+        cancellation.fee = fee;
+        cancellation.full_refunds = true;
+        env.storage()
+            .instance()
+            .set(&DataKey::Cancellation, &cancellation);
+        let returned = cancellation.schedule.stake - fee;
+        if returned > 0 {
+            pay_out(&env, &project.token, &project.creator, returned)?;
+        }
+
+        events::publish(
+            &env,
+            (symbol_short!("cancel"), project.creator),
+            (project.raised, fee),
+        );
+        watch::publish_transition(&env, symbol_short!("cancelled"), fee);
+        Ok(fee)
+    }
+
+    /// (View) Gets the cancellation schedule and outcome, if any.
+    pub fn get_cancellation(env: Env) -> Result<Cancellation, Error> {
+        load(&env).ok_or(Error::NotFound)
+    }
+
+    /// (View) Gets the fee a cancellation would charge right now.
+    pub fn get_cancellation_fee(env: Env) -> Result<u128, Error> {
+        let project = Self::get_project(&env)?;
+        match load(&env) {
+            Some(cancellation) => fee_for(&cancellation.schedule, open_milestones(&project)),
+            None => Ok(0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule(stake: u128, fee_bps_per_milestone: u32) -> CancellationSchedule {
+        CancellationSchedule {
+            stake,
+            fee_bps_per_milestone,
+        }
+    }
+
+    #[test]
+    fn fee_for_is_capped_at_the_stake() {
+        assert_eq!(fee_for(&schedule(1_000, 2_500), 2), Ok(500));
+        assert_eq!(fee_for(&schedule(1_000, 2_500), 5), Ok(1_000));
+        assert_eq!(fee_for(&schedule(1_000, 2_500), 0), Ok(0));
+    }
+
+    #[test]
+    fn fee_for_overflows_at_max() {
+        assert_eq!(fee_for(&schedule(u128::MAX, 2), 1), Err(Error::Overflow));
+    }
+}
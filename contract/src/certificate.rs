@@ -0,0 +1,130 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, BytesN, Env};
+
+use crate::{
+    access, breaker, cancellation, events, Error, MilestoneFund, MilestoneFundClient, Project,
+    StorageKey,
+};
+
+// --- Outcome Certificate ---
+// Once a campaign is settled, its creator and an arbiter appointed by the
+// admin co-sign a certificate of how it ended, together with the hash of
+// the off-chain settlement report. The contract only accepts the outcome
+// its own state shows, so grant funders can take the stored certificate
+// as a verifiable record of the campaign rather than the creator's word.
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CampaignOutcome {
+    Success, // Every milestone was released
+    Partial, // Cancelled after some milestones were released
+    Failed,  // The goal was missed, or cancelled before any release
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OutcomeCertificate {
+    pub outcome: CampaignOutcome,
+    pub report_hash: BytesN<32>,
+    pub creator: Address,
+    pub arbiter: Address,
+    pub issued_at: u64,
+}
+
+/// The outcome of the campaign, or `None` while it is not settled yet.
+fn outcome(env: &Env, project: &Project) -> Option<CampaignOutcome> {
+    if !project.goal_met {
+        let ended = u64::from(env.ledger().sequence()) > project.deadline;
+        return ended.then_some(CampaignOutcome::Failed);
+    }
+    let released = project
+        .milestones
+        .iter()
+        .filter(|milestone| milestone.is_complete)
+        .count() as u32;
+    if released == project.milestones.len() {
+        Some(CampaignOutcome::Success)
+    } else if cancellation::is_cancelled(env) {
+        Some(if released > 0 {
+            CampaignOutcome::Partial
+        } else {
+            CampaignOutcome::Failed
+        })
+    } else {
+        None
+    }
+}
+
+fn arbiter(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&StorageKey::Arbiter)
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Admin) Appoints the arbiter who co-signs the outcome certificate.
+    pub fn set_arbiter(env: Env, arbiter: Address) -> Result<(), Error> {
+        access::require_admin(&env)?;
+        if env
+            .storage()
+            .instance()
+            .has(&StorageKey::OutcomeCertificate)
+        {
+            return Err(Error::AlreadyExists);
+        }
+        env.storage().instance().set(&StorageKey::Arbiter, &arbiter);
+        Ok(())
+    }
+
+    /// (Creator and arbiter) Certifies the settled campaign's `outcome`
+    /// and the hash of its settlement report. Both must sign, and the
+    /// outcome must be the one the contract's state shows. Can only be
+    /// done once.
+    pub fn certify_outcome(
+        env: Env,
+        outcome: CampaignOutcome,
+        report_hash: BytesN<32>,
+    ) -> Result<OutcomeCertificate, Error> {
+        breaker::ensure_writable(&env)?;
+        let project = Self::get_project(&env)?;
+        let arbiter = arbiter(&env).ok_or(Error::NotFound)?;
+        project.creator.require_auth();
+        arbiter.require_auth();
+        if env
+            .storage()
+            .instance()
+            .has(&StorageKey::OutcomeCertificate)
+        {
+            return Err(Error::AlreadyExists);
+        }
+        let settled = self::outcome(&env, &project).ok_or(Error::NotSettled)?;
+        if outcome != settled {
+            return Err(Error::InvalidArgument);
+        }
+
+        let certificate = OutcomeCertificate {
+            outcome,
+            report_hash,
+            creator: project.creator,
+            arbiter,
+            issued_at: u64::from(env.ledger().sequence()),
+        };
+        env.storage()
+            .instance()
+            .set(&StorageKey::OutcomeCertificate, &certificate);
+
+        let topics = (symbol_short!("outcome"), symbol_short!("certified"));
+        events::publish(&env, topics, certificate.clone());
+        Ok(certificate)
+    }
+
+    /// (View) Gets the arbiter, if one is appointed.
+    pub fn get_arbiter(env: Env) -> Option<Address> {
+        arbiter(&env)
+    }
+
+    /// (View) Gets the outcome certificate, once the campaign is certified.
+    pub fn get_outcome_certificate(env: Env) -> Option<OutcomeCertificate> {
+        env.storage()
+            .instance()
+            .get(&StorageKey::OutcomeCertificate)
+    }
+}
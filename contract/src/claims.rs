@@ -0,0 +1,130 @@
+use soroban_sdk::{contractimpl, symbol_short, Address, Env};
+
+use crate::{
+    backers, breaker, events, pay_out, privacy, save_project, ttl, DataKey, Error, Milestone,
+    MilestoneFund, MilestoneFundClient, Project,
+};
+
+// --- Refund Pool ---
+// Funds handed back to backers (e.g. the unreleased part of a downscoped
+// milestone) accumulate in `Project::refund_pool`. Each backer's claimable
+// share is derived pro-rata from their contribution when they claim, so
+// crediting the pool never has to touch every backer's entry.
+
+/// Adds `amount` to the pool shared pro-rata by all backers.
+pub(crate) fn credit_refund_pool(project: &mut Project, amount: u128) {
+    project.refund_pool += amount;
+}
+
+/// Settles an open milestone without a release: what its escrow still
+/// holds goes to the pool. The caller stores the milestone. Returns the
+/// amount credited.
+pub(crate) fn refund_milestone(
+    project: &mut Project,
+    milestone: &mut Milestone,
+) -> Result<u128, Error> {
+    let remainder = milestone.escrow.balance()?;
+    milestone.is_complete = true;
+    milestone.escrow.refunded = milestone
+        .escrow
+        .refunded
+        .checked_add(remainder)
+        .ok_or(Error::Overflow)?;
+    credit_refund_pool(project, remainder);
+    Ok(remainder)
+}
+
+pub(crate) fn claimed(env: &Env, backer: &Address) -> u128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PoolClaimed(backer.clone()))
+        .unwrap_or(0)
+}
+
+/// `contribution`'s pro-rata share of `pool`, out of `raised`.
+pub(crate) fn pro_rata(contribution: u128, pool: u128, raised: u128) -> Result<u128, Error> {
+    if raised == 0 {
+        return Ok(0);
+    }
+    Ok(contribution.checked_mul(pool).ok_or(Error::Overflow)? / raised)
+}
+
+/// Part of the refund pool `backer` can still claim.
+pub(crate) fn claimable(env: &Env, project: &Project, backer: &Address) -> Result<u128, Error> {
+    let contribution = backers::amount(env, backer);
+    let entitled = pro_rata(contribution, project.refund_pool, project.raised)?;
+    Ok(entitled.saturating_sub(claimed(env, backer)))
+}
+
+/// Books `backer`'s outstanding share of the refund pool as claimed and
+/// returns it. The caller stores the project and pays the amount out.
+pub(crate) fn take_claimable(
+    env: &Env,
+    project: &mut Project,
+    backer: &Address,
+) -> Result<u128, Error> {
+    let amount = claimable(env, project, backer)?;
+    if amount == 0 {
+        return Ok(0);
+    }
+    let key = DataKey::PoolClaimed(backer.clone());
+    ttl::set_persistent(env, &key, &(claimed(env, backer) + amount));
+    project.refund_pool_claimed += amount;
+    Ok(amount)
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// Pays `backer` their outstanding share of the refund pool.
+    pub fn claim(env: Env, backer: Address) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        backer.require_auth();
+        let mut project = Self::get_project(&env)?;
+
+        if claimable(&env, &project, &backer)? == 0 {
+            return Err(Error::NoRefundsToClaim);
+        }
+        if breaker::trip_on_divergence(&env, &project)? {
+            return Ok(());
+        }
+
+        let amount = take_claimable(&env, &mut project, &backer)?;
+        save_project(&env, &project);
+
+        pay_out(&env, &project.token, &backer, amount)?;
+
+        events::publish(&env, (symbol_short!("claim"), backer), amount);
+        Ok(())
+    }
+
+    /// (Authenticated view) Gets the refund-pool amount `backer` can
+    /// currently claim. `viewer` must be the backer, the creator or the admin.
+    pub fn get_claimable(env: Env, viewer: Address, backer: Address) -> Result<u128, Error> {
+        let project = Self::get_project(&env)?;
+        privacy::authorize_viewer(&env, &project, &viewer, Some(&backer))?;
+        claimable(&env, &project, &backer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pro_rata_splits_the_pool() {
+        assert_eq!(pro_rata(25, 400, 100), Ok(100));
+        assert_eq!(pro_rata(1, 1, 3), Ok(0));
+        assert_eq!(pro_rata(10, 10, 0), Ok(0));
+        assert_eq!(pro_rata(u128::MAX, 1, u128::MAX), Ok(1));
+        assert_eq!(pro_rata(1, u128::MAX, u128::MAX), Ok(1));
+    }
+
+    #[test]
+    fn pro_rata_overflows_at_max() {
+        assert_eq!(pro_rata(u128::MAX, 2, u128::MAX), Err(Error::Overflow));
+        assert_eq!(
+            pro_rata(u128::MAX, u128::MAX, u128::MAX),
+            Err(Error::Overflow)
+        );
+    }
+}
@@ -0,0 +1,147 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, token, Address, Env};
+
+use crate::{
+    access, breaker, events, factory, pay_out, platform, projects, DataKey, Error, MilestoneFund,
+    MilestoneFundClient, StateKey, StorageKey,
+};
+
+// --- Stale Campaign Cleanup ---
+// Factory deployments collect abandoned test campaigns that never raised a
+// thing. Once such a campaign is past its deadline by the factory's grace
+// period, anyone can have the factory purge it: the campaign deletes the
+// entries `initialize` wrote, leaving only a tombstone so it cannot be
+// initialized again, and the factory drops it from its registry and pays
+// the caller the keeper reward, if one is set and funded.
+//
+// Both roles are played by this contract: the terms live on the factory,
+// the purge on the campaign, which only takes it from the factory that
+// deployed it. The creator co-signs naming that factory, as part of the
+// `deploy_campaign` call.
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CleanupTerms {
+    pub grace_ledgers: u64,            // Ledgers past the deadline before a purge
+    pub reward_token: Option<Address>, // Asset the keeper reward is paid in
+    pub reward: u128,                  // Paid from the factory's own balance
+}
+
+fn load(env: &Env) -> Option<CleanupTerms> {
+    env.storage().instance().get(&StorageKey::CleanupTerms)
+}
+
+pub(crate) fn parent(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&StorageKey::ParentFactory)
+}
+
+/// Whether this instance hosted a campaign that has been purged.
+pub(crate) fn is_purged(env: &Env) -> bool {
+    env.storage().instance().has(&StorageKey::Purged)
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Factory admin) Sets when never-funded campaigns can be purged and
+    /// what the keeper who purges one is paid.
+    pub fn set_cleanup_terms(env: Env, terms: CleanupTerms) -> Result<(), Error> {
+        access::require_admin(&env)?;
+        if !factory::is_factory(&env) {
+            return Err(Error::FeatureDisabled);
+        }
+        if terms.reward > 0 && terms.reward_token.is_none() {
+            return Err(Error::InvalidArgument);
+        }
+        env.storage()
+            .instance()
+            .set(&StorageKey::CleanupTerms, &terms);
+        Ok(())
+    }
+
+    /// (View) Gets the cleanup terms, if this factory has set them.
+    pub fn get_cleanup_terms(env: Env) -> Option<CleanupTerms> {
+        load(&env)
+    }
+
+    /// Purges the factory's campaign number `id` once it is past its
+    /// deadline by the grace period without a single contribution, and
+    /// pays `keeper` the reward, or what is left of it. Callable by anyone.
+    /// Returns the reward paid.
+    pub fn cleanup_stale(env: Env, id: u32, keeper: Address) -> Result<u128, Error> {
+        breaker::ensure_writable(&env)?;
+        let terms = load(&env).ok_or(Error::FeatureDisabled)?;
+        let campaign = Self::get_campaign(env.clone(), id).ok_or(Error::NotFound)?;
+        let client = MilestoneFundClient::new(&env, &campaign);
+        let project = client
+            .try_get_project()
+            .ok()
+            .and_then(Result::ok)
+            .ok_or(Error::NotFound)?;
+        if project.raised > 0 || client.get_backer_count() > 0 {
+            return Err(Error::NotEligible);
+        }
+        if u64::from(env.ledger().sequence())
+            <= project.deadline.saturating_add(terms.grace_ledgers)
+        {
+            return Err(Error::NotDue);
+        }
+
+        client.purge();
+        projects::forget(&env, id);
+        platform::forget(&env, &campaign);
+
+        let mut reward = 0;
+        if let Some(reward_token) = terms.reward_token {
+            let held =
+                token::Client::new(&env, &reward_token).balance(&env.current_contract_address());
+            reward = terms.reward.min(u128::try_from(held).unwrap_or(0));
+            if reward > 0 {
+                pay_out(&env, &reward_token, &keeper, reward)?;
+            }
+        }
+
+        let topics = (symbol_short!("factory"), symbol_short!("cleanup"), id);
+        events::publish(&env, topics, (campaign, keeper, reward));
+        Ok(reward)
+    }
+
+    /// (Creator and factory) Records the factory that deployed this
+    /// campaign, which alone may purge it and receives its reports, and the
+    /// project ID the factory gave it. Both the creator and the factory
+    /// sign, so no other contract can make itself the parent. Only possible
+    /// once, before any contribution.
+    pub fn set_factory(env: Env, factory: Address, project_id: u32) -> Result<(), Error> {
+        let project = access::require_creator(&env)?;
+        factory.require_auth();
+        if parent(&env).is_some() || project.raised > 0 {
+            return Err(Error::AlreadyExists);
+        }
+        env.storage()
+            .instance()
+            .set(&StorageKey::ParentFactory, &factory);
+        projects::record_id(&env, project_id);
+        Ok(())
+    }
+
+    /// (Factory) Deletes this never-funded campaign, leaving a tombstone.
+    pub fn purge(env: Env) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        parent(&env).ok_or(Error::FeatureDisabled)?.require_auth();
+        let project = Self::get_project(&env)?;
+        if project.raised > 0 || u64::from(env.ledger().sequence()) <= project.deadline {
+            return Err(Error::NotEligible);
+        }
+
+        let storage = env.storage().instance();
+        storage.remove(&DataKey::ProjectInfo);
+        storage.remove(&StateKey::ProjectStatus);
+        storage.remove(&StateKey::Milestones);
+        storage.remove(&DataKey::Admin);
+        storage.remove(&DataKey::CreatedAt);
+        storage.remove(&StorageKey::ParentFactory);
+        storage.remove(&StateKey::ProjectId);
+        storage.set(&StorageKey::Purged, &true);
+
+        events::publish(&env, (symbol_short!("purged"),), project.creator);
+        Ok(())
+    }
+}
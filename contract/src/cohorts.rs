@@ -0,0 +1,118 @@
+use soroban_sdk::{contractimpl, contracttype, Address, Env};
+
+use crate::{breaker, cleanup, factory, ttl, Error, MilestoneFund, MilestoneFundClient, StateKey};
+
+// --- Backer Cohorts ---
+// A factory can tell creators how many of their backers are new to the
+// platform and how many came back from an earlier campaign. Every
+// contribution to a campaign it deployed is reported to it; the factory
+// remembers the first campaign each backer contributed to, and a backer is
+// new in that campaign and returning in every later one. The aggregates
+// count each backer once per campaign and sum what they contributed,
+// withdrawals not deducted. A report the factory refuses is dropped.
+// Contributions made through the factory's own `fund` are recorded by the
+// factory directly, since the campaign cannot call back into it.
+
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct BackerCohorts {
+    pub new_backers: u32, // First contributed to the platform in this campaign
+    pub new_amount: u128,
+    pub returning_backers: u32, // Contributed to an earlier campaign first
+    pub returning_amount: u128,
+}
+
+fn load(env: &Env, campaign: &Address) -> BackerCohorts {
+    env.storage()
+        .persistent()
+        .get(&StateKey::Cohorts(campaign.clone()))
+        .unwrap_or_default()
+}
+
+/// Reports a contribution of `amount` by `backer` to the factory that
+/// deployed this campaign, if any. `joined` is whether it is the backer's
+/// first contribution to this campaign.
+pub(crate) fn report(env: &Env, backer: &Address, amount: u128, joined: bool) {
+    let Some(parent) = cleanup::parent(env) else {
+        return;
+    };
+    let campaign = env.current_contract_address();
+    let _ = MilestoneFundClient::new(env, &parent)
+        .try_report_backer(&campaign, backer, &amount, &joined);
+}
+
+/// Records a contribution of `amount` by `backer` to `campaign` in this
+/// factory's backer cohorts.
+pub(crate) fn record(
+    env: &Env,
+    campaign: &Address,
+    backer: &Address,
+    amount: u128,
+    joined: bool,
+) -> Result<(), Error> {
+    let first_key = StateKey::FirstCampaign(backer.clone());
+    let first: Address = match env.storage().persistent().get(&first_key) {
+        Some(first) => first,
+        None => {
+            ttl::set_persistent(env, &first_key, campaign);
+            campaign.clone()
+        }
+    };
+    let mut cohorts = load(env, campaign);
+    let joined = u32::from(joined);
+    if first == *campaign {
+        cohorts.new_backers = cohorts
+            .new_backers
+            .checked_add(joined)
+            .ok_or(Error::Overflow)?;
+        cohorts.new_amount = cohorts
+            .new_amount
+            .checked_add(amount)
+            .ok_or(Error::Overflow)?;
+    } else {
+        cohorts.returning_backers = cohorts
+            .returning_backers
+            .checked_add(joined)
+            .ok_or(Error::Overflow)?;
+        cohorts.returning_amount = cohorts
+            .returning_amount
+            .checked_add(amount)
+            .ok_or(Error::Overflow)?;
+    }
+    ttl::set_persistent(env, &StateKey::Cohorts(campaign.clone()), &cohorts);
+    Ok(())
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Campaign) Records a contribution to a deployed campaign in this
+    /// factory's backer cohorts.
+    pub fn report_backer(
+        env: Env,
+        campaign: Address,
+        backer: Address,
+        amount: u128,
+        joined: bool,
+    ) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        campaign.require_auth();
+        if !factory::is_factory(&env) {
+            return Err(Error::FeatureDisabled);
+        }
+        if Self::get_campaign_report(env.clone(), campaign.clone()).is_none() {
+            return Err(Error::NotFound);
+        }
+
+        record(&env, &campaign, &backer, amount, joined)
+    }
+
+    /// (View) Gets the new and returning backer cohorts of the factory's
+    /// campaign number `project_id`.
+    pub fn get_cohorts(env: Env, project_id: u32) -> Result<BackerCohorts, Error> {
+        if !factory::is_factory(&env) {
+            return Err(Error::FeatureDisabled);
+        }
+        let campaign = Self::get_campaign(env.clone(), project_id).ok_or(Error::NotFound)?;
+        Ok(load(&env, &campaign))
+    }
+}
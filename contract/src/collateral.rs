@@ -0,0 +1,451 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, token, Address, Env, Vec};
+
+use crate::{
+    access, auth, breaker, claims, collect, events, oracle, pay_out, save_project,
+    settlement::SwapAdapterClient, DataKey, Error, MilestoneFund, MilestoneFundClient, Project,
+    ProjectState, StorageKey,
+};
+
+// --- Cross-Campaign Collateral ---
+// A serial creator can back a new campaign with a completed one: the
+// completed campaign pledges part of its surplus, topped up from the
+// creator if needed, to the new campaign's contract, and the new campaign
+// links to it before raising. Each evidence penalty the new campaign applies
+// then slashes the pledge by the same amount into its own refund pool. When
+// the new campaign completes, anyone can hand the rest back to the creator.
+// If it fails or is cancelled instead, or never links the pledge within
+// `UNLINKED_TIMEOUT` ledgers, the creator reclaims the rest themselves.
+//
+// Both roles are played by this contract: the pledge lives on the completed
+// campaign, the link on the new one.
+//
+// The creator may instead post the pledge in another asset, such as their
+// own project token or XLM. It is valued in the campaign token through the
+// price oracle, less the admin's haircut, both when posted and at each
+// slash; a slash sells just enough of it through the asset's settlement
+// route to deliver the slashed value to the new campaign in its token.
+
+const BPS_DENOMINATOR: u128 = 10_000;
+
+/// Ledgers a pledge waits for its campaign to link it (~30 days).
+pub(crate) const UNLINKED_TIMEOUT: u64 = 518_400;
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CollateralPledge {
+    pub campaign: Address, // The campaign contract the pledge backs
+    pub token: Address,    // Asset the pledge is held in
+    pub quote: Address,    // Campaign token slashes are paid in
+    pub amount: u128,
+    pub posted_value: u128, // Haircut value in `quote` when posted
+    pub slashed: u128,
+    pub released: bool,
+    pub posted_at: u64, // Ledger the pledge was posted at
+}
+
+impl CollateralPledge {
+    /// Collateral still held for the linked campaign.
+    pub fn remaining(&self) -> Result<u128, Error> {
+        if self.released {
+            return Ok(0);
+        }
+        self.amount.checked_sub(self.slashed).ok_or(Error::Overflow)
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CollateralLink {
+    pub source: Address, // The completed campaign holding the pledge
+    pub slashed: u128,
+}
+
+fn load_pledge(env: &Env) -> Option<CollateralPledge> {
+    env.storage().instance().get(&DataKey::CollateralPledge)
+}
+
+fn load_link(env: &Env) -> Option<CollateralLink> {
+    env.storage().instance().get(&DataKey::CollateralLink)
+}
+
+/// Campaign-token collateral this campaign holds for a linked campaign.
+pub(crate) fn pledged(env: &Env) -> Result<u128, Error> {
+    load_pledge(env)
+        .filter(|pledge| pledge.token == pledge.quote)
+        .map_or(Ok(0), |pledge| pledge.remaining())
+}
+
+fn haircut_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&StorageKey::CollateralHaircut)
+        .unwrap_or(0)
+}
+
+/// Whether the creator may take back what is left of `pledge`: its
+/// campaign has failed or been cancelled, or has not linked it in time.
+fn is_reclaimable(env: &Env, pledge: &CollateralPledge) -> bool {
+    let now = u64::from(env.ledger().sequence());
+    let campaign = MilestoneFundClient::new(env, &pledge.campaign);
+    if let Ok(Ok(project)) = campaign.try_get_project() {
+        let failed = !project.goal_met && now > project.deadline;
+        if failed
+            || matches!(
+                project.state,
+                ProjectState::Failed | ProjectState::Cancelled
+            )
+        {
+            return true;
+        }
+    }
+    let linked = match campaign.try_get_collateral_link() {
+        Ok(Ok(link)) => link.source == env.current_contract_address(),
+        _ => false,
+    };
+    !linked && now >= pledge.posted_at.saturating_add(UNLINKED_TIMEOUT)
+}
+
+/// Oracle value in `quote` of `amount` of `asset`, less the haircut.
+fn haircut_value(env: &Env, asset: &Address, amount: u128, quote: &Address) -> Result<u128, Error> {
+    let value = oracle::value(env, asset, amount, quote)?;
+    let kept = BPS_DENOMINATOR - u128::from(haircut_bps(env));
+    Ok(value.checked_mul(kept).ok_or(Error::AmountTooLarge)? / BPS_DENOMINATOR)
+}
+
+/// Books a slash of up to `amount` (in the quote token) against a pledge
+/// held in another asset and sells the seized share through the asset's
+/// settlement route, delivering the proceeds to the linked campaign.
+/// Returns the amount delivered.
+fn slash_foreign(env: &Env, pledge: &mut CollateralPledge, amount: u128) -> Result<u128, Error> {
+    let remaining = pledge.remaining()?;
+    let worth = haircut_value(env, &pledge.token, remaining, &pledge.quote)?;
+    let target = amount.min(worth);
+    if target == 0 {
+        return Ok(0);
+    }
+    let seized = if target == worth {
+        remaining
+    } else {
+        remaining
+            .checked_mul(target)
+            .ok_or(Error::AmountTooLarge)?
+            .div_ceil(worth)
+    };
+    let route = MilestoneFund::get_settlement_route(env.clone(), pledge.token.clone())
+        .ok_or(Error::NotFound)?;
+
+    pledge.slashed = pledge.slashed.checked_add(seized).ok_or(Error::Overflow)?;
+    env.storage()
+        .instance()
+        .set(&DataKey::CollateralPledge, &*pledge);
+
+    let mut path = Vec::new(env);
+    for asset in route.path.iter().rev() {
+        path.push_back(asset);
+    }
+    let seized_in = i128::try_from(seized).map_err(|_| Error::AmountTooLarge)?;
+    let min_out = i128::try_from(target).map_err(|_| Error::AmountTooLarge)?;
+    let quote = token::Client::new(env, &pledge.quote);
+    let balance_before = quote.balance(&pledge.campaign);
+    pay_out(env, &pledge.token, &route.adapter, seized)?;
+    SwapAdapterClient::new(env, &route.adapter).swap(
+        &pledge.token,
+        &seized_in,
+        &path,
+        &pledge.quote,
+        &min_out,
+        &pledge.campaign,
+    );
+    let delivered = quote.balance(&pledge.campaign) - balance_before;
+    if delivered < min_out {
+        return Err(Error::SlippageExceeded);
+    }
+    Ok(delivered as u128)
+}
+
+/// Slashes the linked collateral by up to `amount` into the refund pool of
+/// `project`, without persisting it. Returns the amount received.
+pub(crate) fn slash_linked(env: &Env, project: &mut Project, amount: u128) -> Result<u128, Error> {
+    let Some(mut link) = load_link(env) else {
+        return Ok(0);
+    };
+    let source = MilestoneFundClient::new(env, &link.source);
+    let slashed = match source.try_slash_collateral(&amount) {
+        Ok(Ok(slashed)) => slashed,
+        _ => return Ok(0),
+    };
+    if slashed == 0 {
+        return Ok(0);
+    }
+    link.slashed = link.slashed.checked_add(slashed).ok_or(Error::Overflow)?;
+    env.storage()
+        .instance()
+        .set(&DataKey::CollateralLink, &link);
+    claims::credit_refund_pool(project, slashed);
+
+    events::publish(
+        env,
+        (symbol_short!("collat"), symbol_short!("slashed")),
+        slashed,
+    );
+    Ok(slashed)
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Creator) Pledges `amount` as collateral for the campaign at
+    /// `campaign`, once every milestone here is complete. The surplus is
+    /// used first and the creator transfers in the rest.
+    pub fn pledge_collateral(env: Env, campaign: Address, amount: u128) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        let mut project = Self::get_project(&env)?;
+        if !project.goal_met || !project.milestones.iter().all(|m| m.is_complete) {
+            return Err(Error::NotSettled);
+        }
+        if amount == 0 || campaign == env.current_contract_address() {
+            return Err(Error::InvalidArgument);
+        }
+        if load_pledge(&env).is_some() {
+            return Err(Error::AlreadyExists);
+        }
+
+        let from_surplus = amount.min(project.surplus);
+        let top_up = amount - from_surplus;
+        if top_up > 0 {
+            auth::require_auth_for_amount(&env, &project.creator, top_up);
+            collect(&env, &project.token, &project.creator, top_up)?;
+        } else {
+            project.creator.require_auth();
+        }
+        project.surplus -= from_surplus;
+        save_project(&env, &project);
+
+        let pledge = CollateralPledge {
+            campaign: campaign.clone(),
+            token: project.token.clone(),
+            quote: project.token,
+            amount,
+            posted_value: amount,
+            slashed: 0,
+            released: false,
+            posted_at: u64::from(env.ledger().sequence()),
+        };
+        env.storage()
+            .instance()
+            .set(&DataKey::CollateralPledge, &pledge);
+
+        events::publish(
+            &env,
+            (symbol_short!("collat"), symbol_short!("pledged")),
+            (campaign, amount),
+        );
+        Ok(())
+    }
+
+    /// (Creator) Pledges `amount` of `asset`, other than the campaign
+    /// token, as collateral for the campaign at `campaign`, once every
+    /// milestone here is complete. `asset` needs a settlement route to be
+    /// sold through when slashed, and is valued through the price oracle.
+    pub fn pledge_collateral_in(
+        env: Env,
+        campaign: Address,
+        asset: Address,
+        amount: u128,
+    ) -> Result<u128, Error> {
+        breaker::ensure_writable(&env)?;
+        let project = Self::get_project(&env)?;
+        if !project.goal_met || !project.milestones.iter().all(|m| m.is_complete) {
+            return Err(Error::NotSettled);
+        }
+        if amount == 0 || campaign == env.current_contract_address() || asset == project.token {
+            return Err(Error::InvalidArgument);
+        }
+        if load_pledge(&env).is_some() {
+            return Err(Error::AlreadyExists);
+        }
+        if Self::get_settlement_route(env.clone(), asset.clone()).is_none() {
+            return Err(Error::NotFound);
+        }
+        let posted_value = haircut_value(&env, &asset, amount, &project.token)?;
+        if posted_value == 0 {
+            return Err(Error::InvalidArgument);
+        }
+
+        auth::require_auth_for_amount(&env, &project.creator, amount);
+        collect(&env, &asset, &project.creator, amount)?;
+        let pledge = CollateralPledge {
+            campaign: campaign.clone(),
+            token: asset,
+            quote: project.token,
+            amount,
+            posted_value,
+            slashed: 0,
+            released: false,
+            posted_at: u64::from(env.ledger().sequence()),
+        };
+        env.storage()
+            .instance()
+            .set(&DataKey::CollateralPledge, &pledge);
+
+        events::publish(
+            &env,
+            (symbol_short!("collat"), symbol_short!("pledged")),
+            (campaign, amount),
+        );
+        Ok(posted_value)
+    }
+
+    /// (Admin) Sets the haircut, in basis points, taken off the oracle value
+    /// of collateral held in another asset. Locked once a pledge is posted.
+    pub fn set_collateral_haircut(env: Env, haircut_bps: u32) -> Result<(), Error> {
+        access::require_admin(&env)?;
+        if u128::from(haircut_bps) >= BPS_DENOMINATOR {
+            return Err(Error::InvalidArgument);
+        }
+        if load_pledge(&env).is_some() {
+            return Err(Error::TermsLocked);
+        }
+        env.storage()
+            .instance()
+            .set(&StorageKey::CollateralHaircut, &haircut_bps);
+        Ok(())
+    }
+
+    /// (Linked campaign) Pays up to `amount` of the pledge to the linked
+    /// campaign, in its token. Returns the amount paid.
+    pub fn slash_collateral(env: Env, amount: u128) -> Result<u128, Error> {
+        breaker::ensure_writable(&env)?;
+        let mut pledge = load_pledge(&env).ok_or(Error::NotFound)?;
+        pledge.campaign.require_auth();
+        if pledge.token != pledge.quote {
+            return slash_foreign(&env, &mut pledge, amount);
+        }
+        let slashed = amount.min(pledge.remaining()?);
+        if slashed > 0 {
+            pledge.slashed = pledge.slashed.checked_add(slashed).ok_or(Error::Overflow)?;
+            env.storage()
+                .instance()
+                .set(&DataKey::CollateralPledge, &pledge);
+            pay_out(&env, &pledge.token, &pledge.campaign, slashed)?;
+        }
+        Ok(slashed)
+    }
+
+    /// (Linked campaign) Returns what is left of the pledge to the creator.
+    pub fn release_collateral(env: Env) -> Result<u128, Error> {
+        breaker::ensure_writable(&env)?;
+        let mut pledge = load_pledge(&env).ok_or(Error::NotFound)?;
+        pledge.campaign.require_auth();
+        let remaining = pledge.remaining()?;
+        pledge.released = true;
+        env.storage()
+            .instance()
+            .set(&DataKey::CollateralPledge, &pledge);
+        if remaining > 0 {
+            let project = Self::get_project(&env)?;
+            pay_out(&env, &pledge.token, &project.creator, remaining)?;
+        }
+
+        events::publish(
+            &env,
+            (symbol_short!("collat"), symbol_short!("released")),
+            remaining,
+        );
+        Ok(remaining)
+    }
+
+    /// (Creator) Takes back what is left of the pledge once its campaign
+    /// has failed or been cancelled, or has not linked it within
+    /// `UNLINKED_TIMEOUT` ledgers of posting. Returns the amount returned.
+    pub fn reclaim_collateral(env: Env) -> Result<u128, Error> {
+        let project = access::require_creator(&env)?;
+        let mut pledge = load_pledge(&env).ok_or(Error::NotFound)?;
+        if pledge.released {
+            return Err(Error::AlreadyExists);
+        }
+        if !is_reclaimable(&env, &pledge) {
+            return Err(Error::NotEligible);
+        }
+        let remaining = pledge.remaining()?;
+        pledge.released = true;
+        env.storage()
+            .instance()
+            .set(&DataKey::CollateralPledge, &pledge);
+        if remaining > 0 {
+            pay_out(&env, &pledge.token, &project.creator, remaining)?;
+        }
+
+        events::publish(
+            &env,
+            (symbol_short!("collat"), symbol_short!("reclaimed")),
+            remaining,
+        );
+        Ok(remaining)
+    }
+
+    /// (Creator) Links the collateral `source` has pledged to this campaign.
+    /// Only possible while the campaign is still raising.
+    pub fn link_collateral(env: Env, source: Address) -> Result<(), Error> {
+        let project = access::require_creator(&env)?;
+        if project.goal_met {
+            return Err(Error::TermsLocked);
+        }
+        if load_link(&env).is_some() {
+            return Err(Error::AlreadyExists);
+        }
+
+        let pledge = MilestoneFundClient::new(&env, &source)
+            .try_get_collateral_pledge()
+            .ok()
+            .and_then(Result::ok)
+            .ok_or(Error::NotFound)?;
+        if pledge.campaign != env.current_contract_address()
+            || pledge.quote != project.token
+            || pledge.remaining()? == 0
+        {
+            return Err(Error::InvalidArgument);
+        }
+
+        let link = CollateralLink { source, slashed: 0 };
+        env.storage()
+            .instance()
+            .set(&DataKey::CollateralLink, &link);
+        Ok(())
+    }
+
+    /// Hands the rest of the linked collateral back once every milestone
+    /// here is complete. Callable by anyone.
+    pub fn return_collateral(env: Env) -> Result<u128, Error> {
+        breaker::ensure_writable(&env)?;
+        let project = Self::get_project(&env)?;
+        let link = load_link(&env).ok_or(Error::NotFound)?;
+        if !project.goal_met || !project.milestones.iter().all(|m| m.is_complete) {
+            return Err(Error::NotSettled);
+        }
+        let returned = MilestoneFundClient::new(&env, &link.source).release_collateral();
+        env.storage().instance().remove(&DataKey::CollateralLink);
+        Ok(returned)
+    }
+
+    /// (View) Gets the collateral this campaign has pledged to another one.
+    pub fn get_collateral_pledge(env: Env) -> Result<CollateralPledge, Error> {
+        load_pledge(&env).ok_or(Error::NotFound)
+    }
+
+    /// (View) Gets the current haircut value, in the linked campaign's
+    /// token, of what is left of the pledge.
+    pub fn get_collateral_value(env: Env) -> Result<u128, Error> {
+        let pledge = load_pledge(&env).ok_or(Error::NotFound)?;
+        haircut_value(&env, &pledge.token, pledge.remaining()?, &pledge.quote)
+    }
+
+    /// (View) Gets the collateral haircut in basis points.
+    pub fn get_collateral_haircut(env: Env) -> u32 {
+        haircut_bps(&env)
+    }
+
+    /// (View) Gets the collateral linked to this campaign.
+    pub fn get_collateral_link(env: Env) -> Result<CollateralLink, Error> {
+        load_link(&env).ok_or(Error::NotFound)
+    }
+}
@@ -0,0 +1,81 @@
+use soroban_sdk::{contractimpl, contracttype, Address, Env, Vec};
+
+use crate::{Error, GovernanceConfig, MilestoneFund, MilestoneFundClient, ProjectSummary};
+
+// --- Portfolio Comparison ---
+// Each campaign is its own contract, so a backer's portfolio is a list of
+// campaign addresses. `compare_projects` reads them all in one simulated
+// call and normalizes the figures dashboards put side by side.
+
+/// Campaigns compared per call, to stay within resource limits.
+const MAX_COMPARED: u32 = 10;
+const LEDGERS_PER_DAY: u64 = 17_280;
+const BPS: u128 = 10_000;
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProjectComparison {
+    pub project: Address,
+    pub progress_bps: u32, // Raised as a share of the goal, uncapped
+    pub days_left: u64,    // Until the funding deadline, rounded up
+    pub health_score: u32,
+    pub governance: GovernanceConfig,
+}
+
+fn compare(
+    env: &Env,
+    project: Address,
+    summary: ProjectSummary,
+    health_score: u32,
+    governance: GovernanceConfig,
+) -> ProjectComparison {
+    let progress = summary.raised.saturating_mul(BPS) / summary.goal;
+    let ledgers_left = summary
+        .deadline
+        .saturating_sub(u64::from(env.ledger().sequence()));
+    ProjectComparison {
+        project,
+        progress_bps: u32::try_from(progress).unwrap_or(u32::MAX),
+        days_left: ledgers_left.div_ceil(LEDGERS_PER_DAY),
+        health_score,
+        governance,
+    }
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (View) Compares up to `MAX_COMPARED` campaigns side by side. Each
+    /// address must be a campaign; this one may be among them.
+    pub fn compare_projects(
+        env: Env,
+        projects: Vec<Address>,
+    ) -> Result<Vec<ProjectComparison>, Error> {
+        if projects.len() > MAX_COMPARED {
+            return Err(Error::CapExceeded);
+        }
+        let mut comparisons = Vec::new(&env);
+        for project in projects.iter() {
+            // A contract cannot call back into itself, so read this one directly
+            let comparison = if project == env.current_contract_address() {
+                compare(
+                    &env,
+                    project,
+                    Self::get_summary(env.clone())?,
+                    Self::get_health(env.clone())?.score,
+                    Self::get_governance_config(env.clone())?,
+                )
+            } else {
+                let client = MilestoneFundClient::new(&env, &project);
+                compare(
+                    &env,
+                    project,
+                    client.get_summary(),
+                    client.get_health().score,
+                    client.get_governance_config(),
+                )
+            };
+            comparisons.push_back(comparison);
+        }
+        Ok(comparisons)
+    }
+}
@@ -0,0 +1,140 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env, Map};
+
+use crate::{
+    access, backers, breaker, events, ttl, Error, MilestoneFund, MilestoneFundClient, Project,
+    StateKey, StorageKey,
+};
+
+// --- Fallback Delegates ---
+// A backer can name another backer as a fallback: once the voting window
+// reaches its cutoff (`cutoff_ledgers` after the funding deadline), the
+// fallback's vote on a milestone counts for the backer too, unless the
+// backer has voted on it themselves. Only direct votes are mirrored; a
+// fallback's own fallback is not followed. Each backer's choice is kept in
+// its own persistent entry; the instance only counts them.
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FallbackDelegation {
+    pub cutoff_ledgers: u64,
+    pub delegates: u32, // Backers who named a fallback
+}
+
+fn load(env: &Env) -> FallbackDelegation {
+    env.storage()
+        .instance()
+        .get(&StorageKey::FallbackDelegation)
+        .unwrap_or(FallbackDelegation {
+            cutoff_ledgers: 0,
+            delegates: 0,
+        })
+}
+
+fn save(env: &Env, delegation: &FallbackDelegation) {
+    env.storage()
+        .instance()
+        .set(&StorageKey::FallbackDelegation, delegation);
+}
+
+/// Whether fallback votes currently count.
+pub(crate) fn is_active(env: &Env, project: &Project) -> bool {
+    let delegation = load(env);
+    let cutoff = project.deadline.saturating_add(delegation.cutoff_ledgers);
+    delegation.delegates > 0 && u64::from(env.ledger().sequence()) >= cutoff
+}
+
+/// The fallback `backer` named, if any.
+fn delegate(env: &Env, backer: &Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&StateKey::FallbackDelegate(backer.clone()))
+}
+
+/// `votes` with each fallback's vote copied to the backers who named them
+/// and have not voted, once the cutoff has passed.
+pub(crate) fn effective_votes(
+    env: &Env,
+    project: &Project,
+    votes: &Map<Address, bool>,
+) -> Map<Address, bool> {
+    if !is_active(env, project) {
+        return votes.clone();
+    }
+    let mut effective = votes.clone();
+    for backer in backers::all(env).iter() {
+        if votes.contains_key(backer.clone()) {
+            continue;
+        }
+        if let Some(approve) = delegate(env, &backer).and_then(|delegate| votes.get(delegate)) {
+            effective.set(backer, approve);
+        }
+    }
+    effective
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Backer) Names the backer whose vote counts for `backer` past the
+    /// cutoff, or clears it with `None`.
+    pub fn set_fallback_delegate(
+        env: Env,
+        backer: Address,
+        delegate: Option<Address>,
+    ) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        backer.require_auth();
+        Self::get_project(&env)?;
+        if !backers::is_backer(&env, &backer) {
+            return Err(Error::NotABacker);
+        }
+
+        let mut delegation = load(&env);
+        let key = StateKey::FallbackDelegate(backer.clone());
+        let had_delegate = env.storage().persistent().has(&key);
+        match delegate.clone() {
+            Some(delegate) => {
+                if delegate == backer || !backers::is_backer(&env, &delegate) {
+                    return Err(Error::InvalidArgument);
+                }
+                ttl::set_persistent(&env, &key, &delegate);
+                if !had_delegate {
+                    delegation.delegates += 1;
+                }
+            }
+            None => {
+                env.storage().persistent().remove(&key);
+                if had_delegate {
+                    delegation.delegates -= 1;
+                }
+            }
+        }
+        save(&env, &delegation);
+
+        events::publish(&env, (symbol_short!("fallback"), backer), delegate);
+        Ok(())
+    }
+
+    /// (Creator) Sets how many ledgers after the funding deadline fallback
+    /// votes start counting. Only possible while the campaign is still
+    /// raising.
+    pub fn set_fallback_cutoff(env: Env, cutoff_ledgers: u64) -> Result<(), Error> {
+        let project = access::require_creator(&env)?;
+        if project.goal_met {
+            return Err(Error::TermsLocked);
+        }
+        let mut delegation = load(&env);
+        delegation.cutoff_ledgers = cutoff_ledgers;
+        save(&env, &delegation);
+        Ok(())
+    }
+
+    /// (View) Gets the fallback cutoff and how many backers named a fallback.
+    pub fn get_fallback_delegation(env: Env) -> FallbackDelegation {
+        load(&env)
+    }
+
+    /// (View) Gets the fallback `backer` named, if any.
+    pub fn get_fallback_delegate(env: Env, backer: Address) -> Option<Address> {
+        delegate(&env, &backer)
+    }
+}
@@ -0,0 +1,59 @@
+use soroban_sdk::{contractimpl, symbol_short, Env, String};
+
+use crate::{
+    access, approved_milestone, breaker, claims, events, evidence, lifecycle, pay_out, platform,
+    release_cap, save_project, settle_release, watch, Error, MilestoneFund, MilestoneFundClient,
+};
+
+#[contractimpl]
+impl MilestoneFund {
+    /// Releases only `amount` of an approved milestone's allocation to the
+    /// creator, returning the remainder to the backers' refund pool.
+    ///
+    /// Only the creator can downscope, and `amount` must be below the
+    /// milestone's allocation. The `reason` is stored on the milestone.
+    pub fn release_partial(
+        env: Env,
+        milestone_index: u32,
+        amount: u128,
+        reason: String,
+    ) -> Result<(), Error> {
+        let mut project = access::require_creator(&env)?;
+
+        evidence::apply_penalty(&env, &mut project, milestone_index)?;
+        let mut milestone = approved_milestone(&env, &project, milestone_index)?;
+        if amount >= milestone.amount_to_release {
+            return Err(Error::InvalidArgument);
+        }
+        if breaker::trip_on_divergence(&env, &project)? {
+            return Ok(());
+        }
+
+        let remainder = milestone.amount_to_release - amount;
+        milestone.is_complete = true;
+        milestone.escrow.released = amount;
+        milestone.escrow.refunded = remainder;
+        milestone.downscope_reason = Some(reason.clone());
+        project.milestones.set(milestone_index, milestone);
+        claims::credit_refund_pool(&mut project, remainder);
+        lifecycle::advance(&env, &mut project);
+        save_project(&env, &project);
+
+        let payable = release_cap::admit(
+            &env,
+            settle_release(&env, &project, milestone_index, amount)?,
+        );
+        if payable > 0 {
+            pay_out(&env, &project.token, &project.creator, payable)?;
+        }
+        platform::report(&env, &project);
+
+        let topics = (symbol_short!("downscope"), milestone_index);
+        events::publish(&env, topics, (amount, remainder, reason));
+        let topics = (symbol_short!("release"), project.creator);
+        events::publish(&env, topics, amount);
+        watch::publish_transition(&env, symbol_short!("released"), milestone_index);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,200 @@
+use soroban_sdk::{contractimpl, symbol_short, vec, Address, Env, String, Vec};
+
+use crate::{
+    access, breaker, events, factory, governance, projects, save_project, ttl, upgrade, DataKey,
+    Error, Milestone, MilestoneEscrow, MilestoneFund, MilestoneFundClient, Project, ProjectState,
+    StorageKey,
+};
+
+// --- Chunked Initialization ---
+// A campaign with dozens of milestones may not fit `initialize` into one
+// transaction. `create_project` stores the project without milestones as a
+// draft, `add_milestones_chunk` appends them over as many calls as needed,
+// and `open_funding` runs the checks `initialize` would have (a non-empty
+// list summing to the goal) and ends the draft. Funding is rejected while
+// the project is a draft. Called on a factory, `create_project` deploys a
+// campaign holding the draft instead and returns its project ID.
+//
+// While drafting, the creator can also take milestones out of the plan and
+// put them back at any position. Removed milestones wait in a bin until
+// restored or until funding opens; the list itself always stays compact,
+// so indices only ever refer to milestones in the plan.
+
+/// Whether the project is still a draft.
+pub(crate) fn is_drafting(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::Drafting)
+}
+
+fn removed(env: &Env) -> Vec<Milestone> {
+    env.storage()
+        .instance()
+        .get(&StorageKey::RemovedMilestones)
+        .unwrap_or_else(|| vec![env])
+}
+
+fn save_removed(env: &Env, removed: &Vec<Milestone>) {
+    if removed.is_empty() {
+        env.storage()
+            .instance()
+            .remove(&StorageKey::RemovedMilestones);
+    } else {
+        env.storage()
+            .instance()
+            .set(&StorageKey::RemovedMilestones, removed);
+    }
+}
+
+/// Loads the draft project for a creator edit.
+fn draft(env: &Env) -> Result<Project, Error> {
+    let project = MilestoneFund::get_project(env)?;
+    project.creator.require_auth();
+    if !is_drafting(env) {
+        return Err(Error::TermsLocked);
+    }
+    Ok(project)
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// Creates a draft project with no milestones yet and returns its
+    /// project ID. On a campaign instance it can only be called once, in
+    /// place of `initialize`, and the ID is 0; on a factory it deploys a new
+    /// campaign holding the draft, whose milestones are then added there.
+    pub fn create_project(
+        env: Env,
+        admin: Address,
+        creator: Address,
+        token: Address,
+        goal: u128,
+        deadline: u64,
+    ) -> Result<u32, Error> {
+        if factory::is_factory(&env) {
+            return factory::create_draft(&env, &admin, creator, token, goal, deadline);
+        }
+        if env.storage().instance().has(&DataKey::ProjectInfo) {
+            return Err(Error::ProjectAlreadyInitialized);
+        }
+        if deadline <= u64::from(env.ledger().sequence()) {
+            return Err(Error::DeadlineMustBeInFuture);
+        }
+        if goal == 0 {
+            return Err(Error::GoalMustBePositive);
+        }
+
+        let project = Project {
+            creator,
+            token,
+            goal,
+            raised: 0,
+            deadline,
+            start_ledger: 0,
+            milestones: vec![&env],
+            goal_met: false,
+            surplus: 0,
+            refund_pool: 0,
+            refund_pool_claimed: 0,
+            state: ProjectState::Funding,
+            approval_threshold_bps: governance::DEFAULT_APPROVAL_THRESHOLD_BPS,
+            quorum_bps: 0,
+        };
+        save_project(&env, &project);
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::CreatedAt, &u64::from(env.ledger().sequence()));
+        upgrade::record_version(&env);
+        env.storage().instance().set(&DataKey::Drafting, &true);
+        ttl::extend_instance(&env);
+        Ok(projects::STANDALONE_PROJECT_ID)
+    }
+
+    /// (Creator) Appends milestone titles and amounts to a draft project.
+    pub fn add_milestones_chunk(env: Env, chunk: Vec<(String, u128)>) -> Result<u32, Error> {
+        breaker::ensure_writable(&env)?;
+        let mut project = draft(&env)?;
+        if chunk.is_empty() {
+            return Err(Error::MilestoneListEmpty);
+        }
+
+        for (title, amount) in chunk.iter() {
+            project.milestones.push_back(Milestone {
+                title,
+                amount_to_release: amount,
+                is_complete: false,
+                is_rejected: false,
+                is_failed: false,
+                escrow: MilestoneEscrow::default(),
+                downscope_reason: None,
+            });
+        }
+        save_project(&env, &project);
+        Ok(project.milestones.len())
+    }
+
+    /// (Creator) Takes the milestone at `index` out of a draft's plan,
+    /// shifting the later ones down, and keeps it for `restore_milestone`.
+    /// Returns the number of milestones left in the plan.
+    pub fn remove_milestone(env: Env, index: u32) -> Result<u32, Error> {
+        breaker::ensure_writable(&env)?;
+        let mut project = draft(&env)?;
+        let milestone = project
+            .milestones
+            .get(index)
+            .ok_or(Error::MilestoneInvalidIndex)?;
+        project.milestones.remove(index);
+        let mut bin = removed(&env);
+        bin.push_back(milestone);
+        save_removed(&env, &bin);
+        save_project(&env, &project);
+        Ok(project.milestones.len())
+    }
+
+    /// (Creator) Puts removed milestone number `removed_index` (see
+    /// `get_removed_milestones`) back into a draft's plan at `index`,
+    /// shifting the later ones up. Returns the number of milestones in the plan.
+    pub fn restore_milestone(env: Env, removed_index: u32, index: u32) -> Result<u32, Error> {
+        breaker::ensure_writable(&env)?;
+        let mut project = draft(&env)?;
+        let mut bin = removed(&env);
+        let milestone = bin.get(removed_index).ok_or(Error::NotFound)?;
+        if index > project.milestones.len() {
+            return Err(Error::MilestoneInvalidIndex);
+        }
+        bin.remove(removed_index);
+        project.milestones.insert(index, milestone);
+        save_removed(&env, &bin);
+        save_project(&env, &project);
+        Ok(project.milestones.len())
+    }
+
+    /// (View) Gets the milestones removed from the draft and not restored.
+    pub fn get_removed_milestones(env: Env) -> Vec<Milestone> {
+        removed(&env)
+    }
+
+    /// (Creator) Ends the draft once its milestones sum to the goal, opening
+    /// the project for funding.
+    pub fn open_funding(env: Env) -> Result<(), Error> {
+        let project = access::require_creator(&env)?;
+        if !is_drafting(&env) {
+            return Err(Error::AlreadyExists);
+        }
+        if project.milestones.is_empty() {
+            return Err(Error::MilestoneListEmpty);
+        }
+        let mut total: u128 = 0;
+        for milestone in project.milestones.iter() {
+            total = total
+                .checked_add(milestone.amount_to_release)
+                .ok_or(Error::AmountTooLarge)?;
+        }
+        if total != project.goal {
+            return Err(Error::MilestoneAmountsMismatchGoal);
+        }
+
+        env.storage().instance().remove(&DataKey::Drafting);
+        save_removed(&env, &vec![&env]);
+        events::publish(&env, (symbol_short!("opened"),), project.milestones.len());
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+use soroban_sdk::{contractimpl, symbol_short, Env};
+
+use crate::{access, events, Error, MilestoneFund, MilestoneFundClient, StateKey};
+
+// --- Emergency Pause ---
+// When a vulnerability is discovered the admin needs a brake that does not
+// depend on the books diverging first. `pause` stops contributions, votes
+// and releases on every path that leads to them, failing with `Paused`,
+// until `unpause`. Unlike the creator's funding pauses it does not move
+// the deadline, and refunds and claims stay open so backers are never
+// locked in.
+
+pub(crate) fn is_paused(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&StateKey::Paused)
+        .unwrap_or(false)
+}
+
+/// Fails with `Paused` while the admin has paused the contract.
+pub(crate) fn ensure_not_paused(env: &Env) -> Result<(), Error> {
+    if is_paused(env) {
+        return Err(Error::Paused);
+    }
+    Ok(())
+}
+
+fn set_paused(env: &Env, paused: bool) -> Result<(), Error> {
+    access::require_admin(env)?;
+    if is_paused(env) == paused {
+        return Err(Error::AlreadyExists);
+    }
+    env.storage().instance().set(&StateKey::Paused, &paused);
+    Ok(())
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Admin) Pauses contributions, votes and releases.
+    pub fn pause(env: Env) -> Result<(), Error> {
+        set_paused(&env, true)?;
+        events::publish(&env, (symbol_short!("paused"),), true);
+        Ok(())
+    }
+
+    /// (Admin) Lifts the pause.
+    pub fn unpause(env: Env) -> Result<(), Error> {
+        set_paused(&env, false)?;
+        events::publish(&env, (symbol_short!("paused"),), false);
+        Ok(())
+    }
+
+    /// (View) Checks whether the admin has paused the contract.
+    pub fn is_paused(env: Env) -> bool {
+        is_paused(&env)
+    }
+}
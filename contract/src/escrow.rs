@@ -0,0 +1,226 @@
+use soroban_sdk::{contractimpl, contracttype, Env, Vec};
+
+use crate::{
+    bounty, budgets, cancellation, collateral, fees, rebates, release_cap, remainder, Error,
+    MilestoneFund, MilestoneFundClient, Project,
+};
+
+// --- Milestone Escrow ---
+// Every token the contract holds is attributed to exactly one bucket: a
+// milestone's escrow, the surplus, the outstanding refund or remainder
+// pool, the fee pool or an institution's unallocated budget. Contributions
+// fill milestone escrows in order up to their allocation; anything beyond
+// the last allocation lands in the surplus. Tokens leave a milestone's
+// escrow only as a release to the creator, a refund or a forfeiture to the
+// refund pool.
+
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MilestoneEscrow {
+    pub allocated: u128, // Contributions earmarked for the milestone
+    pub released: u128,  // Paid to the creator
+    pub refunded: u128,  // Returned to backers (e.g. a downscope remainder)
+    pub forfeited: u128, // Moved to backers as a penalty
+}
+
+impl MilestoneEscrow {
+    /// Tokens still held for the milestone.
+    pub fn balance(&self) -> Result<u128, Error> {
+        let settled = checked_sum([self.released, self.refunded, self.forfeited].into_iter())?;
+        Ok(self.allocated.saturating_sub(settled))
+    }
+}
+
+// Where every token held by the contract is attributed
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct EscrowBreakdown {
+    pub milestones: Vec<u128>, // Balance held per milestone, by index
+    pub surplus: u128,
+    pub refund_pool: u128, // Credited to backers but not yet claimed
+    pub fee_pool: u128,
+    pub budgets: u128,
+    pub collateral: u128,    // Pledged to another campaign
+    pub bounties: u128,      // Attached to milestones by backers
+    pub stake: u128,         // Creator's stake behind the cancellation fee
+    pub queued: u128,        // Released to the creator, waiting under the release cap
+    pub remainder: u128,     // Unreleased funds owed to backers after a failure mid-way
+    pub platform_fees: u128, // Taken from payouts, not yet withdrawn
+}
+
+impl EscrowBreakdown {
+    /// Tokens the contract should hold across all buckets.
+    pub fn total(&self) -> Result<u128, Error> {
+        let buckets = [
+            self.surplus,
+            self.refund_pool,
+            self.fee_pool,
+            self.budgets,
+            self.collateral,
+            self.bounties,
+            self.stake,
+            self.queued,
+            self.remainder,
+            self.platform_fees,
+        ];
+        let total = checked_sum(buckets.into_iter())?;
+        total
+            .checked_add(checked_sum(self.milestones.iter())?)
+            .ok_or(Error::Overflow)
+    }
+}
+
+/// Sum of `amounts`, failing with `Overflow` rather than wrapping.
+fn checked_sum(mut amounts: impl Iterator<Item = u128>) -> Result<u128, Error> {
+    amounts.try_fold(0u128, |total, amount| {
+        total.checked_add(amount).ok_or(Error::Overflow)
+    })
+}
+
+/// Earmarks a fresh contribution: milestones first, in order, then surplus.
+pub(crate) fn earmark(project: &mut Project, amount: u128) -> Result<(), Error> {
+    let mut remaining = amount;
+    for index in 0..project.milestones.len() {
+        if remaining == 0 {
+            break;
+        }
+        let mut milestone = project.milestones.get_unchecked(index);
+        let target = milestone
+            .amount_to_release
+            .checked_add(milestone.escrow.forfeited)
+            .ok_or(Error::Overflow)?;
+        let gap = target.saturating_sub(milestone.escrow.allocated);
+        if gap == 0 {
+            continue;
+        }
+        let earmarked = gap.min(remaining);
+        milestone.escrow.allocated = milestone
+            .escrow
+            .allocated
+            .checked_add(earmarked)
+            .ok_or(Error::Overflow)?;
+        project.milestones.set(index, milestone);
+        remaining -= earmarked;
+    }
+    project.surplus = project
+        .surplus
+        .checked_add(remaining)
+        .ok_or(Error::Overflow)?;
+    Ok(())
+}
+
+/// Takes a withdrawn contribution of `amount` back out of the earmarks,
+/// surplus first, then milestone allocations from the last one back, so
+/// later contributions fill the milestones again.
+pub(crate) fn unearmark(project: &mut Project, amount: u128) -> Result<(), Error> {
+    let from_surplus = project.surplus.min(amount);
+    project.surplus -= from_surplus;
+    let mut remaining = amount - from_surplus;
+    for index in (0..project.milestones.len()).rev() {
+        if remaining == 0 {
+            break;
+        }
+        let mut milestone = project.milestones.get_unchecked(index);
+        let taken = milestone.escrow.balance()?.min(remaining);
+        milestone.escrow.allocated -= taken;
+        project.milestones.set(index, milestone);
+        remaining -= taken;
+    }
+    Ok(())
+}
+
+/// Books a refund of `amount` straight to a backer, undoing `earmark`:
+/// surplus first, then milestone escrows from the last one back.
+pub(crate) fn refund(project: &mut Project, amount: u128) -> Result<(), Error> {
+    let from_surplus = project.surplus.min(amount);
+    project.surplus -= from_surplus;
+    let mut remaining = amount - from_surplus;
+    for index in (0..project.milestones.len()).rev() {
+        if remaining == 0 {
+            break;
+        }
+        let mut milestone = project.milestones.get_unchecked(index);
+        let refunded = milestone.escrow.balance()?.min(remaining);
+        milestone.escrow.refunded += refunded;
+        project.milestones.set(index, milestone);
+        remaining -= refunded;
+    }
+    Ok(())
+}
+
+pub(crate) fn breakdown(env: &Env, project: &Project) -> Result<EscrowBreakdown, Error> {
+    let mut milestones = Vec::new(env);
+    for milestone in project.milestones.iter() {
+        milestones.push_back(milestone.escrow.balance()?);
+    }
+    Ok(EscrowBreakdown {
+        milestones,
+        surplus: project.surplus,
+        refund_pool: project.refund_pool - project.refund_pool_claimed,
+        fee_pool: rebates::fee_pool(env),
+        budgets: budgets::budgets_held(env),
+        collateral: collateral::pledged(env)?,
+        bounties: bounty::held(env, project)?,
+        stake: cancellation::stake_held(env),
+        queued: release_cap::queued(env),
+        remainder: remainder::outstanding(env),
+        platform_fees: fees::held(env),
+    })
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (View) Gets how the contract's token balance is attributed.
+    pub fn get_escrow(env: Env) -> Result<EscrowBreakdown, Error> {
+        let project = Self::get_project(&env)?;
+        breakdown(&env, &project)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_sum_adds_every_bucket() {
+        assert_eq!(checked_sum([].into_iter()), Ok(0));
+        assert_eq!(checked_sum([1, 2, 3].into_iter()), Ok(6));
+        assert_eq!(checked_sum([u128::MAX, 0].into_iter()), Ok(u128::MAX));
+        assert_eq!(checked_sum([u128::MAX - 1, 1].into_iter()), Ok(u128::MAX));
+    }
+
+    #[test]
+    fn checked_sum_overflows_at_max() {
+        assert_eq!(
+            checked_sum([u128::MAX, 1].into_iter()),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            checked_sum([u128::MAX / 2, u128::MAX / 2, 2].into_iter()),
+            Err(Error::Overflow)
+        );
+    }
+
+    #[test]
+    fn balance_nets_out_every_settlement() {
+        let escrow = MilestoneEscrow {
+            allocated: 100,
+            released: 30,
+            refunded: 20,
+            forfeited: 10,
+        };
+        assert_eq!(escrow.balance(), Ok(40));
+        assert_eq!(MilestoneEscrow::default().balance(), Ok(0));
+    }
+
+    #[test]
+    fn balance_overflows_at_max() {
+        let escrow = MilestoneEscrow {
+            allocated: u128::MAX,
+            released: u128::MAX,
+            refunded: 1,
+            forfeited: 0,
+        };
+        assert_eq!(escrow.balance(), Err(Error::Overflow));
+    }
+}
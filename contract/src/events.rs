@@ -0,0 +1,45 @@
+use soroban_sdk::{contractimpl, Env, IntoVal, Symbol, Val, Vec};
+
+use crate::{access, DataKey, Error, MilestoneFund, MilestoneFundClient};
+
+// --- Event Tenancy ---
+// Platforms sharing one deployment register their platform id as a tenant
+// topic. It is appended to the topics of every event the project publishes,
+// so multi-tenant indexers can filter the event stream by tenant without
+// decoding payloads. All events go through `publish` for this reason.
+
+fn tenant(env: &Env) -> Option<Symbol> {
+    env.storage().instance().get(&DataKey::EventTenant)
+}
+
+/// Publishes an event, with the tenant topic appended if one is registered.
+pub(crate) fn publish<T, D>(env: &Env, topics: T, data: D)
+where
+    T: IntoVal<Env, Vec<Val>>,
+    D: IntoVal<Env, Val>,
+{
+    let mut topics: Vec<Val> = topics.into_val(env);
+    if let Some(tenant) = tenant(env) {
+        topics.push_back(tenant.into_val(env));
+    }
+    env.events().publish(topics, data);
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Admin) Sets the platform id appended to every event's topics, or
+    /// removes it with `None`.
+    pub fn set_event_tenant(env: Env, tenant: Option<Symbol>) -> Result<(), Error> {
+        access::require_admin(&env)?;
+        match tenant {
+            Some(tenant) => env.storage().instance().set(&DataKey::EventTenant, &tenant),
+            None => env.storage().instance().remove(&DataKey::EventTenant),
+        }
+        Ok(())
+    }
+
+    /// (View) Gets the tenant topic appended to every event, if any.
+    pub fn get_event_tenant(env: Env) -> Option<Symbol> {
+        tenant(&env)
+    }
+}
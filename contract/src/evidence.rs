@@ -0,0 +1,208 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Bytes, BytesN, Env};
+
+use crate::{
+    access, breaker, claims, collateral, events, health, save_project, stream, ttl, watch, DataKey,
+    Error, MilestoneFund, MilestoneFundClient, Project,
+};
+
+// --- Milestone Evidence Deadlines ---
+// The creator commits to a ledger by which evidence of each milestone's
+// delivery will be submitted. Windows and the penalty rate can only be
+// configured while the campaign is still raising, so backers know the terms
+// they pledge under. Once the goal is met, a missed window moves
+// `penalty_bps` of the milestone's allocation from the creator's future
+// release into the backers' refund pool, either through the permissionless
+// `enforce_evidence_deadline` or automatically when the milestone is released.
+
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MilestoneEvidence {
+    pub deadline: Option<u64>,   // Ledger sequence evidence is due by
+    pub evidence: Option<Bytes>, // 32-byte hash of the submitted evidence
+    pub submitted_at: Option<u64>,
+    pub penalty: u128, // Allocation moved to the refund pool
+}
+
+const BPS_DENOMINATOR: u128 = 10_000;
+
+pub(crate) fn load(env: &Env, milestone_index: u32) -> MilestoneEvidence {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Evidence(milestone_index))
+        .unwrap_or_default()
+}
+
+pub(crate) fn save(env: &Env, milestone_index: u32, record: &MilestoneEvidence) {
+    ttl::set_persistent(env, &DataKey::Evidence(milestone_index), record);
+}
+
+fn penalty_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::EvidencePenaltyBps)
+        .unwrap_or(0)
+}
+
+pub(crate) fn is_overdue(env: &Env, record: &MilestoneEvidence) -> bool {
+    let Some(deadline) = record.deadline else {
+        return false;
+    };
+    match record.submitted_at {
+        Some(submitted_at) => submitted_at > deadline,
+        None => u64::from(env.ledger().sequence()) > deadline,
+    }
+}
+
+/// Applies the missed-deadline penalty to an open milestone if it is due
+/// and not yet applied. Persists the project when a penalty is applied.
+/// Returns the amount moved to the refund pool.
+pub(crate) fn apply_penalty(
+    env: &Env,
+    project: &mut Project,
+    milestone_index: u32,
+) -> Result<u128, Error> {
+    let Some(mut milestone) = project.milestones.get(milestone_index) else {
+        return Ok(0);
+    };
+    let mut record = load(env, milestone_index);
+    if !project.goal_met
+        || milestone.is_complete
+        || record.penalty > 0
+        || stream::is_streaming(env, milestone_index)
+        || !is_overdue(env, &record)
+    {
+        return Ok(0);
+    }
+    let penalty = milestone
+        .amount_to_release
+        .checked_mul(u128::from(penalty_bps(env)))
+        .ok_or(Error::Overflow)?
+        / BPS_DENOMINATOR;
+    if penalty == 0 {
+        return Ok(0);
+    }
+
+    milestone.amount_to_release -= penalty;
+    milestone.escrow.forfeited += penalty;
+    project.milestones.set(milestone_index, milestone);
+    claims::credit_refund_pool(project, penalty);
+    collateral::slash_linked(env, project, penalty)?;
+    save_project(env, project);
+    record.penalty = penalty;
+    save(env, milestone_index, &record);
+
+    health::record_dispute(env);
+
+    events::publish(env, (symbol_short!("penalty"), milestone_index), penalty);
+    watch::publish_transition(env, symbol_short!("penalized"), milestone_index);
+    Ok(penalty)
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Creator) Sets the ledger by which evidence for a milestone is due.
+    /// Only possible while the campaign is still raising.
+    pub fn set_evidence_deadline(
+        env: Env,
+        milestone_index: u32,
+        deadline: u64,
+    ) -> Result<(), Error> {
+        let project = access::require_creator(&env)?;
+        if project.goal_met {
+            return Err(Error::TermsLocked);
+        }
+        if project.milestones.get(milestone_index).is_none() {
+            return Err(Error::MilestoneInvalidIndex);
+        }
+        if deadline <= u64::from(env.ledger().sequence()) {
+            return Err(Error::DeadlineMustBeInFuture);
+        }
+
+        let mut record = load(&env, milestone_index);
+        record.deadline = Some(deadline);
+        save(&env, milestone_index, &record);
+        Ok(())
+    }
+
+    /// (Creator) Sets the share of a milestone's allocation, in basis points,
+    /// forfeited to backers when its evidence is late. Only possible while the
+    /// campaign is still raising.
+    pub fn set_evidence_penalty(env: Env, penalty_bps: u32) -> Result<(), Error> {
+        let project = access::require_creator(&env)?;
+        if project.goal_met {
+            return Err(Error::TermsLocked);
+        }
+        if u128::from(penalty_bps) > BPS_DENOMINATOR {
+            return Err(Error::InvalidArgument);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::EvidencePenaltyBps, &penalty_bps);
+        Ok(())
+    }
+
+    /// (Creator) Submits the hash of the evidence for an open milestone.
+    pub fn submit_evidence(
+        env: Env,
+        milestone_index: u32,
+        evidence: BytesN<32>,
+    ) -> Result<(), Error> {
+        let project = access::require_creator(&env)?;
+        let milestone = project
+            .milestones
+            .get(milestone_index)
+            .ok_or(Error::MilestoneInvalidIndex)?;
+        if milestone.is_complete {
+            return Err(Error::MilestoneAlreadyCompleted);
+        }
+
+        let mut record = load(&env, milestone_index);
+        if record.evidence.is_some() {
+            return Err(Error::AlreadyExists);
+        }
+        record.evidence = Some(evidence.clone().into());
+        record.submitted_at = Some(u64::from(env.ledger().sequence()));
+        save(&env, milestone_index, &record);
+
+        events::publish(&env, (symbol_short!("evidence"), milestone_index), evidence);
+        Ok(())
+    }
+
+    /// Applies the penalty for a milestone whose evidence deadline was
+    /// missed. Callable by anyone.
+    pub fn enforce_evidence_deadline(env: Env, milestone_index: u32) -> Result<u128, Error> {
+        breaker::ensure_writable(&env)?;
+        let mut project = Self::get_project(&env)?;
+        if !project.goal_met {
+            return Err(Error::GoalNotMet);
+        }
+        let milestone = project
+            .milestones
+            .get(milestone_index)
+            .ok_or(Error::MilestoneInvalidIndex)?;
+        if milestone.is_complete {
+            return Err(Error::MilestoneAlreadyCompleted);
+        }
+        let record = load(&env, milestone_index);
+        if record.deadline.is_none() {
+            return Err(Error::NotFound);
+        }
+        if record.penalty > 0 {
+            return Err(Error::AlreadyExists);
+        }
+        if !is_overdue(&env, &record) {
+            return Err(Error::NotDue);
+        }
+        apply_penalty(&env, &mut project, milestone_index)
+    }
+
+    /// (View) Gets the evidence record of a milestone.
+    pub fn get_evidence(env: Env, milestone_index: u32) -> MilestoneEvidence {
+        load(&env, milestone_index)
+    }
+
+    /// (View) Gets the configured evidence penalty in basis points.
+    pub fn get_evidence_penalty(env: Env) -> u32 {
+        penalty_bps(&env)
+    }
+}
@@ -0,0 +1,245 @@
+use soroban_sdk::{contractimpl, contracttype, xdr::ToXdr, Address, Bytes, Env, Vec};
+
+use crate::{
+    backers, breaker, claims, rebates, votes, watch, Error, Milestone, MilestoneFund,
+    MilestoneFundClient, Project,
+};
+
+// --- State Export ---
+// A new indexer can bootstrap the complete contract state from simulated
+// reads: start with `export_state_chunk(None)` and keep passing the returned
+// cursor until it is `None`. Milestones are followed by the votes cast on
+// each, and the backer and vote sections are read a page at a time from
+// their indexes, so a chunk costs the same however many backers there are.
+// Each chunk is the XDR encoding of a
+// `StateChunk`, and map-backed sections are emitted in key order, so the
+// same state always exports to the same bytes.
+// LP routes are configuration keyed by share token and are not exported.
+
+const ENTRIES_PER_CHUNK: u32 = 50;
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StateSection {
+    Header,
+    Milestones,
+    Votes(u32), // Milestone index
+    Backers,
+    Watchers,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StateCursor {
+    pub section: StateSection,
+    pub offset: u32,
+}
+
+// Project-wide scalars and configuration
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct StateHeader {
+    pub ledger: u32, // Sequence the chunk was read at
+    pub admin: Address,
+    pub creator: Address,
+    pub token: Address,
+    pub goal: u128,
+    pub raised: u128,
+    pub deadline: u64,
+    pub start_ledger: u64,
+    pub goal_met: bool,
+    pub surplus: u128,
+    pub refund_pool: u128,
+    pub refund_pool_claimed: u128,
+    pub fee_pool: u128,
+    pub vote_rebate: u128,
+    pub breaker_tripped: bool,
+    pub breaker_tolerance: u128,
+    pub milestone_count: u32,
+    pub backer_count: u32,
+    pub watcher_count: u32,
+}
+
+// A backer's vote on a milestone
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct VoteRecord {
+    pub backer: Address,
+    pub approve: bool,
+}
+
+// Everything the contract stores about a single backer
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BackerRecord {
+    pub backer: Address,
+    pub contributed: u128,
+    pub pool_claimed: u128,
+    pub votes_cast: u32,
+    pub rebate_claimed: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum StateChunk {
+    Header(StateHeader),
+    Milestone(u32, Milestone),
+    Votes(u32, Vec<VoteRecord>), // Milestone index, votes
+    Backers(Vec<BackerRecord>),
+    Watchers(Vec<Address>),
+}
+
+fn next_cursor(section: StateSection, offset: u32) -> Option<StateCursor> {
+    Some(StateCursor { section, offset })
+}
+
+fn header(env: &Env, admin: Address, project: &Project) -> StateHeader {
+    StateHeader {
+        ledger: env.ledger().sequence(),
+        admin,
+        creator: project.creator.clone(),
+        token: project.token.clone(),
+        goal: project.goal,
+        raised: project.raised,
+        deadline: project.deadline,
+        start_ledger: project.start_ledger,
+        goal_met: project.goal_met,
+        surplus: project.surplus,
+        refund_pool: project.refund_pool,
+        refund_pool_claimed: project.refund_pool_claimed,
+        fee_pool: rebates::fee_pool(env),
+        vote_rebate: rebates::rebate_amount(env),
+        breaker_tripped: breaker::is_tripped(env),
+        breaker_tolerance: breaker::tolerance(env),
+        milestone_count: project.milestones.len(),
+        backer_count: backers::count(env),
+        watcher_count: watch::watcher_count(env),
+    }
+}
+
+fn vote_page(env: &Env, milestone_index: u32, start: u32, end: u32) -> Vec<VoteRecord> {
+    let mut records = Vec::new(env);
+    for backer in votes::voters(env, milestone_index, start, end).iter() {
+        if let Some(approve) = votes::get(env, milestone_index, &backer) {
+            records.push_back(VoteRecord { backer, approve });
+        }
+    }
+    records
+}
+
+fn backer_page(env: &Env, start: u32, end: u32) -> Vec<BackerRecord> {
+    let mut records = Vec::new(env);
+    for backer in backers::range(env, start, end).iter() {
+        records.push_back(BackerRecord {
+            contributed: backers::amount(env, &backer),
+            pool_claimed: claims::claimed(env, &backer),
+            votes_cast: rebates::votes_cast(env, &backer),
+            rebate_claimed: rebates::rebate_claimed(env, &backer),
+            backer,
+        });
+    }
+    records
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (View) Exports one deterministic chunk of the contract state.
+    ///
+    /// Pass `None` to start; returns the XDR-encoded `StateChunk` and the
+    /// cursor of the next chunk, or `None` once the export is complete.
+    pub fn export_state_chunk(
+        env: Env,
+        cursor: Option<StateCursor>,
+    ) -> Result<(Bytes, Option<StateCursor>), Error> {
+        let project = Self::get_project(&env)?;
+        let mut at = cursor.unwrap_or(StateCursor {
+            section: StateSection::Header,
+            offset: 0,
+        });
+        // A draft has no milestones, so its milestone and vote sections are
+        // empty and done as soon as they start
+        let milestone_section = matches!(
+            at.section,
+            StateSection::Milestones | StateSection::Votes(_)
+        );
+        if project.milestones.is_empty() && milestone_section && at.offset == 0 {
+            at = StateCursor {
+                section: StateSection::Backers,
+                offset: 0,
+            };
+        }
+
+        let (chunk, next) = match at.section {
+            StateSection::Header => {
+                let admin = Self::get_admin(env.clone())?;
+                let chunk = StateChunk::Header(header(&env, admin, &project));
+                let next = if project.milestones.is_empty() {
+                    next_cursor(StateSection::Backers, 0)
+                } else {
+                    next_cursor(StateSection::Milestones, 0)
+                };
+                (chunk, next)
+            }
+            StateSection::Milestones => {
+                let milestone = project
+                    .milestones
+                    .get(at.offset)
+                    .ok_or(Error::InvalidArgument)?;
+                let next = if at.offset + 1 < project.milestones.len() {
+                    next_cursor(StateSection::Milestones, at.offset + 1)
+                } else {
+                    next_cursor(StateSection::Votes(0), 0)
+                };
+                (StateChunk::Milestone(at.offset, milestone), next)
+            }
+            StateSection::Votes(index) => {
+                if index >= project.milestones.len() {
+                    return Err(Error::InvalidArgument);
+                }
+                let total = votes::count(&env, index);
+                if at.offset > total {
+                    return Err(Error::InvalidArgument);
+                }
+                let end = (at.offset + ENTRIES_PER_CHUNK).min(total);
+                let next = if end < total {
+                    next_cursor(StateSection::Votes(index), end)
+                } else if index + 1 < project.milestones.len() {
+                    next_cursor(StateSection::Votes(index + 1), 0)
+                } else {
+                    next_cursor(StateSection::Backers, 0)
+                };
+                let chunk = StateChunk::Votes(index, vote_page(&env, index, at.offset, end));
+                (chunk, next)
+            }
+            StateSection::Backers => {
+                let total = backers::count(&env);
+                if at.offset > total {
+                    return Err(Error::InvalidArgument);
+                }
+                let end = (at.offset + ENTRIES_PER_CHUNK).min(total);
+                let next = if end < total {
+                    next_cursor(StateSection::Backers, end)
+                } else {
+                    next_cursor(StateSection::Watchers, 0)
+                };
+                (StateChunk::Backers(backer_page(&env, at.offset, end)), next)
+            }
+            StateSection::Watchers => {
+                let keys = watch::load_watchers(&env).keys();
+                let total = keys.len();
+                if at.offset > total {
+                    return Err(Error::InvalidArgument);
+                }
+                let end = (at.offset + ENTRIES_PER_CHUNK).min(total);
+                let next = if end < total {
+                    next_cursor(StateSection::Watchers, end)
+                } else {
+                    None
+                };
+                (StateChunk::Watchers(keys.slice(at.offset..end)), next)
+            }
+        };
+
+        Ok((chunk.to_xdr(&env), next))
+    }
+}
@@ -0,0 +1,261 @@
+use soroban_sdk::{
+    contractimpl, contracttype, symbol_short, Address, BytesN, Env, Map, String, Vec,
+};
+
+use crate::{
+    breaker, events, governance, platform, projects, registry, ttl, upgrade, DataKey, Error,
+    MilestoneFund, MilestoneFundClient, StorageKey,
+};
+
+// --- Campaign Factory ---
+// An instance initialized as a factory hosts no campaign of its own.
+// Instead, creators call it to deploy a fresh campaign instance from the
+// factory's own code, initialized with the factory's admin, and the factory
+// keeps a numbered registry of every campaign it deployed. The number seeds
+// the deployment salt, so campaign addresses are predictable from the
+// factory address and the campaign count, and is the project ID the
+// campaign is reached by through the factory (see `projects`).
+//
+// Backers of several failed campaigns can sweep their refunds through the
+// factory with `claim_all_refunds`, which walks the registry a few
+// campaigns per call from a per-backer cursor and claims each refund on
+// the backer's behalf. Campaigns that never raised anything can be purged
+// through the factory as well (see `cleanup`).
+
+/// Most campaigns returned by one `list_campaigns` page.
+const MAX_PAGE: u32 = 50;
+/// Most campaigns visited by one `claim_all_refunds` call.
+const MAX_SWEEP: u32 = 10;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FactoryConfig {
+    pub wasm_hash: BytesN<32>, // Code campaigns are deployed from
+    pub campaigns: u32,        // Number of campaigns deployed so far
+}
+
+/// Outcome of one `claim_all_refunds` call.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundSweep {
+    pub paid: Map<Address, i128>, // Token -> total refunded in this call
+    pub claims: u32,              // Campaigns that paid a refund
+    pub next: u32,                // Campaign number the next call starts at
+    pub done: bool,               // Whether this call reached the last campaign
+}
+
+fn load(env: &Env) -> Option<FactoryConfig> {
+    env.storage().instance().get(&StorageKey::Factory)
+}
+
+pub(crate) fn is_factory(env: &Env) -> bool {
+    env.storage().instance().has(&StorageKey::Factory)
+}
+
+/// What `backer` still has booked in a campaign, zero if it cannot be read.
+fn contribution(client: &MilestoneFundClient, backer: &Address) -> u128 {
+    match client.try_get_backer_info(backer, backer) {
+        Ok(Ok(amount)) => amount,
+        _ => 0,
+    }
+}
+
+fn salt(env: &Env, id: u32) -> BytesN<32> {
+    let mut salt = [0u8; 32];
+    salt[28..].copy_from_slice(&id.to_be_bytes());
+    BytesN::from_array(env, &salt)
+}
+
+/// Deploys a campaign for `creator`, has `init` initialize it with the
+/// factory's admin, and registers it under the next project ID. Returns the
+/// ID and the campaign's address.
+fn deploy(
+    env: &Env,
+    creator: Address,
+    token: &Address,
+    init: impl FnOnce(&MilestoneFundClient, &Address),
+) -> Result<(u32, Address), Error> {
+    breaker::ensure_writable(env)?;
+    creator.require_auth();
+    let mut config = load(env).ok_or(Error::FeatureDisabled)?;
+    if !registry::is_allowed(env, token) {
+        return Err(Error::NotEligible);
+    }
+
+    let id = config.campaigns;
+    let campaign = env
+        .deployer()
+        .with_current_contract(salt(env, id))
+        .deploy(config.wasm_hash.clone());
+    let client = MilestoneFundClient::new(env, &campaign);
+    init(&client, &MilestoneFund::get_admin(env.clone())?);
+    client.set_factory(&env.current_contract_address(), &id);
+    platform::register(env, &campaign, &client.get_project());
+
+    config.campaigns = config.campaigns.checked_add(1).ok_or(Error::Overflow)?;
+    env.storage().instance().set(&StorageKey::Factory, &config);
+    projects::register(env, id, &campaign);
+
+    let topics = (symbol_short!("factory"), symbol_short!("deployed"), creator);
+    events::publish(env, topics, (id, campaign.clone()));
+    Ok((id, campaign))
+}
+
+/// Deploys a campaign for `creator` as a draft project, the way
+/// `create_project` creates one in place, and returns its project ID. The
+/// campaign is administered by the factory's admin, who `admin` must be.
+pub(crate) fn create_draft(
+    env: &Env,
+    admin: &Address,
+    creator: Address,
+    token: Address,
+    goal: u128,
+    deadline: u64,
+) -> Result<u32, Error> {
+    if *admin != MilestoneFund::get_admin(env.clone())? {
+        return Err(Error::InvalidArgument);
+    }
+    let draft = |client: &MilestoneFundClient, admin: &Address| {
+        client.create_project(admin, &creator, &token, &goal, &deadline);
+    };
+    let (id, _) = deploy(env, creator.clone(), &token, draft)?;
+    Ok(id)
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// Initializes this instance as a factory deploying campaigns from
+    /// `wasm_hash`, which should be this contract's own code. Can only be
+    /// called once, and not on an instance hosting a campaign.
+    pub fn initialize_factory(
+        env: Env,
+        admin: Address,
+        wasm_hash: BytesN<32>,
+    ) -> Result<(), Error> {
+        let storage = env.storage().instance();
+        if storage.has(&DataKey::ProjectInfo) || storage.has(&DataKey::Admin) {
+            return Err(Error::ProjectAlreadyInitialized);
+        }
+        storage.set(&DataKey::Admin, &admin);
+        storage.set(
+            &StorageKey::Factory,
+            &FactoryConfig {
+                wasm_hash,
+                campaigns: 0,
+            },
+        );
+        upgrade::record_version(&env);
+        Ok(())
+    }
+
+    /// (Creator) Deploys and initializes a new campaign, with the arguments
+    /// of `initialize` and the factory's admin, and records this factory as
+    /// its parent, which the creator's authorization covers. Returns its
+    /// address; its project ID is the factory's campaign count before the
+    /// call.
+    pub fn deploy_campaign(
+        env: Env,
+        creator: Address,
+        token: Address,
+        goal: u128,
+        deadline: u64,
+        milestones: Vec<(String, u128)>,
+    ) -> Result<Address, Error> {
+        let initialize = |client: &MilestoneFundClient, admin: &Address| {
+            client.initialize(
+                admin,
+                &creator,
+                &token,
+                &goal,
+                &deadline,
+                &milestones,
+                &governance::DEFAULT_APPROVAL_THRESHOLD_BPS,
+                &0,
+            );
+        };
+        let (_, campaign) = deploy(&env, creator.clone(), &token, initialize)?;
+        Ok(campaign)
+    }
+
+    /// (View) Gets the factory settings, if this instance is a factory.
+    pub fn get_factory(env: Env) -> Option<FactoryConfig> {
+        load(&env)
+    }
+
+    /// (View) Gets the address of the factory's campaign number `id`.
+    pub fn get_campaign(env: Env, id: u32) -> Option<Address> {
+        projects::address(&env, id)
+    }
+
+    /// (View) Lists up to `limit` (at most 50) deployed campaigns from
+    /// number `start` on, in deployment order.
+    pub fn list_campaigns(env: Env, start: u32, limit: u32) -> Result<Vec<Address>, Error> {
+        let config = load(&env).ok_or(Error::FeatureDisabled)?;
+        if limit > MAX_PAGE {
+            return Err(Error::CapExceeded);
+        }
+        let end = config.campaigns.min(start.saturating_add(limit));
+        let mut campaigns = Vec::new(&env);
+        for id in start..end {
+            if let Some(campaign) = Self::get_campaign(env.clone(), id) {
+                campaigns.push_back(campaign);
+            }
+        }
+        Ok(campaigns)
+    }
+
+    /// (Backer) Claims `backer`'s refunds from the next campaigns (at most
+    /// 10) of the factory's registry, carrying on where their previous call
+    /// stopped; past the last campaign the cursor wraps back to the first.
+    /// Campaigns not refunding, or where `backer` has nothing to claim, are
+    /// skipped. Returns what was paid, summed per token.
+    pub fn claim_all_refunds(env: Env, backer: Address) -> Result<RefundSweep, Error> {
+        breaker::ensure_writable(&env)?;
+        backer.require_auth();
+        let config = load(&env).ok_or(Error::FeatureDisabled)?;
+        let key = StorageKey::RefundCursor(backer.clone());
+        let start: u32 = env.storage().persistent().get(&key).unwrap_or(0);
+        let end = config.campaigns.min(start.saturating_add(MAX_SWEEP));
+
+        let mut paid: Map<Address, i128> = Map::new(&env);
+        let mut claims = 0;
+        for id in start..end {
+            let Some(campaign) = Self::get_campaign(env.clone(), id) else {
+                continue;
+            };
+            let client = MilestoneFundClient::new(&env, &campaign);
+            let before = contribution(&client, &backer);
+            if before == 0 || !matches!(client.try_claim_refund(&id, &backer), Ok(Ok(()))) {
+                continue;
+            }
+            let refunded = before - contribution(&client, &backer);
+            if refunded == 0 {
+                continue;
+            }
+            // The campaign cannot report a claim made from here back to us
+            Self::sync_campaign(env.clone(), id)?;
+            let amount = i128::try_from(refunded).map_err(|_| Error::AmountTooLarge)?;
+            let token = client.get_project().token;
+            let total = paid
+                .get(token.clone())
+                .unwrap_or(0)
+                .checked_add(amount)
+                .ok_or(Error::Overflow)?;
+            paid.set(token, total);
+            claims += 1;
+        }
+        let done = end >= config.campaigns;
+        let next = if done { 0 } else { end };
+        ttl::set_persistent(&env, &key, &next);
+
+        let sweep = RefundSweep {
+            paid,
+            claims,
+            next,
+            done,
+        };
+        let topics = (symbol_short!("factory"), symbol_short!("refunds"), backer);
+        events::publish(&env, topics, sweep.paid.clone());
+        Ok(sweep)
+    }
+}
@@ -0,0 +1,138 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env};
+
+use crate::{
+    access, breaker, events, pay_out, Error, MilestoneFund, MilestoneFundClient, StateKey,
+};
+
+// --- Platform Fee ---
+// A platform hosting the campaign can take a share of every milestone
+// payout: releases, downscoped releases, settled releases and stream
+// installments alike. The fee is taken after the insurance premium and
+// accrues in the contract until the fee recipient withdraws it. The admin
+// sets it before the first contribution, so creators and backers know it
+// when the campaign raises.
+
+const BPS_DENOMINATOR: u128 = 10_000;
+/// Largest fee the admin can set, in basis points.
+const MAX_FEE_BPS: u32 = 1_000;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlatformFee {
+    pub recipient: Address,
+    pub bps: u32,        // Share of every payout
+    pub accrued: u128,   // Taken from payouts, all time
+    pub withdrawn: u128, // Part of `accrued` already paid to the recipient
+}
+
+fn load(env: &Env) -> Option<PlatformFee> {
+    env.storage().instance().get(&StateKey::PlatformFee)
+}
+
+fn save(env: &Env, fee: &PlatformFee) {
+    env.storage().instance().set(&StateKey::PlatformFee, fee);
+}
+
+/// Fees taken but not yet withdrawn.
+pub(crate) fn held(env: &Env) -> u128 {
+    load(env).map_or(0, |fee| fee.accrued - fee.withdrawn)
+}
+
+/// Takes the platform fee out of a payout of `amount`. Returns the amount
+/// left for the payee.
+pub(crate) fn deduct(env: &Env, amount: u128) -> Result<u128, Error> {
+    let Some(mut fee) = load(env) else {
+        return Ok(amount);
+    };
+    let charged = charge(amount, fee.bps)?;
+    if charged == 0 {
+        return Ok(amount);
+    }
+    fee.accrued = fee.accrued.checked_add(charged).ok_or(Error::Overflow)?;
+    save(env, &fee);
+
+    events::publish(env, (symbol_short!("fee"), fee.recipient), charged);
+    Ok(amount - charged)
+}
+
+/// Fee of `bps` basis points on a payout of `amount`.
+fn charge(amount: u128, bps: u32) -> Result<u128, Error> {
+    Ok(amount.checked_mul(u128::from(bps)).ok_or(Error::Overflow)? / BPS_DENOMINATOR)
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Admin) Sets the share of every milestone payout sent to
+    /// `recipient`, in basis points (at most 1 000). Only possible before
+    /// the first contribution.
+    pub fn set_platform_fee(env: Env, recipient: Address, bps: u32) -> Result<(), Error> {
+        access::require_admin(&env)?;
+        if bps > MAX_FEE_BPS {
+            return Err(Error::InvalidArgument);
+        }
+        if Self::get_project(&env)?.raised > 0 {
+            return Err(Error::TermsLocked);
+        }
+        save(
+            &env,
+            &PlatformFee {
+                recipient,
+                bps,
+                accrued: 0,
+                withdrawn: 0,
+            },
+        );
+        Ok(())
+    }
+
+    /// (Fee recipient) Pays the recipient the fees accrued so far. Returns
+    /// the amount paid.
+    pub fn withdraw_fees(env: Env) -> Result<u128, Error> {
+        breaker::ensure_writable(&env)?;
+        let mut fee = load(&env).ok_or(Error::FeatureDisabled)?;
+        fee.recipient.require_auth();
+        let project = Self::get_project(&env)?;
+        let amount = fee.accrued - fee.withdrawn;
+        if amount == 0 {
+            return Err(Error::InsufficientFunds);
+        }
+        if breaker::trip_on_divergence(&env, &project)? {
+            return Ok(0);
+        }
+
+        fee.withdrawn = fee.accrued;
+        save(&env, &fee);
+        pay_out(&env, &project.token, &fee.recipient, amount)?;
+
+        events::publish(&env, (symbol_short!("fee_out"), fee.recipient), amount);
+        Ok(amount)
+    }
+
+    /// (View) Gets the platform fee and its running totals, if one is set.
+    pub fn get_platform_fee(env: Env) -> Option<PlatformFee> {
+        load(&env)
+    }
+
+    /// (View) Gets the fees accrued and not yet withdrawn.
+    pub fn get_accrued_fees(env: Env) -> u128 {
+        held(&env)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn charge_takes_the_share() {
+        assert_eq!(charge(1_000, 250), Ok(25));
+        assert_eq!(charge(u128::MAX, 0), Ok(0));
+        assert_eq!(charge(u128::MAX / 10_000, 10_000), Ok(u128::MAX / 10_000));
+    }
+
+    #[test]
+    fn charge_overflows_at_max() {
+        assert_eq!(charge(u128::MAX, 2), Err(Error::Overflow));
+        assert_eq!(charge(u128::MAX / 2 + 1, 2), Err(Error::Overflow));
+    }
+}
@@ -0,0 +1,247 @@
+//! Canonical campaign states for integration tests.
+//!
+//! Every constructor registers a fresh contract in the given test `Env`,
+//! mocks all auths and drives it through real contract calls, so the
+//! resulting state is exactly what a live deployment would hold. Addresses
+//! are generated from the `Env`'s deterministic PRNG, so the same sequence
+//! of constructor calls always yields the same fixtures.
+//!
+//! Late-campaign states that would take thousands of calls to reach (see
+//! `crowded`) are written to storage directly instead, through
+//! `Fixture::edit_project` and `Fixture::seed_contributions`, and the ledger
+//! can be fast-forwarded with `Fixture::advance_ledgers`.
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String, Vec,
+};
+
+use crate::{record_contribution, save_project, MilestoneFund, MilestoneFundClient, Project};
+
+/// Ledger sequence every fixture starts at.
+pub const START_LEDGER: u32 = 100;
+/// Funding deadline (ledger sequence) of every fixture project.
+pub const DEADLINE: u64 = START_LEDGER as u64 + 1_000;
+/// Funding goal of every fixture project.
+pub const GOAL: u128 = 1_000;
+/// Amount credited by a single `fund` call.
+pub const CONTRIBUTION: u128 = 100;
+/// Seconds the close time moves per ledger in `Fixture::advance_ledgers`.
+pub const LEDGER_SECONDS: u64 = 5;
+/// TTL every entry starts with, so fast-forwarding never archives state.
+pub const ENTRY_TTL: u32 = 10_000_000;
+
+/// A registered, initialized campaign plus the actors that shaped it.
+pub struct Fixture {
+    pub env: Env,
+    pub client: MilestoneFundClient<'static>,
+    pub admin: Address,
+    pub creator: Address,
+    pub token: Address,
+    /// Backers in the order they first contributed.
+    pub backers: Vec<Address>,
+}
+
+impl Fixture {
+    /// Mints `CONTRIBUTION` to `backer` and calls `fund` with it, `times` times.
+    pub fn contribute(&self, backer: &Address, times: u32) {
+        let amount = CONTRIBUTION as i128;
+        let token = token::StellarAssetClient::new(&self.env, &self.token);
+        for _ in 0..times {
+            token.mint(backer, &amount);
+            self.client.fund(&0, backer, &amount, &None);
+        }
+    }
+
+    /// Moves the ledger to the first sequence after the funding deadline.
+    pub fn pass_deadline(&self) {
+        self.env.ledger().set_sequence_number(DEADLINE as u32 + 1);
+    }
+
+    /// Fast-forwards the ledger by `ledgers` sequence numbers, moving the
+    /// close time along by `LEDGER_SECONDS` per ledger.
+    pub fn advance_ledgers(&self, ledgers: u32) {
+        self.env.ledger().with_mut(|info| {
+            info.sequence_number += ledgers;
+            info.timestamp += u64::from(ledgers) * LEDGER_SECONDS;
+        });
+    }
+
+    /// Applies `edit` to the stored project, bypassing every entrypoint
+    /// check. The direct write is not metered against the test's budget.
+    pub fn edit_project(&self, edit: impl FnOnce(&mut Project)) {
+        self.env.as_contract(&self.client.address, || {
+            let mut project = MilestoneFund::get_project(&self.env).unwrap();
+            edit(&mut project);
+            save_project(&self.env, &project);
+        });
+        self.env.budget().reset_default();
+    }
+
+    /// Books each `(backer, amount)` through the same bookkeeping `fund`
+    /// runs (backer index, ticker, stats, vote weight, lifecycle state),
+    /// skipping only the auth and the token pull, and mints the matching
+    /// tokens to the contract so its balance agrees with the books.
+    pub fn seed_contributions(&self, contributions: &Vec<(Address, u128)>) {
+        let mut total: u128 = 0;
+        self.env.as_contract(&self.client.address, || {
+            for (backer, amount) in contributions.iter() {
+                let project = MilestoneFund::get_project(&self.env).unwrap();
+                record_contribution(&self.env, project, &backer, amount).unwrap();
+                total += amount;
+            }
+        });
+        self.env.budget().reset_default();
+        token::StellarAssetClient::new(&self.env, &self.token)
+            .mint(&self.client.address, &(total as i128));
+    }
+}
+
+/// A freshly initialized project with two milestones (400 + 600) and no
+/// contributions yet.
+pub fn initialized(env: &Env) -> Fixture {
+    env.mock_all_auths();
+    env.ledger().with_mut(|info| {
+        info.sequence_number = START_LEDGER;
+        info.min_persistent_entry_ttl = ENTRY_TTL;
+        info.min_temp_entry_ttl = ENTRY_TTL;
+        info.max_entry_ttl = ENTRY_TTL;
+    });
+
+    let admin = Address::generate(env);
+    let creator = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(creator.clone())
+        .address();
+    let contract_id = env.register_contract(None, MilestoneFund);
+    let client = MilestoneFundClient::new(env, &contract_id);
+
+    let milestones = vec![
+        env,
+        (String::from_str(env, "Prototype"), 400u128),
+        (String::from_str(env, "Launch"), 600u128),
+    ];
+    client.initialize(
+        &admin,
+        &creator,
+        &token,
+        &GOAL,
+        &DEADLINE,
+        &milestones,
+        &5_000,
+        &0,
+    );
+
+    Fixture {
+        env: env.clone(),
+        client,
+        admin,
+        creator,
+        token,
+        backers: Vec::new(env),
+    }
+}
+
+/// Three backers have contributed 300, 200 and 100; the goal is not met.
+pub fn mid_funding(env: &Env) -> Fixture {
+    let mut fixture = initialized(env);
+    for times in [3, 2, 1] {
+        let backer = Address::generate(env);
+        fixture.contribute(&backer, times);
+        fixture.backers.push_back(backer);
+    }
+    fixture
+}
+
+/// Four backers have contributed 400, 300, 200 and 100, meeting the goal.
+pub fn goal_met(env: &Env) -> Fixture {
+    let mut fixture = initialized(env);
+    for times in [4, 3, 2, 1] {
+        let backer = Address::generate(env);
+        fixture.contribute(&backer, times);
+        fixture.backers.push_back(backer);
+    }
+    fixture
+}
+
+/// `count` backers share the goal equally, the last one also taking the
+/// remainder, all seeded directly rather than through `fund`. A `count` of
+/// zero yields the `initialized` fixture.
+pub fn crowded(env: &Env, count: u32) -> Fixture {
+    let mut fixture = initialized(env);
+    if count == 0 {
+        return fixture;
+    }
+    let share = GOAL / u128::from(count);
+    let mut contributions = Vec::new(env);
+    for index in 0..count {
+        let backer = Address::generate(env);
+        let amount = if index + 1 == count {
+            GOAL - share * u128::from(count - 1)
+        } else {
+            share
+        };
+        contributions.push_back((backer.clone(), amount));
+        fixture.backers.push_back(backer);
+    }
+    fixture.seed_contributions(&contributions);
+    fixture
+}
+
+/// The goal is met and milestone 0 has a contested tally: the 300 backer
+/// voted for it, which is short of the majority needed to release.
+pub fn disputed(env: &Env) -> Fixture {
+    let fixture = goal_met(env);
+    fixture
+        .client
+        .vote(&0, &fixture.backers.get_unchecked(1), &0, &true);
+    fixture
+}
+
+/// The deadline has passed with only 600 of the 1 000 goal raised, so
+/// backers can claim refunds.
+pub fn refunding(env: &Env) -> Fixture {
+    let fixture = mid_funding(env);
+    fixture.pass_deadline();
+    fixture
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ProjectState;
+
+    #[test]
+    fn crowded_books_what_fund_would() {
+        let env = Env::default();
+        let fixture = crowded(&env, 3);
+        let project = fixture.client.get_project();
+        assert_eq!(project.raised, GOAL);
+        assert!(project.goal_met);
+        assert_eq!(fixture.client.get_state(), ProjectState::Succeeded);
+        assert_eq!(fixture.client.get_backer_count(), 3);
+        assert_eq!(fixture.client.get_recent_contributions().len(), 3);
+        let balance = token::Client::new(&env, &fixture.token).balance(&fixture.client.address);
+        assert_eq!(balance, GOAL as i128);
+    }
+
+    #[test]
+    fn crowded_with_no_backers_is_initialized() {
+        let env = Env::default();
+        let fixture = crowded(&env, 0);
+        assert_eq!(fixture.client.get_project().raised, 0);
+        assert_eq!(fixture.client.get_state(), ProjectState::Funding);
+    }
+
+    #[test]
+    fn fund_and_seed_reach_the_same_state() {
+        let env = Env::default();
+        let funded = goal_met(&env);
+        let seeded = crowded(&env, 4);
+        assert_eq!(funded.client.get_state(), seeded.client.get_state());
+        assert_eq!(
+            funded.client.get_project().raised,
+            seeded.client.get_project().raised
+        );
+    }
+}
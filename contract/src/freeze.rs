@@ -0,0 +1,107 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, BytesN, Env, Vec};
+
+use crate::{breaker, events, ttl, Error, MilestoneFund, MilestoneFundClient, StorageKey};
+
+// --- Milestone Freeze ---
+// A fraud report against one milestone should not halt the whole
+// campaign. The reconcilers (the admin alone when none are appointed) can
+// freeze a single milestone while they investigate: voting on it and
+// releasing it fail with `MilestoneFrozen` until the freeze runs out, at
+// most `MAX_FREEZE_LEDGERS` after it was set, or is lifted early. The
+// report itself stays off-chain; the freeze records its hash, and every
+// freeze and unfreeze is published.
+
+/// Longest a milestone can stay frozen, about a week of ledgers.
+const MAX_FREEZE_LEDGERS: u64 = 120_960;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestoneFreeze {
+    pub reason_hash: BytesN<32>, // Hash of the fraud report under investigation
+    pub frozen_at: u64,
+    pub until: u64, // First ledger the milestone is no longer frozen at
+}
+
+fn load(env: &Env, milestone_index: u32) -> Option<MilestoneFreeze> {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::MilestoneFreeze(milestone_index))
+}
+
+/// Whether a milestone is frozen right now.
+pub(crate) fn is_frozen(env: &Env, milestone_index: u32) -> bool {
+    load(env, milestone_index)
+        .is_some_and(|freeze| u64::from(env.ledger().sequence()) < freeze.until)
+}
+
+/// Fails with `MilestoneFrozen` while a milestone is frozen.
+pub(crate) fn ensure_not_frozen(env: &Env, milestone_index: u32) -> Result<(), Error> {
+    if is_frozen(env, milestone_index) {
+        return Err(Error::MilestoneFrozen);
+    }
+    Ok(())
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Reconcilers) Freezes a milestone for `ledgers`, at most about a
+    /// week, pending the investigation of the report hashed in
+    /// `reason_hash`. Freezing a frozen milestone replaces its freeze.
+    pub fn freeze_milestone(
+        env: Env,
+        approvers: Vec<Address>,
+        milestone_index: u32,
+        reason_hash: BytesN<32>,
+        ledgers: u64,
+    ) -> Result<u64, Error> {
+        breaker::ensure_writable(&env)?;
+        breaker::require_reconcilers(&env, &approvers)?;
+        let project = Self::get_project(&env)?;
+        let milestone = project
+            .milestones
+            .get(milestone_index)
+            .ok_or(Error::MilestoneInvalidIndex)?;
+        if milestone.is_complete {
+            return Err(Error::MilestoneAlreadyCompleted);
+        }
+        if ledgers == 0 || ledgers > MAX_FREEZE_LEDGERS {
+            return Err(Error::InvalidArgument);
+        }
+
+        let now = u64::from(env.ledger().sequence());
+        let freeze = MilestoneFreeze {
+            reason_hash,
+            frozen_at: now,
+            until: now + ledgers,
+        };
+        ttl::set_persistent(&env, &StorageKey::MilestoneFreeze(milestone_index), &freeze);
+
+        let topics = (symbol_short!("freeze"), milestone_index);
+        events::publish(&env, topics, (freeze.reason_hash, freeze.until));
+        Ok(freeze.until)
+    }
+
+    /// (Reconcilers) Lifts a milestone's freeze before it runs out.
+    pub fn unfreeze_milestone(
+        env: Env,
+        approvers: Vec<Address>,
+        milestone_index: u32,
+    ) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        breaker::require_reconcilers(&env, &approvers)?;
+        if !is_frozen(&env, milestone_index) {
+            return Err(Error::NotFound);
+        }
+        env.storage()
+            .persistent()
+            .remove(&StorageKey::MilestoneFreeze(milestone_index));
+
+        events::publish(&env, (symbol_short!("unfreeze"), milestone_index), ());
+        Ok(())
+    }
+
+    /// (View) Gets a milestone's current or most recent freeze.
+    pub fn get_milestone_freeze(env: Env, milestone_index: u32) -> Option<MilestoneFreeze> {
+        load(&env, milestone_index)
+    }
+}
@@ -0,0 +1,146 @@
+use soroban_sdk::{contractimpl, contracttype, Env};
+
+use crate::{
+    bulk_vote, rejection, retro, succession, timelock,
+    weighting::{self, WeightingMode},
+    Error, MilestoneFund, MilestoneFundClient, Project,
+};
+
+// --- Governance Parameters ---
+// The rules votes are decided by are spread over constants, defaults and
+// several storage entries. `get_governance_config` gathers them into one
+// struct so dashboards and simulators read a single, typed answer.
+
+/// Approval weight a milestone needs unless the project sets its own, as a
+/// share of the raised amount in basis points. The threshold is exclusive:
+/// exactly half does not pass.
+pub(crate) const DEFAULT_APPROVAL_THRESHOLD_BPS: u32 = 5_000;
+/// Two thirds of the raised weight, rounded up to a whole basis point. Both
+/// enforced by `clears_supermajority` and reported by the config view.
+const SUPERMAJORITY_BPS: u32 = 6_667;
+const BPS_DENOMINATOR: u128 = 10_000;
+
+/// Checks a project's approval threshold and quorum: the threshold at
+/// least a simple majority and passable, the quorum at most everyone.
+pub(crate) fn validate_rules(approval_threshold_bps: u32, quorum_bps: u32) -> Result<(), Error> {
+    if approval_threshold_bps < DEFAULT_APPROVAL_THRESHOLD_BPS
+        || u128::from(approval_threshold_bps) >= BPS_DENOMINATOR
+        || u128::from(quorum_bps) > BPS_DENOMINATOR
+    {
+        return Err(Error::InvalidArgument);
+    }
+    Ok(())
+}
+
+/// `weight` and `bps` basis points of `whole` on a common scale, so a
+/// threshold is one comparison. Fails with `Overflow` rather than
+/// saturating, which would misjudge weights near `u128::MAX`.
+pub(crate) fn scaled(weight: u128, whole: u128, bps: u32) -> Result<(u128, u128), Error> {
+    let weight = weight.checked_mul(BPS_DENOMINATOR).ok_or(Error::Overflow)?;
+    let needed = whole.checked_mul(u128::from(bps)).ok_or(Error::Overflow)?;
+    Ok((weight, needed))
+}
+
+/// Whether `weight` of `raised` clears the project's approval threshold.
+pub(crate) fn clears_threshold(
+    project: &Project,
+    weight: u128,
+    raised: u128,
+) -> Result<bool, Error> {
+    let (weight, needed) = scaled(weight, raised, project.approval_threshold_bps)?;
+    Ok(weight > needed)
+}
+
+/// Whether `weight` of `raised` is a two-thirds supermajority, i.e. at
+/// least `SUPERMAJORITY_BPS` of it.
+pub(crate) fn clears_supermajority(weight: u128, raised: u128) -> Result<bool, Error> {
+    let (weight, needed) = scaled(weight, raised, SUPERMAJORITY_BPS)?;
+    Ok(weight >= needed)
+}
+
+/// Whether `turnout`, the weight voting either way, of `raised` meets the
+/// project's quorum.
+pub(crate) fn meets_quorum(project: &Project, turnout: u128, raised: u128) -> Result<bool, Error> {
+    let (turnout, needed) = scaled(turnout, raised, project.quorum_bps)?;
+    Ok(turnout >= needed)
+}
+
+// When vote weights are read
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SnapshotPolicy {
+    Live, // Weights at the time of the tally
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GovernanceConfig {
+    pub approval_threshold_bps: u32, // Exclusive share of raised weight to release a milestone
+    pub rejection_threshold_bps: u32, // Exclusive share of raised weight to reject a milestone
+    pub quorum_bps: u32,             // Minimum turnout, 0 when none
+    pub supermajority_bps: u32,      // Migration, succession and upgrade votes
+    pub veto_ledgers: u64,           // Window in which the creator can cancel a succession
+    pub timelock_ledgers: u64,       // Delay between approval and release
+    pub voting_window_ledgers: Option<u64>, // After the deadline; None when open until release
+    pub weighting: WeightingMode,
+    pub snapshot: SnapshotPolicy,
+    pub bulk_vote_strict: bool,
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (View) Gets every parameter votes are decided by.
+    pub fn get_governance_config(env: Env) -> Result<GovernanceConfig, Error> {
+        let project = Self::get_project(&env)?;
+        Ok(GovernanceConfig {
+            approval_threshold_bps: project.approval_threshold_bps,
+            rejection_threshold_bps: rejection::threshold_bps(&env),
+            quorum_bps: project.quorum_bps,
+            supermajority_bps: SUPERMAJORITY_BPS,
+            veto_ledgers: succession::CHALLENGE_WINDOW,
+            timelock_ledgers: timelock::ledgers(&env),
+            voting_window_ledgers: retro::voting_ledgers(&env),
+            weighting: weighting::mode(&env),
+            snapshot: SnapshotPolicy::Live,
+            bulk_vote_strict: bulk_vote::is_strict(&env),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn supermajority_is_two_thirds() {
+        assert_eq!(clears_supermajority(6_667, 10_000), Ok(true));
+        assert_eq!(clears_supermajority(6_666, 10_000), Ok(false));
+        // Exactly two thirds falls short of the rounded-up threshold
+        assert_eq!(clears_supermajority(2, 3), Ok(false));
+        assert_eq!(clears_supermajority(1, 2), Ok(false));
+        assert_eq!(clears_supermajority(0, 0), Ok(true));
+        let unit = u128::MAX / 100_000_000;
+        assert_eq!(clears_supermajority(unit * 6_667, unit * 10_000), Ok(true));
+    }
+
+    #[test]
+    fn supermajority_overflows_at_max() {
+        // Saturating, half of u128::MAX would have passed as two thirds
+        assert_eq!(
+            clears_supermajority(u128::MAX / 2, u128::MAX),
+            Err(Error::Overflow)
+        );
+        assert_eq!(clears_supermajority(u128::MAX, 1), Err(Error::Overflow));
+    }
+
+    #[test]
+    fn scaled_overflows_at_max() {
+        assert_eq!(scaled(1, 2, 5_000), Ok((10_000, 10_000)));
+        assert_eq!(
+            scaled(u128::MAX / 10_000, 0, 0),
+            Ok((u128::MAX / 10_000 * 10_000, 0))
+        );
+        assert_eq!(scaled(u128::MAX, 1, 5_000), Err(Error::Overflow));
+        assert_eq!(scaled(1, u128::MAX, 2), Err(Error::Overflow));
+    }
+}
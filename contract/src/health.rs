@@ -0,0 +1,127 @@
+use soroban_sdk::{contractimpl, contracttype, Env};
+
+use crate::{evidence, pruning, DataKey, Error, MilestoneFund, MilestoneFundClient, Project};
+
+// --- Project Health ---
+// A deterministic ranking signal computed from stored metrics only, so any
+// listing page reading it at the same ledger gets the same answer. All
+// components are basis points (0..=10_000); components that do not apply
+// yet are `None` and left out of the overall score rather than guessed.
+
+const BPS: u128 = 10_000;
+/// Deducted from the overall score for every recorded dispute.
+const DISPUTE_PENALTY_BPS: u32 = 1_000;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectHealth {
+    pub score: u32,
+    pub funding_pace_bps: u32, // Funding progress relative to time elapsed
+    pub on_time_bps: Option<u32>, // Evidence delivered by its deadline
+    pub participation_bps: Option<u32>, // Average approval weight per milestone
+    pub dispute_count: u32,
+}
+
+fn ratio_bps(part: u128, whole: u128) -> u32 {
+    if whole == 0 {
+        return 0;
+    }
+    (part.saturating_mul(BPS) / whole).min(BPS) as u32
+}
+
+pub(crate) fn dispute_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::DisputeCount)
+        .unwrap_or(0)
+}
+
+/// Counts an enforcement action taken against the creator.
+pub(crate) fn record_dispute(env: &Env) {
+    env.storage()
+        .instance()
+        .set(&DataKey::DisputeCount, &(dispute_count(env) + 1));
+}
+
+fn funding_pace(env: &Env, project: &Project) -> u32 {
+    if project.goal_met {
+        return BPS as u32;
+    }
+    let created_at: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::CreatedAt)
+        .unwrap_or(0);
+    // The funding window opens at the launch if one was scheduled
+    let opened_at = created_at.max(project.start_ledger);
+    let now = u64::from(env.ledger().sequence()).min(project.deadline);
+    let window = project.deadline.saturating_sub(opened_at);
+    let elapsed = now.saturating_sub(opened_at).max(1);
+    // Raised share of the goal, scaled to what the full window would yield
+    let projected = project.raised.saturating_mul(u128::from(window)) / u128::from(elapsed);
+    ratio_bps(projected, project.goal)
+}
+
+fn on_time(env: &Env, project: &Project) -> Option<u32> {
+    let mut resolved: u128 = 0;
+    let mut punctual: u128 = 0;
+    for index in 0..project.milestones.len() {
+        let record = evidence::load(env, index);
+        let Some(deadline) = record.deadline else {
+            continue;
+        };
+        match record.submitted_at {
+            Some(submitted_at) => {
+                resolved += 1;
+                if submitted_at <= deadline {
+                    punctual += 1;
+                }
+            }
+            None if evidence::is_overdue(env, &record) => resolved += 1,
+            None => {}
+        }
+    }
+    (resolved > 0).then(|| ratio_bps(punctual, resolved))
+}
+
+fn participation(env: &Env, project: &Project) -> Option<u32> {
+    if !project.goal_met || project.raised == 0 {
+        return None;
+    }
+    let mut total: u128 = 0;
+    for index in 0..project.milestones.len() {
+        let weight = pruning::tally(env, project, index).approve_weight;
+        total += u128::from(ratio_bps(weight, project.raised));
+    }
+    Some((total / u128::from(project.milestones.len())) as u32)
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (View) Gets the project's composite health score and its components.
+    pub fn get_health(env: Env) -> Result<ProjectHealth, Error> {
+        let project = Self::get_project(&env)?;
+
+        let funding_pace_bps = funding_pace(&env, &project);
+        let on_time_bps = on_time(&env, &project);
+        let participation_bps = participation(&env, &project);
+        let dispute_count = dispute_count(&env);
+
+        let mut sum = funding_pace_bps;
+        let mut components = 1;
+        for component in [on_time_bps, participation_bps].into_iter().flatten() {
+            sum += component;
+            components += 1;
+        }
+        let score =
+            (sum / components).saturating_sub(dispute_count.saturating_mul(DISPUTE_PENALTY_BPS));
+
+        Ok(ProjectHealth {
+            score,
+            funding_pace_bps,
+            on_time_bps,
+            participation_bps,
+            dispute_count,
+        })
+    }
+}
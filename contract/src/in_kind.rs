@@ -0,0 +1,137 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env};
+
+use crate::{
+    access, backers, breaker, escrow, events, lifecycle, oracle, pay_out, platform, rewards,
+    save_project, Error, MilestoneFund, MilestoneFundClient, Project, ProjectState, StorageKey,
+};
+
+// --- In-Kind Refunds ---
+// When a campaign with a reward escrow fails, the reward tokens are of no
+// use to anyone where they sit. The creator can offer backers the choice
+// of taking them instead of their money back: a backer who calls
+// `claim_in_kind_refund` receives reward tokens worth their contribution,
+// valued through the price oracle less a discount for the risk of holding
+// them, up to their pro-rata share of the escrow. The refund they gave up
+// goes to the creator in exchange; what the share falls short of is still
+// refunded in cash. Backers choose by claiming during the election
+// window after the deadline; once it has closed, whoever has not claimed
+// gets the configured default.
+
+const BPS_DENOMINATOR: u128 = 10_000;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InKindRefund {
+    pub discount_bps: u32,     // Taken off the oracle value of the reward tokens
+    pub election_ledgers: u64, // Ledgers after the deadline backers have to choose
+    pub default_in_kind: bool, // What the backers who did not choose get
+}
+
+fn load(env: &Env) -> Option<InKindRefund> {
+    env.storage().instance().get(&StorageKey::InKindRefund)
+}
+
+fn election_open(env: &Env, project: &Project, terms: &InKindRefund) -> bool {
+    u64::from(env.ledger().sequence()) <= project.deadline.saturating_add(terms.election_ledgers)
+}
+
+/// Fails a cash refund of a failed campaign once its election window has
+/// closed with in-kind refunds as the default.
+pub(crate) fn ensure_cash(env: &Env, project: &Project, backer: &Address) -> Result<(), Error> {
+    let Some(terms) = load(env) else {
+        return Ok(());
+    };
+    if !terms.default_in_kind
+        || project.state == ProjectState::Cancelled
+        || election_open(env, project, &terms)
+    {
+        return Ok(());
+    }
+    match rewards::share(env, project, backer) {
+        Some((_, share)) if share > 0 => Err(Error::NotEligible),
+        _ => Ok(()),
+    }
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Creator) Offers backers reward tokens instead of a cash refund if
+    /// the campaign fails. Only possible before the first contribution.
+    pub fn set_in_kind_refund(env: Env, terms: InKindRefund) -> Result<(), Error> {
+        let project = access::require_creator(&env)?;
+        if u128::from(terms.discount_bps) >= BPS_DENOMINATOR || terms.election_ledgers == 0 {
+            return Err(Error::InvalidArgument);
+        }
+        if project.raised > 0 {
+            return Err(Error::TermsLocked);
+        }
+        env.storage()
+            .instance()
+            .set(&StorageKey::InKindRefund, &terms);
+        Ok(())
+    }
+
+    /// (Backer) Takes the refund of a failed campaign in reward tokens.
+    /// Possible during the election window, and after it when in-kind is
+    /// the default. Returns the reward tokens paid.
+    pub fn claim_in_kind_refund(env: Env, backer: Address) -> Result<u128, Error> {
+        breaker::ensure_writable(&env)?;
+        backer.require_auth();
+        let terms = load(&env).ok_or(Error::FeatureDisabled)?;
+        let mut project = Self::get_project(&env)?;
+        lifecycle::advance(&env, &mut project);
+        if project.state != ProjectState::Failed {
+            return Err(Error::RefundsNotAvailable);
+        }
+        if !terms.default_in_kind && !election_open(&env, &project, &terms) {
+            return Err(Error::NotEligible);
+        }
+        let contribution = backers::contribution(&env, &backer).ok_or(Error::NoRefundsToClaim)?;
+        if contribution == 0 {
+            return Err(Error::NoRefundsToClaim);
+        }
+        let (reward_token, share) =
+            rewards::share(&env, &project, &backer).ok_or(Error::NotFound)?;
+        if share == 0 {
+            return Err(Error::NotEligible);
+        }
+        let value = oracle::value(&env, &reward_token, share, &project.token)?;
+        let discounted =
+            value * (BPS_DENOMINATOR - u128::from(terms.discount_bps)) / BPS_DENOMINATOR;
+        if discounted == 0 {
+            return Err(Error::NotEligible);
+        }
+        // Just enough tokens to cover the contribution, or the whole share
+        let covered = contribution.min(discounted);
+        let tokens = if covered == contribution {
+            share * contribution / discounted
+        } else {
+            share
+        };
+        let cash = contribution - covered;
+        if breaker::trip_on_divergence(&env, &project)? {
+            return Ok(0);
+        }
+
+        backers::set(&env, &backer, 0);
+        escrow::refund(&mut project, contribution)?;
+        save_project(&env, &project);
+        rewards::book_claim(&env, &backer, tokens);
+
+        pay_out(&env, &reward_token, &backer, tokens)?;
+        pay_out(&env, &project.token, &project.creator, covered)?;
+        if cash > 0 {
+            pay_out(&env, &project.token, &backer, cash)?;
+        }
+        platform::report(&env, &project);
+
+        let topics = (symbol_short!("refund"), symbol_short!("inkind"), backer);
+        events::publish(&env, topics, (tokens, cash));
+        Ok(tokens)
+    }
+
+    /// (View) Gets the in-kind refund terms, if the creator offers them.
+    pub fn get_in_kind_refund(env: Env) -> Option<InKindRefund> {
+        load(&env)
+    }
+}
@@ -0,0 +1,141 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env, Map, Vec};
+
+use crate::{
+    access, backers, breaker, events, ttl, weighting, Error, MilestoneFund, MilestoneFundClient,
+    StorageKey,
+};
+
+// --- Inactive Backers ---
+// Abandoned wallets still count in every approval denominator, so enough of
+// them can deadlock governance for good. The admin sets how many ledgers
+// without interaction make an address inactive, and which other campaigns
+// of the platform count as interaction too. A keeper then marks an address
+// inactive; the contract verifies the idle period against its own record
+// and each peer campaign's `get_last_active`. Inactive backers who have
+// not voted on a milestone are left out of its denominator until they
+// interact again, which makes them active on the spot.
+
+/// Most peer campaigns consulted when marking an address inactive.
+pub const MAX_PEERS: u32 = 10;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InactivityPolicy {
+    pub ledgers: u64, // Idle ledgers before an address can be marked, 0 when disabled
+    pub peers: Vec<Address>,
+}
+
+fn load(env: &Env) -> Option<InactivityPolicy> {
+    env.storage().instance().get(&StorageKey::InactivityPolicy)
+}
+
+fn last_active(env: &Env, address: &Address) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::LastActive(address.clone()))
+}
+
+fn inactive_since(env: &Env, address: &Address) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::InactiveSince(address.clone()))
+}
+
+/// Records that `address` interacted with the campaign.
+pub(crate) fn note_active(env: &Env, address: &Address) {
+    ttl::set_persistent(
+        env,
+        &StorageKey::LastActive(address.clone()),
+        &u64::from(env.ledger().sequence()),
+    );
+}
+
+fn is_inactive(env: &Env, address: &Address) -> bool {
+    inactive_since(env, address)
+        .is_some_and(|marked| last_active(env, address).is_none_or(|last| last < marked))
+}
+
+/// Whether inactive backers are excluded from tallies.
+pub(crate) fn is_enabled(env: &Env) -> bool {
+    load(env).is_some_and(|policy| policy.ledgers > 0)
+}
+
+/// Weight of the inactive backers who have not voted in `votes`.
+pub(crate) fn excluded_weight(env: &Env, votes: &Map<Address, bool>) -> Result<u128, Error> {
+    if !is_enabled(env) {
+        return Ok(0);
+    }
+    let mut excluded: u128 = 0;
+    for backer in backers::all(env).iter() {
+        if !votes.contains_key(backer.clone()) && is_inactive(env, &backer) {
+            excluded = excluded
+                .checked_add(weighting::weight(env, &backer))
+                .ok_or(Error::Overflow)?;
+        }
+    }
+    Ok(excluded)
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Admin) Sets the inactivity policy. Up to `MAX_PEERS` peer
+    /// campaigns, other than this one.
+    pub fn set_inactivity_policy(env: Env, policy: InactivityPolicy) -> Result<(), Error> {
+        access::require_admin(&env)?;
+        if policy.peers.len() > MAX_PEERS {
+            return Err(Error::CapExceeded);
+        }
+        if policy.peers.contains(env.current_contract_address()) {
+            return Err(Error::InvalidArgument);
+        }
+        env.storage()
+            .instance()
+            .set(&StorageKey::InactivityPolicy, &policy);
+        Ok(())
+    }
+
+    /// Marks `backer` inactive once neither this campaign nor any peer has
+    /// seen them for the policy's idle period. Callable by anyone.
+    pub fn mark_inactive(env: Env, backer: Address) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        Self::get_project(&env)?;
+        let policy = load(&env)
+            .filter(|policy| policy.ledgers > 0)
+            .ok_or(Error::FeatureDisabled)?;
+        if !backers::is_backer(&env, &backer) {
+            return Err(Error::NotABacker);
+        }
+        if is_inactive(&env, &backer) {
+            return Err(Error::AlreadyExists);
+        }
+
+        let mut last = last_active(&env, &backer).unwrap_or(0);
+        for peer in policy.peers.iter() {
+            let seen = MilestoneFundClient::new(&env, &peer).get_last_active(&backer);
+            last = last.max(seen.unwrap_or(0));
+        }
+        let now = u64::from(env.ledger().sequence());
+        if now < last + policy.ledgers {
+            return Err(Error::NotDue);
+        }
+
+        ttl::set_persistent(&env, &StorageKey::InactiveSince(backer.clone()), &now);
+        events::publish(&env, (symbol_short!("inactive"), backer), last);
+        Ok(())
+    }
+
+    /// (View) Gets the inactivity policy, if one is set.
+    pub fn get_inactivity_policy(env: Env) -> Option<InactivityPolicy> {
+        load(&env)
+    }
+
+    /// (View) Gets the ledger `address` last interacted with the campaign.
+    pub fn get_last_active(env: Env, address: Address) -> Option<u64> {
+        last_active(&env, &address)
+    }
+
+    /// (View) Whether `address` is currently marked inactive.
+    pub fn is_inactive(env: Env, address: Address) -> bool {
+        is_inactive(&env, &address)
+    }
+}
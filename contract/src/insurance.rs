@@ -0,0 +1,156 @@
+use soroban_sdk::{contractclient, contractimpl, contracttype, symbol_short, Address, Env};
+
+use crate::{
+    access, backers, breaker, cancellation, events, health, pay_out, ttl, DataKey, Error,
+    MilestoneFund, MilestoneFundClient, Project, StateKey,
+};
+
+// --- Campaign Insurance ---
+// The creator can insure the campaign with an external coverage pool before
+// it is funded. Every release then routes `premium_bps` of the released
+// amount to the pool. If the campaign later ends disputed (an enforcement
+// action was taken against the creator), cancelled or failed, each backer
+// can claim coverage once; the pool decides what to pay from the backer's
+// share of the raised amount.
+
+const BPS_DENOMINATOR: u128 = 10_000;
+
+/// Interface the coverage pool must implement. The pool can rely on the
+/// campaign contract being the invoker of both calls.
+#[contractclient(name = "CoveragePoolClient")]
+pub trait CoveragePool {
+    /// Notifies the pool of a premium of `amount` of `token`, already
+    /// transferred to it by `campaign`.
+    fn receive_premium(env: Env, campaign: Address, token: Address, amount: i128);
+
+    /// Pays `backer` their coverage for `campaign`, given their
+    /// `contribution` out of `raised`. Returns the amount paid.
+    fn pay_coverage(
+        env: Env,
+        campaign: Address,
+        backer: Address,
+        contribution: u128,
+        raised: u128,
+    ) -> i128;
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct InsurancePolicy {
+    pub pool: Address,
+    pub premium_bps: u32,
+    pub premiums_paid: u128,
+    pub claims: u32, // Backers who claimed coverage
+}
+
+fn load(env: &Env) -> Option<InsurancePolicy> {
+    env.storage().instance().get(&DataKey::Insurance)
+}
+
+fn save(env: &Env, policy: &InsurancePolicy) {
+    env.storage().instance().set(&DataKey::Insurance, policy);
+}
+
+/// Pays the premium on a release of `amount` to the coverage pool.
+/// Returns the amount left for the creator.
+pub(crate) fn deduct_premium(env: &Env, project: &Project, amount: u128) -> Result<u128, Error> {
+    let Some(mut policy) = load(env) else {
+        return Ok(amount);
+    };
+    let premium = amount * u128::from(policy.premium_bps) / BPS_DENOMINATOR;
+    if premium == 0 {
+        return Ok(amount);
+    }
+    policy.premiums_paid += premium;
+    save(env, &policy);
+    pay_out(env, &project.token, &policy.pool, premium)?;
+    let premium_in = i128::try_from(premium).map_err(|_| Error::AmountTooLarge)?;
+    CoveragePoolClient::new(env, &policy.pool).receive_premium(
+        &env.current_contract_address(),
+        &project.token,
+        &premium_in,
+    );
+
+    events::publish(
+        env,
+        (symbol_short!("insure"), symbol_short!("premium")),
+        premium,
+    );
+    Ok(amount - premium)
+}
+
+/// Whether the campaign ended in a way its coverage pays out for.
+fn is_covered_outcome(env: &Env, project: &Project) -> bool {
+    let failed = !project.goal_met && u64::from(env.ledger().sequence()) > project.deadline;
+    failed || health::dispute_count(env) > 0 || cancellation::is_cancelled(env)
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Creator) Insures the campaign with `pool`, paying `premium_bps` of
+    /// every release to it. Only possible while the campaign is still
+    /// raising.
+    pub fn set_insurance(env: Env, pool: Address, premium_bps: u32) -> Result<(), Error> {
+        let project = access::require_creator(&env)?;
+        if project.goal_met {
+            return Err(Error::TermsLocked);
+        }
+        if u128::from(premium_bps) > BPS_DENOMINATOR {
+            return Err(Error::InvalidArgument);
+        }
+        let policy = InsurancePolicy {
+            pool,
+            premium_bps,
+            premiums_paid: 0,
+            claims: 0,
+        };
+        save(&env, &policy);
+        Ok(())
+    }
+
+    /// (Backer) Claims coverage from the pool once the campaign has ended
+    /// disputed, cancelled or failed. Returns the amount paid.
+    pub fn claim_coverage(env: Env, backer: Address) -> Result<i128, Error> {
+        breaker::ensure_writable(&env)?;
+        backer.require_auth();
+        let project = Self::get_project(&env)?;
+        let mut policy = load(&env).ok_or(Error::NotFound)?;
+        let contribution = backers::contribution(&env, &backer).ok_or(Error::NotABacker)?;
+        if !is_covered_outcome(&env, &project) {
+            return Err(Error::NotEligible);
+        }
+        let claim_key = StateKey::CoverageClaim(backer.clone());
+        if env.storage().persistent().has(&claim_key) {
+            return Err(Error::AlreadyExists);
+        }
+
+        let paid = CoveragePoolClient::new(&env, &policy.pool).pay_coverage(
+            &env.current_contract_address(),
+            &backer,
+            &contribution,
+            &project.raised,
+        );
+        ttl::set_persistent(&env, &claim_key, &paid);
+        policy.claims += 1;
+        save(&env, &policy);
+
+        events::publish(
+            &env,
+            (symbol_short!("insure"), symbol_short!("claim"), backer),
+            paid,
+        );
+        Ok(paid)
+    }
+
+    /// (View) Gets the campaign's insurance policy.
+    pub fn get_insurance(env: Env) -> Result<InsurancePolicy, Error> {
+        load(&env).ok_or(Error::NotFound)
+    }
+
+    /// (View) Gets the coverage paid to `backer`, if they claimed.
+    pub fn get_coverage_claim(env: Env, backer: Address) -> Option<i128> {
+        env.storage()
+            .persistent()
+            .get(&StateKey::CoverageClaim(backer))
+    }
+}
@@ -0,0 +1,111 @@
+use soroban_sdk::{contractimpl, symbol_short, Address, Env, Symbol};
+
+use crate::{events, projects, Error, MilestoneFund, MilestoneFundClient};
+
+// --- Interface Versioning ---
+// Campaign addresses outlive their WASM, so integrators built against an
+// older interface keep calling a live contract after it is upgraded. Every
+// breaking signature change bumps `INTERFACE_VERSION`, and the previous
+// signature stays available as a thin shim that translates to the current
+// implementation and publishes a `("deprecate", name)` event carrying the
+// version it was retired in. Soroban dispatches by name alone, so a shim
+// takes its entrypoint's name with the suffix of the version it belongs to.
+// Shims of the calls that took a project ID in version 3 answer for the
+// campaign of the instance they are called on.
+
+/// The version of the entrypoint interface this WASM implements.
+pub const INTERFACE_VERSION: u32 = 3;
+
+/// The amount every `fund` call contributed before it took one.
+const V1_FUND_AMOUNT: i128 = 100;
+
+fn deprecated(env: &Env, name: Symbol, retired_in: u32) {
+    events::publish(env, (symbol_short!("deprecate"), name), retired_in);
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (View) Gets the version of the entrypoint interface.
+    pub fn get_interface_version() -> u32 {
+        INTERFACE_VERSION
+    }
+
+    /// Deprecated: the version 1 `fund`, contributing the fixed amount it
+    /// always did. Use `fund` with an amount instead.
+    pub fn fund_v1(env: Env, backer: Address, source: Option<Symbol>) -> Result<(), Error> {
+        deprecated(&env, symbol_short!("fund"), 2);
+        let project_id = projects::own_id(&env);
+        Self::fund(env, project_id, backer, V1_FUND_AMOUNT, source)
+    }
+
+    /// Deprecated: the version 1 `get_backer_info`, answering to the backer
+    /// itself, who must now sign. Use `get_backer_info` with a viewer instead.
+    pub fn get_backer_info_v1(env: Env, backer: Address) -> Result<u128, Error> {
+        deprecated(&env, symbol_short!("backer"), 2);
+        Self::get_backer_info(env, backer.clone(), backer)
+    }
+
+    /// Deprecated: the version 1 `get_claimable`, answering to the backer
+    /// itself, who must now sign. Use `get_claimable` with a viewer instead.
+    pub fn get_claimable_v1(env: Env, backer: Address) -> Result<u128, Error> {
+        deprecated(&env, symbol_short!("claimable"), 2);
+        Self::get_claimable(env, backer.clone(), backer)
+    }
+
+    /// Deprecated: the version 2 `fund`, without a project ID. Use `fund`
+    /// with the project ID instead.
+    pub fn fund_v2(
+        env: Env,
+        backer: Address,
+        amount: i128,
+        source: Option<Symbol>,
+    ) -> Result<(), Error> {
+        deprecated(&env, symbol_short!("fund"), 3);
+        let project_id = projects::own_id(&env);
+        Self::fund(env, project_id, backer, amount, source)
+    }
+
+    /// Deprecated: the version 2 `vote`, without a project ID. Use `vote`
+    /// with the project ID instead.
+    pub fn vote_v2(
+        env: Env,
+        backer: Address,
+        milestone_index: u32,
+        approve: bool,
+    ) -> Result<(), Error> {
+        deprecated(&env, symbol_short!("vote"), 3);
+        let project_id = projects::own_id(&env);
+        Self::vote(env, project_id, backer, milestone_index, approve)
+    }
+
+    /// Deprecated: the version 2 `release_funds`, without a project ID. Use
+    /// `release_funds` with the project ID instead.
+    pub fn release_funds_v2(env: Env, milestone_index: u32) -> Result<(), Error> {
+        deprecated(&env, symbol_short!("release"), 3);
+        let project_id = projects::own_id(&env);
+        Self::release_funds(env, project_id, milestone_index)
+    }
+
+    /// Deprecated: the version 2 `claim_refund`, without a project ID. Use
+    /// `claim_refund` with the project ID instead.
+    pub fn claim_refund_v2(env: Env, backer: Address) -> Result<(), Error> {
+        deprecated(&env, symbol_short!("refund"), 3);
+        let project_id = projects::own_id(&env);
+        Self::claim_refund(env, project_id, backer)
+    }
+
+    /// Deprecated: the version 2 `create_project`, which returned nothing.
+    /// Use `create_project`, which returns the project ID, instead.
+    pub fn create_project_v2(
+        env: Env,
+        admin: Address,
+        creator: Address,
+        token: Address,
+        goal: u128,
+        deadline: u64,
+    ) -> Result<(), Error> {
+        deprecated(&env, symbol_short!("create"), 3);
+        Self::create_project(env, admin, creator, token, goal, deadline)?;
+        Ok(())
+    }
+}
@@ -1,11 +1,165 @@
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short, vec, Address, Env, Map, String, Vec,
+    contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, Address, BytesN, Env, Map,
+    String, Symbol, Vec,
 };
 
-// Define a custom error type for the contract
-#[contracttype]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+mod access;
+mod auth;
+mod backers;
+mod ballots;
+mod bounty;
+mod breaker;
+mod budgets;
+mod bulk_vote;
+mod burn;
+mod cancellation;
+mod certificate;
+mod claims;
+mod cleanup;
+mod cohorts;
+mod collateral;
+mod comparison;
+mod delegation;
+mod downscope;
+mod drafts;
+mod emergency;
+mod escrow;
+mod events;
+mod evidence;
+mod export;
+mod factory;
+mod fees;
+mod freeze;
+mod governance;
+mod health;
+mod in_kind;
+mod inactivity;
+mod lifecycle;
+mod insurance;
+mod interface;
+mod limits;
+mod lp;
+mod memos;
+mod metadata;
+mod migration;
+mod optimistic;
+mod oracle;
+mod pauses;
+mod personhood;
+mod platform;
+mod polls;
+mod privacy;
+mod projects;
+mod pruning;
+mod raffle;
+mod rebates;
+mod receipts;
+mod registry;
+mod rejection;
+mod release_cap;
+mod remainder;
+mod renewal;
+mod retro;
+mod revision;
+mod rewards;
+mod schedule;
+mod settlement;
+mod simulation;
+mod simple_escrow;
+mod sources;
+mod stats;
+mod stipend;
+mod stream;
+mod stream_pledge;
+mod succession;
+mod sequential;
+mod sybil;
+mod terms;
+mod ticker;
+mod timelock;
+mod token_sale;
+mod ttl;
+mod upgrade;
+mod vote_change;
+mod votes;
+mod voting_deadline;
+mod watch;
+mod weighting;
+mod withdrawal;
+
+#[cfg(feature = "testutils")]
+pub mod fixtures;
+
+pub use ballots::SignedBallot;
+pub use budgets::{
+    BudgetStatement, CampaignAllocation, CampaignStatement, InstitutionBudget, PlatformStatement,
+};
+pub use comparison::ProjectComparison;
+pub use delegation::FallbackDelegation;
+pub use escrow::{EscrowBreakdown, MilestoneEscrow};
+pub use evidence::MilestoneEvidence;
+pub use export::{BackerRecord, StateChunk, StateCursor, StateHeader, StateSection, VoteRecord};
+pub use factory::{FactoryConfig, RefundSweep};
+pub use fees::PlatformFee;
+pub use freeze::MilestoneFreeze;
+pub use health::ProjectHealth;
+pub use in_kind::InKindRefund;
+pub use inactivity::{InactivityPolicy, MAX_PEERS};
+pub use insurance::{CoveragePool, CoveragePoolClient, InsurancePolicy};
+pub use interface::INTERFACE_VERSION;
+pub use lifecycle::ProjectState;
+pub use limits::{OverflowPolicy, StorageLimits};
+pub use lp::{LpAdapter, LpRoute};
+pub use memos::{PayoutRecord, ReleaseMemo};
+pub use metadata::MetadataManifest;
+pub use migration::{MigrationProposal, RescueContract};
+pub use optimistic::OptimisticRelease;
+pub use oracle::{PriceOracle, PriceOracleClient};
+pub use pauses::{FundingPauses, PauseInterval};
+pub use personhood::{Personhood, PersonhoodClient, PersonhoodGate};
+pub use platform::{CampaignReport, PlatformStats};
+pub use pruning::VoteTally;
+pub use raffle::RaffleResult;
+pub use receipts::VoteReceipt;
+pub use registry::{TokenRegistry, TokenRegistryClient};
+pub use release_cap::ReleaseCap;
+pub use remainder::RemainderRefund;
+pub use collateral::{CollateralLink, CollateralPledge};
+pub use governance::{GovernanceConfig, SnapshotPolicy};
+pub use bounty::MilestoneBounty;
+pub use breaker::{AccountingAdjustment, Reconcilers};
+pub use cancellation::{Cancellation, CancellationSchedule};
+pub use certificate::{CampaignOutcome, OutcomeCertificate};
+pub use cleanup::CleanupTerms;
+pub use cohorts::BackerCohorts;
+pub use renewal::{Renewal, RenewalTemplate};
+pub use retro::{RetroRound, WorkClaim};
+pub use revision::CostRevision;
+pub use rewards::RewardEscrow;
+pub use schedule::{BudgetSchedule, BurnDown};
+pub use settlement::{SwapAdapter, SwapRoute};
+pub use simulation::VoteSimulation;
+pub use sources::SourceStats;
+pub use stats::{ContributionRecord, StatsBucket, StatsLog, BUCKET_LEDGERS};
+pub use stipend::{Stipend, StipendTerms};
+pub use stream::MilestoneStream;
+pub use stream_pledge::{PaymentStream, PaymentStreamClient, StreamPledge, StreamPledgeStatus};
+pub use succession::SuccessionProposal;
+pub use sybil::SybilGate;
+pub use terms::{Amendment, TermsLock};
+pub use ticker::{RecentContribution, Ticker};
+pub use timelock::ReleaseTimelock;
+pub use token_sale::{TokenSale, PRICE_SCALE};
+pub use upgrade::UpgradeProposal;
+pub use weighting::{Holding, WeightingMode};
+
+// Define a custom error type for the contract. Clients see these as
+// contract error codes, so a variant's code never changes once released;
+// new variants take the next free code.
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
 pub enum Error {
     ProjectAlreadyInitialized = 1,
     DeadlineMustBeInFuture = 2,
@@ -26,22 +180,34 @@ pub enum Error {
     RefundsNotAvailable = 17,
     NoRefundsToClaim = 18,
     SdkInternalError = 19, // <-- ADD THIS
-}
-
-// This is the implementation you already have (GOOD)
-// This converts YourError -> SdkError
-impl From<&Error> for soroban_sdk::Error {
-    fn from(value: &Error) -> soroban_sdk::Error {
-        soroban_sdk::Error::from_contract_error(*value as u32)
-    }
-}
-
-// FIX: Add the missing implementation (THIS IS NEW)
-// This converts SdkError -> YourError
-impl From<soroban_sdk::Error> for Error {
-    fn from(_value: soroban_sdk::Error) -> Error {
-        Error::SdkInternalError
-    }
+    // The contract spec caps an enum at 50 cases, so the codes below are
+    // shared by every subsystem rather than minted per feature.
+    AlreadyExists = 20,  // The record, claim or setting is already in place
+    NotFound = 21,       // No such record (watch, route, stream, proposal, ...)
+    CircuitBreakerTripped = 22,
+    BalanceDiverged = 23,
+    InvalidArgument = 24, // An argument is out of range or inconsistent
+    SlippageExceeded = 25,
+    NotDue = 26,          // The deadline or interval has not passed yet
+    AmountTooLarge = 27,
+    FeatureDisabled = 28,
+    NotSettled = 29,
+    NotEligible = 30,
+    InsufficientFunds = 31, // A pool or budget cannot cover the amount
+    CapExceeded = 32,
+    TermsLocked = 33,
+    FundingNotStarted = 34,
+    MilestoneStreaming = 35,
+    NotApproved = 36,     // A governance vote has not reached its threshold
+    Migrated = 37,
+    ReadOnlyMode = 38,    // The circuit breaker is tripped; only views and reconciliation run
+    MilestoneRejected = 39,
+    MilestoneFrozen = 40, // Under investigation; no votes or releases until it thaws
+    VotingClosed = 41,    // The milestone's voting deadline has passed
+    MilestoneOutOfOrder = 42, // An earlier milestone must be released first
+    Paused = 43,              // The admin has paused contributions, votes and releases
+    SchemaMismatch = 44,      // The stored layout must be migrated before use
+    Overflow = 45,            // An amount or weight does not fit its type
 }
 
 // --- Data Structures ---
@@ -53,10 +219,17 @@ pub struct Milestone {
     pub title: String,
     pub amount_to_release: u128,
     pub is_complete: bool,
-    pub votes: Map<Address, bool>, // Map<BackerAddress, VotedYes>
+    pub is_rejected: bool,         // Voted down; its escrow went to the refund pool
+    pub is_failed: bool,           // Not approved by its voting deadline; likewise refunded
+    pub escrow: MilestoneEscrow,   // Tokens earmarked for this milestone and where they went
+    pub downscope_reason: Option<String>,
 }
 
-// Represents the entire project state
+// Represents the campaign's terms and running totals. Contributions and
+// milestone votes live in their own persistent entries (see `backers` and
+// `votes`). The rest is stored in three sections, the terms
+// (`ProjectConfig`), the running totals (`ProjectStatus`) and the
+// milestones, and `save_project` only rewrites the sections a call changed.
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct Project {
@@ -65,16 +238,238 @@ pub struct Project {
     pub goal: u128,           // The total amount to raise
     pub raised: u128,         // The current amount raised
     pub deadline: u64,        // Ledger sequence deadline
+    pub start_ledger: u64,    // Contributions are rejected before this ledger
     pub milestones: Vec<Milestone>,
-    pub backers: Map<Address, u128>, // Map<BackerAddress, AmountFunded>
     pub goal_met: bool,
+    pub surplus: u128,            // Raised beyond the sum of milestone allocations
+    pub refund_pool: u128,        // Total credited back to backers pro-rata
+    pub refund_pool_claimed: u128, // Part of the refund pool already paid out
+    pub state: ProjectState,
+    pub approval_threshold_bps: u32, // Exclusive share of raised weight to release a milestone
+    pub quorum_bps: u32,             // Share of raised weight that must vote, either way
+}
+
+// Stored section of the project: its parties and terms
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+struct ProjectConfig {
+    creator: Address,
+    token: Address,
+    goal: u128,
+    deadline: u64,
+    start_ledger: u64,
+    approval_threshold_bps: u32,
+    quorum_bps: u32,
+}
+
+// Stored section of the project: the totals every contribution, release
+// and refund moves
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+struct ProjectStatus {
+    raised: u128,
+    goal_met: bool,
+    surplus: u128,
+    refund_pool: u128,
+    refund_pool_claimed: u128,
+    state: ProjectState,
+}
+
+// Compact, read-only overview of the project for listing pages
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProjectSummary {
+    pub creator: Address,
+    pub token: Address,
+    pub goal: u128,
+    pub raised: u128,
+    pub deadline: u64,
+    pub start_ledger: u64,
+    pub goal_met: bool,
+    pub milestone_count: u32,
+    pub backer_count: u32,
+    pub watcher_count: u32,
+}
+
+/// Stores `project`, rewriting only the sections that differ from the
+/// stored ones.
+pub(crate) fn save_project(env: &Env, project: &Project) {
+    let storage = env.storage().instance();
+    let config = ProjectConfig {
+        creator: project.creator.clone(),
+        token: project.token.clone(),
+        goal: project.goal,
+        deadline: project.deadline,
+        start_ledger: project.start_ledger,
+        approval_threshold_bps: project.approval_threshold_bps,
+        quorum_bps: project.quorum_bps,
+    };
+    if storage.get(&DataKey::ProjectInfo) != Some(config.clone()) {
+        storage.set(&DataKey::ProjectInfo, &config);
+    }
+    let status = ProjectStatus {
+        raised: project.raised,
+        goal_met: project.goal_met,
+        surplus: project.surplus,
+        refund_pool: project.refund_pool,
+        refund_pool_claimed: project.refund_pool_claimed,
+        state: project.state,
+    };
+    if storage.get(&StateKey::ProjectStatus) != Some(status.clone()) {
+        storage.set(&StateKey::ProjectStatus, &status);
+    }
+    let milestones: Option<Vec<Milestone>> = storage.get(&StateKey::Milestones);
+    if milestones.as_ref() != Some(&project.milestones) {
+        storage.set(&StateKey::Milestones, &project.milestones);
+    }
 }
 
 // --- Contract Keys for Storage ---
 #[derive(Clone)]
 #[contracttype]
 enum DataKey {
-    ProjectInfo,
+    ProjectInfo, // ProjectConfig; the status and milestones have StateKey entries
+    Admin,
+    Watchers, // Map<WatcherAddress, bool>, kept in persistent storage
+    BreakerTripped,
+    BreakerTolerance,
+    PoolClaimed(Address), // Refund-pool amount already paid to a backer, persistent
+    FeePool,
+    RebateAmount,
+    VotesCast(Address),     // Milestones a backer has voted on, persistent
+    RebateClaimed(Address), // Persistent
+    Budget(Address),        // Institution -> InstitutionBudget, persistent
+    BudgetsHeld,            // Unallocated budget tokens held for all institutions
+    Evidence(u32),          // Milestone index -> MilestoneEvidence, persistent
+    EvidencePenaltyBps,
+    CreatedAt,     // Ledger sequence the project was initialized at
+    DisputeCount,  // Enforcement actions taken against the creator
+    Stream(u32),   // Milestone index -> MilestoneStream, persistent
+    BulkVoteStrict,
+    SourceStats(Symbol), // Source tag -> SourceStats, persistent
+    Migration,
+    Rewards,                // RewardEscrow
+    RewardClaimed(Address), // Backer -> reward tokens claimed, persistent
+    StorageLimits,
+    AnonymousPool, // Contributions from backers past the backer cap
+    AuthNonce(Address), // Address -> next call-bound auth nonce, persistent
+    RetroRound,
+    RetroVote(Address), // Backer -> work claim index voted for, persistent
+    Succession,
+    SettlementRoute(Address), // Settlement asset -> SwapRoute, persistent
+    SettlementSlippageBps,
+    FundingPauses,
+    EventTenant,
+    BudgetSchedule,
+    VoteTally(u32), // Milestone index -> VoteTally snapshot taken on pruning, persistent
+    Raffle,
+    CollateralPledge, // Collateral pledged to another campaign
+    CollateralLink,   // Collateral another campaign pledged to this one
+    Bounty(u32),      // Milestone index -> MilestoneBounty, persistent
+    Cancellation,
+    StatsLog,         // Raw contribution records awaiting rollup, persistent
+    StatsBucket(u64), // Ledger bucket -> StatsBucket, persistent
+    Insurance,
+    WeightingMode,
+    Holding(Address), // Backer -> time-weighted Holding, persistent
+    Drafting,         // Set while milestones are still being added in chunks
+    Renewal,
+    TermsLock,
+    LpRoute(Address), // Share token -> LpRoute, kept in persistent storage
+    Backer(Address),  // Backer -> contribution, persistent
+    Project(u32),     // Project ID -> campaign address on a multi-project deployment, persistent
+}
+
+// `DataKey` is at the 50-case limit of contracttype enums, so keys added
+// from here on live in this second enum
+#[derive(Clone)]
+#[contracttype]
+enum StorageKey {
+    FallbackDelegation,
+    ReleaseTimelock,
+    SybilGate,
+    FirstSeen(Address), // Address -> ledger of its first interaction, persistent
+    Stipend,
+    MinContribution,
+    Arbiter,
+    OutcomeCertificate,
+    InactivityPolicy,
+    LastActive(Address),    // Address -> ledger of its last interaction, persistent
+    InactiveSince(Address), // Address -> ledger it was marked inactive at, persistent
+    TokenRegistry,
+    Factory,
+    Campaign(u32), // Factory campaign number -> address, persistent; superseded by DataKey::Project
+    CostRevision,
+    Reconcilers,
+    BackerCount,
+    BackerPage(u32),     // Page number -> backer addresses, persistent
+    Vote(u32, Address),  // (Milestone index, backer) -> approve, persistent
+    VoteCount(u32),      // Milestone index -> number of votes cast
+    VoterPage(u32, u32), // (Milestone index, page number) -> voter addresses, persistent
+    RefundCursor(Address), // Backer -> next factory campaign to sweep for refunds, persistent
+    PriceOracle,
+    CollateralHaircut,
+    ApprovedWeight(u32), // Milestone index -> contribution weight of its approving votes
+    RejectedWeight(u32), // Milestone index -> contribution weight of its rejecting votes
+    CleanupTerms,
+    ParentFactory,
+    Purged,
+    FinalizedAt,
+    RemovedMilestones,
+    ReleaseCap,
+    BurnBps,
+    Burned,
+    RejectionThreshold,
+    StreamPledge(Address),
+    MilestoneFreeze(u32),
+    VotingDeadline(u32),
+    PlatformStats,
+    CampaignReport(Address), // Factory campaign address -> its last report, persistent
+    OptimisticRelease,
+    InKindRefund,
+    PersonhoodGate,
+    PersonPledged(BytesN<32>), // Attestation id -> amount its person has pledged, persistent
+    PersonOf(Address),         // Backer -> attestation id their pledges were charged to, persistent
+    SequentialReleases,
+    MetadataVersions,
+    MetadataChunk(BytesN<32>), // Chunk hash -> chunk bytes, persistent
+    MetadataManifest(u32),     // Version -> MetadataManifest, persistent
+}
+
+// `StorageKey` has reached the 50-case limit as well; keys added from here
+// on live in this third enum
+#[derive(Clone)]
+#[contracttype]
+enum StateKey {
+    RemainderRefund,
+    RemainderClaimed(Address), // Backer -> remainder share paid to them, persistent
+    PlatformFee,
+    Paused,
+    FirstCampaign(Address), // Backer -> factory campaign they first contributed to, persistent
+    Cohorts(Address),       // Factory campaign address -> BackerCohorts, persistent
+    StorageVersion,
+    UpgradeProposal,
+    UpgradeVote(Address),   // Backer -> round of the upgrade proposal they approved, persistent
+    UpgradeWeight(Address), // Backer -> (round, weight when that upgrade proposal opened), persistent
+    ReleaseMemo(u32), // Milestone index -> ReleaseMemo, persistent
+    Payout(u32),      // Milestone index -> PayoutRecord, persistent
+    MemoRequired,
+    TokenSale,
+    SaleClaimed(Address), // Backer -> project tokens paid to them, persistent
+    Ticker,               // Ring buffer of the latest contributions, temporary
+    SourceCount,
+    SourcePage(u32), // Page index -> source tags in first-seen order, persistent
+    ProjectStatus,
+    Milestones,
+    PollRound(Symbol),
+    PollVote(Symbol, u32, Address), // (Poll, scope, backer) -> vote, persistent
+    FallbackDelegate(Address),      // Backer -> fallback delegate, persistent
+    CoverageClaim(Address),         // Backer -> coverage paid, persistent
+    Participated(Address, u32),     // (Backer, milestone index) flag, persistent
+    PlatformBudget(Address, Address), // (Institution, token) -> InstitutionBudget on a factory, persistent
+    CampaignAllocation(Address, u32), // (Institution, campaign number) -> CampaignAllocation, persistent
+    AnonymousContribution(Address),   // Contributor -> amount in the anonymous pool, persistent
+    ProjectId,                        // This campaign's ID on the deployment hosting it
 }
 
 #[contract]
@@ -85,21 +480,33 @@ impl MilestoneFund {
     /// Initializes the project. Can only be called once.
     ///
     /// # Arguments
+    /// * `admin` - The address allowed to perform administrative recovery actions.
     /// * `creator` - The address of the project creator (who will receive funds).
     /// * `token` - The address of the token asset to be raised.
     /// * `goal` - The total funding target.
     /// * `deadline` - The ledger sequence number when funding closes.
     /// * `milestones` - A vector of milestone titles and their corresponding fund release amounts.
+    /// * `approval_threshold_bps` - Share of the raised weight a milestone's approvals must
+    ///   exceed, in basis points: at least 5 000 (a simple majority), below 10 000.
+    /// * `quorum_bps` - Share of the raised weight that must vote on a milestone, either way,
+    ///   before it can be released, in basis points. 0 for none.
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize(
         env: Env,
+        admin: Address,
         creator: Address,
         token: Address,
         goal: u128,
         deadline: u64,
         milestones: Vec<(String, u128)>,
+        approval_threshold_bps: u32,
+        quorum_bps: u32,
     ) -> Result<(), Error> {
-        // Ensure not already initialized
-        if env.storage().instance().has(&DataKey::ProjectInfo) {
+        // Ensure not already initialized, as a campaign or a factory, nor purged
+        if env.storage().instance().has(&DataKey::ProjectInfo)
+            || factory::is_factory(&env)
+            || cleanup::is_purged(&env)
+        {
             return Err(Error::ProjectAlreadyInitialized);
         }
 
@@ -114,17 +521,23 @@ impl MilestoneFund {
         if milestones.is_empty() {
             return Err(Error::MilestoneListEmpty);
         }
+        governance::validate_rules(approval_threshold_bps, quorum_bps)?;
 
         let mut total_milestone_amount: u128 = 0;
         let mut milestone_vec: Vec<Milestone> = vec![&env];
 
         for (title, amount) in milestones.iter() {
-            total_milestone_amount += amount;
+            total_milestone_amount = total_milestone_amount
+                .checked_add(amount)
+                .ok_or(Error::Overflow)?;
             milestone_vec.push_back(Milestone {
                 title,
                 amount_to_release: amount,
                 is_complete: false,
-                votes: Map::new(&env),
+                is_rejected: false,
+                is_failed: false,
+                escrow: MilestoneEscrow::default(),
+                downscope_reason: None,
             });
         }
 
@@ -140,201 +553,613 @@ impl MilestoneFund {
             goal,
             raised: 0,
             deadline,
+            start_ledger: 0,
             milestones: milestone_vec,
-            backers: Map::new(&env),
             goal_met: false,
+            surplus: 0,
+            refund_pool: 0,
+            refund_pool_claimed: 0,
+            state: ProjectState::Funding,
+            approval_threshold_bps,
+            quorum_bps,
         };
 
+        save_project(&env, &project);
+        env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage()
             .instance()
-            .set(&DataKey::ProjectInfo, &project);
+            .set(&DataKey::CreatedAt, &u64::from(env.ledger().sequence()));
+        upgrade::record_version(&env);
         
         // Set a Time-To-Live (TTL) for the contract data
-        env.storage().instance().extend_ttl(100, 100);
+        ttl::extend_instance(&env);
 
         Ok(())
     }
 
-    /// Allows a backer to fund the project.
-    pub fn fund(env: Env, backer: Address) -> Result<(), Error> {
-        backer.require_auth(); // The backer must authorize this
-        let mut project = Self::get_project(&env)?;
+    /// Embargoes funding until `start_ledger`, so a project can be published
+    /// ahead of its launch. Only the creator can schedule the launch, and
+    /// only before the project has received any contribution.
+    pub fn schedule_launch(env: Env, start_ledger: u64) -> Result<(), Error> {
+        let mut project = access::require_creator(&env)?;
 
-        // --- Funding Period Checks ---
-        if project.goal_met {
-            return Err(Error::GoalAlreadyMet);
+        if project.raised > 0 {
+            return Err(Error::AlreadyExists);
         }
-        // FIX: Use u64::from() for explicit type conversion
-        if u64::from(env.ledger().sequence()) > project.deadline {
-            return Err(Error::DeadlinePassed);
+        if start_ledger >= project.deadline {
+            return Err(Error::InvalidArgument);
         }
 
-        // --- Logic for Receiving Funds ---
-        // This is a simplified example. In a real contract, you'd use
-        // `token.transfer_from` and get the amount from call arguments.
-        // For this example, let's assume a hypothetical `amount` was passed.
-        let amount_to_fund: u128 = 100; // Placeholder: This should come from `call_stack` or args
+        project.start_ledger = start_ledger;
+        save_project(&env, &project);
+
+        events::publish(&env, (symbol_short!("launch"),), start_ledger);
+        Ok(())
+    }
 
-        if amount_to_fund == 0 {
-            return Err(Error::FundingAmountTooLow);
+    /// Allows a backer to fund project `project_id`, optionally tagging the
+    /// contribution with the `source` (e.g. "web", "mobile") it came through.
+    pub fn fund(
+        env: Env,
+        project_id: u32,
+        backer: Address,
+        amount: i128,
+        source: Option<Symbol>,
+    ) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        if let Some(campaign) = projects::route(&env, project_id)? {
+            return projects::fund(&env, project_id, &campaign, &backer, amount, &source);
         }
-        
-        // --- In a real contract, you'd execute the transfer ---
-        // let token_client = token::Client::new(&env, &project.token);
-        // token_client.transfer_from(&env.current_contract_address(), &backer, &env.current_contract_address(), &amount_to_fund);
-        
-        // --- Update State ---
-        project.raised += amount_to_fund;
-        let current_funding = project.backers.get(backer.clone()).unwrap_or(0);
-        project
-            .backers
-            .set(backer.clone(), current_funding + amount_to_fund);
-
-        // Check if goal is now met
-        if project.raised >= project.goal {
-            project.goal_met = true;
-            // Optionally close funding, or allow over-funding
+        let project = Self::get_project(&env)?;
+
+        // The backer signs over the exact amount, which is pulled into the contract
+        auth::require_auth_for_amount(&env, &backer, amount);
+        let amount_to_fund = u128::try_from(amount).map_err(|_| Error::FundingAmountTooLow)?;
+        check_contribution(&env, &project, &backer, amount_to_fund)?;
+        collect(&env, &project.token, &backer, amount_to_fund)?;
+
+        record_contribution(&env, project, &backer, amount_to_fund)?;
+        if let Some(source) = source {
+            sources::record(&env, &source, amount_to_fund);
         }
+        Ok(())
+    }
+
+    /// (View) Dry-runs a contribution: returns the exact error `fund` would
+    /// fail with for `backer` and `amount`, or `Ok` if it would go through.
+    pub fn can_fund(env: Env, backer: Address, amount: u128) -> Result<(), Error> {
+        let project = Self::get_project(&env)?;
+        check_contribution(&env, &project, &backer, amount)
+    }
 
+    /// (Admin) Sets the smallest amount a single contribution may be.
+    pub fn set_min_contribution(env: Env, amount: u128) -> Result<(), Error> {
+        access::require_admin(&env)?;
         env.storage()
             .instance()
-            .set(&DataKey::ProjectInfo, &project);
-
-        // Emit an event (good practice)
-        let topics = (symbol_short!("fund"), backer);
-        env.events().publish(topics, amount_to_fund);
-
+            .set(&StorageKey::MinContribution, &amount);
         Ok(())
     }
 
-    /// Allows a backer to vote on a milestone.
-    pub fn vote(env: Env, backer: Address, milestone_index: u32) -> Result<(), Error> {
-        backer.require_auth();
-        let mut project = Self::get_project(&env)?;
+    /// (View) Gets the smallest amount a single contribution may be.
+    pub fn get_min_contribution(env: Env) -> u128 {
+        min_contribution(&env)
+    }
 
-        if !project.goal_met {
-            return Err(Error::GoalNotMet);
-        }
-        if !project.backers.contains_key(backer.clone()) {
-            return Err(Error::NotABacker);
+    /// Allows a backer to vote for or against a milestone of project
+    /// `project_id`. A rejection that clears the rejection threshold rejects
+    /// the milestone at once.
+    pub fn vote(
+        env: Env,
+        project_id: u32,
+        backer: Address,
+        milestone_index: u32,
+        approve: bool,
+    ) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        if let Some(campaign) = projects::route(&env, project_id)? {
+            MilestoneFundClient::new(&env, &campaign).vote(
+                &project_id,
+                &backer,
+                &milestone_index,
+                &approve,
+            );
+            return projects::sync(&env, project_id);
         }
+        backer.require_auth();
+        let project = Self::get_project(&env)?;
 
-        let mut milestone = project
-            .milestones
-            .get(milestone_index)
-            .ok_or(Error::MilestoneInvalidIndex)?;
-
-        if milestone.is_complete {
-            return Err(Error::MilestoneAlreadyCompleted);
-        }
-        
-        if milestone.votes.contains_key(backer.clone()) {
-            return Err(Error::AlreadyVoted);
+        cast_vote(&env, &project, &backer, milestone_index, approve)?;
+        if !approve {
+            rejection::reject_if_carried(&env, milestone_index)?;
         }
 
-        // Record the vote
-        milestone.votes.set(backer.clone(), true);
-        project.milestones.set(milestone_index, milestone);
-        
-        env.storage().instance().set(&DataKey::ProjectInfo, &project);
-        
         Ok(())
     }
 
-    /// Releases funds for a completed milestone.
-    pub fn release_funds(env: Env, milestone_index: u32) -> Result<(), Error> {
-        let mut project = Self::get_project(&env)?;
-        
-        if !project.goal_met {
-            return Err(Error::GoalNotMet);
-        }
-
-        let mut milestone = project
-            .milestones
-            .get(milestone_index)
-            .ok_or(Error::MilestoneInvalidIndex)?;
-
-        if milestone.is_complete {
-            return Err(Error::MilestoneAlreadyCompleted);
-        }
-
-        // --- Voting Logic ---
-        // Check if total vote weight exceeds 50% of raised funds
-        let mut total_vote_weight: u128 = 0;
-        for (backer, _voted_yes) in milestone.votes.iter() {
-            let backer_amount = project.backers.get(backer).unwrap_or(0);
-            total_vote_weight += backer_amount;
+    /// Releases funds for a completed milestone of project `project_id`.
+    pub fn release_funds(env: Env, project_id: u32, milestone_index: u32) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        if let Some(campaign) = projects::route(&env, project_id)? {
+            MilestoneFundClient::new(&env, &campaign).release_funds(&project_id, &milestone_index);
+            return projects::sync(&env, project_id);
         }
+        let mut project = Self::get_project(&env)?;
+        evidence::apply_penalty(&env, &mut project, milestone_index)?;
+        let mut milestone = approved_milestone(&env, &project, milestone_index)?;
 
-        // Check for > 50% approval by funding amount
-        if total_vote_weight * 2 <= project.raised {
-            return Err(Error::MilestoneNotYetApproved);
+        // Nothing leaves the contract while its balance disagrees with the books
+        if breaker::trip_on_divergence(&env, &project)? {
+            return Ok(());
         }
 
         // --- Mark as complete and transfer funds ---
         milestone.is_complete = true;
+        milestone.escrow.released = milestone.amount_to_release;
         project.milestones.set(milestone_index, milestone.clone());
-        env.storage().instance().set(&DataKey::ProjectInfo, &project);
-        
+        lifecycle::advance(&env, &mut project);
+        save_project(&env, &project);
+        let net = settle_release(&env, &project, milestone_index, milestone.amount_to_release)?;
+        let payable = release_cap::admit(&env, net);
+
         // --- Execute Transfer ---
-        // let token_client = token::Client::new(&env, &project.token);
-        // token_client.transfer(&env.current_contract_address(), &project.creator, &milestone.amount_to_release);
+        // Only after every state write above, so a reentrant call sees the milestone complete
+        if payable > 0 {
+            pay_out(&env, &project.token, &project.creator, payable)?;
+        }
+        platform::report(&env, &project);
 
         // Emit an event
         let topics = (symbol_short!("release"), project.creator);
-        env.events().publish(topics, milestone.amount_to_release);
+        events::publish(&env, topics, milestone.amount_to_release);
+        watch::publish_transition(&env, symbol_short!("released"), milestone_index);
 
         Ok(())
     }
 
-    /// Allows backers to claim a refund if the goal was not met by the deadline.
-    pub fn claim_refund(env: Env, backer: Address) -> Result<(), Error> {
+    /// Allows backers to claim a refund from project `project_id` if its
+    /// goal was not met by the deadline.
+    pub fn claim_refund(env: Env, project_id: u32, backer: Address) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        if let Some(campaign) = projects::route(&env, project_id)? {
+            MilestoneFundClient::new(&env, &campaign).claim_refund(&project_id, &backer);
+            return projects::sync(&env, project_id);
+        }
         backer.require_auth();
-        let project = Self::get_project(&env)?;
+        let mut project = Self::get_project(&env)?;
 
-        // Refunds only available if deadline passed AND goal was NOT met
+        // Refunds only available if deadline passed AND goal was NOT met,
+        // or the creator cancelled before any payout
         // FIX: Use u64::from() for explicit type conversion
-        if u64::from(env.ledger().sequence()) <= project.deadline || project.goal_met {
+        let failed = u64::from(env.ledger().sequence()) > project.deadline && !project.goal_met;
+        if !failed && !cancellation::refunds_open(&env) {
             return Err(Error::RefundsNotAvailable);
         }
+        in_kind::ensure_cash(&env, &project, &backer)?;
+
+        let amount_to_refund = backers::contribution(&env, &backer).ok_or(Error::NoRefundsToClaim)?;
 
-        let amount_to_refund = project
-            .backers
-            .get(backer.clone())
-            .ok_or(Error::NoRefundsToClaim)?;
-            
         if amount_to_refund == 0 {
             return Err(Error::NoRefundsToClaim);
         }
-        
-        // --- Execute Transfer ---
-        // In a real contract, this would transfer `amount_to_refund` of
-        // `project.token` back to the `backer`.
-        
-        // --- Update State to prevent double claim ---
-        // We'd need to modify the `Project` struct to track refunds,
-        // or just set the backer's amount to 0. For simplicity, we assume
-        // a separate storage for refunds or setting backer amount to 0.
-        // let mut project_mut = project;
-        // project_mut.backers.set(backer.clone(), 0);
-        // env.storage().instance().set(&DataKey::ProjectInfo, &project_mut);
 
+        if breaker::trip_on_divergence(&env, &project)? {
+            return Ok(());
+        }
+
+        // --- Update State to prevent double claim, then transfer ---
+        // The backer's refund-pool share (e.g. of a cancellation fee) is
+        // derived from the contribution zeroed here, so it is paid with it
+        let pool_share = claims::take_claimable(&env, &mut project, &backer)?;
+        let total = amount_to_refund
+            .checked_add(pool_share)
+            .ok_or(Error::Overflow)?;
+        backers::set(&env, &backer, 0);
+        escrow::refund(&mut project, amount_to_refund)?;
+        lifecycle::advance(&env, &mut project);
+        save_project(&env, &project);
+
+        pay_out(&env, &project.token, &backer, total)?;
+        platform::report(&env, &project);
+
+        events::publish(&env, (symbol_short!("refund"), backer), total);
         Ok(())
     }
 
     // --- Helper & View Functions ---
 
-    /// (View) Gets the full project details.
+    /// (View) Gets the full project details. Fails with `SchemaMismatch`
+    /// while the stored layout awaits `migrate`.
     pub fn get_project(env: &Env) -> Result<Project, Error> {
+        upgrade::ensure_current(env)?;
+        let storage = env.storage().instance();
+        let config: ProjectConfig = storage
+            .get(&DataKey::ProjectInfo)
+            .ok_or(Error::ProjectNotInitialized)?;
+        let status: ProjectStatus = storage
+            .get(&StateKey::ProjectStatus)
+            .ok_or(Error::ProjectNotInitialized)?;
+        Ok(Project {
+            creator: config.creator,
+            token: config.token,
+            goal: config.goal,
+            raised: status.raised,
+            deadline: config.deadline,
+            start_ledger: config.start_ledger,
+            milestones: storage
+                .get(&StateKey::Milestones)
+                .unwrap_or_else(|| Vec::new(env)),
+            goal_met: status.goal_met,
+            surplus: status.surplus,
+            refund_pool: status.refund_pool,
+            refund_pool_claimed: status.refund_pool_claimed,
+            state: status.state,
+            approval_threshold_bps: config.approval_threshold_bps,
+            quorum_bps: config.quorum_bps,
+        })
+    }
+
+    /// (View) Gets a compact overview of the project, including its watcher count.
+    pub fn get_summary(env: Env) -> Result<ProjectSummary, Error> {
+        let project = Self::get_project(&env)?;
+        Ok(ProjectSummary {
+            creator: project.creator,
+            token: project.token,
+            goal: project.goal,
+            raised: project.raised,
+            deadline: project.deadline,
+            start_ledger: project.start_ledger,
+            goal_met: project.goal_met,
+            milestone_count: project.milestones.len(),
+            backer_count: backers::count(&env),
+            watcher_count: watch::watcher_count(&env),
+        })
+    }
+
+    /// (View) Gets the administrator address.
+    pub fn get_admin(env: Env) -> Result<Address, Error> {
         env.storage()
             .instance()
-            .get(&DataKey::ProjectInfo)
+            .get(&DataKey::Admin)
             .ok_or(Error::ProjectNotInitialized)
     }
 
-    /// (View) Gets the amount a specific backer has funded.
-    pub fn get_backer_info(env: Env, backer: Address) -> Result<u128, Error> {
+    /// (Authenticated view) Gets the amount a specific backer has funded.
+    /// `viewer` must be the backer, the creator or the admin.
+    pub fn get_backer_info(env: Env, viewer: Address, backer: Address) -> Result<u128, Error> {
         let project = Self::get_project(&env)?;
-        Ok(project.backers.get(backer).unwrap_or(0))
+        privacy::authorize_viewer(&env, &project, &viewer, Some(&backer))?;
+        Ok(backers::amount(&env, &backer))
+    }
+}
+
+// --- Internal Helpers ---
+
+/// Rejects contributions before the launch, once the goal is met or after the deadline.
+pub(crate) fn ensure_funding_open(env: &Env, project: &Project) -> Result<(), Error> {
+    migration::ensure_not_migrated(env)?;
+    emergency::ensure_not_paused(env)?;
+    if project.state == ProjectState::Cancelled {
+        return Err(Error::FundingIsClosed);
+    }
+    if project.goal_met {
+        return Err(Error::GoalAlreadyMet);
+    }
+    // FIX: Use u64::from() for explicit type conversion
+    let now = u64::from(env.ledger().sequence());
+    if now < project.start_ledger || drafts::is_drafting(env) {
+        return Err(Error::FundingNotStarted);
+    }
+    if now > project.deadline {
+        return Err(Error::DeadlinePassed);
+    }
+    if pauses::is_paused(env) {
+        return Err(Error::FundingIsClosed);
+    }
+    Ok(())
+}
+
+/// Transfers `amount` of `token` from the contract to `to`.
+pub(crate) fn pay_out(env: &Env, token: &Address, to: &Address, amount: u128) -> Result<(), Error> {
+    let amount = i128::try_from(amount).map_err(|_| Error::AmountTooLarge)?;
+    token::Client::new(env, token).transfer(&env.current_contract_address(), to, &amount);
+    Ok(())
+}
+
+/// Transfers `amount` of `token` from `from` into the contract.
+pub(crate) fn collect(env: &Env, token: &Address, from: &Address, amount: u128) -> Result<(), Error> {
+    let amount = i128::try_from(amount).map_err(|_| Error::AmountTooLarge)?;
+    token::Client::new(env, token).transfer(from, &env.current_contract_address(), &amount);
+    Ok(())
+}
+
+/// Records `backer`'s vote on a milestone of `project`.
+pub(crate) fn cast_vote(
+    env: &Env,
+    project: &Project,
+    backer: &Address,
+    milestone_index: u32,
+    approve: bool,
+) -> Result<(), Error> {
+    emergency::ensure_not_paused(env)?;
+    if !project.goal_met {
+        return Err(Error::GoalNotMet);
+    }
+    if !backers::is_backer(env, backer) {
+        return Err(Error::NotABacker);
+    }
+
+    let milestone = project
+        .milestones
+        .get(milestone_index)
+        .ok_or(Error::MilestoneInvalidIndex)?;
+
+    if milestone.is_rejected {
+        return Err(Error::MilestoneRejected);
+    }
+    if milestone.is_complete {
+        return Err(Error::MilestoneAlreadyCompleted);
+    }
+    freeze::ensure_not_frozen(env, milestone_index)?;
+    voting_deadline::ensure_open(env, milestone_index)?;
+
+    if votes::has_voted(env, milestone_index, backer) {
+        return Err(Error::AlreadyVoted);
+    }
+    limits::ensure_room(
+        env,
+        symbol_short!("votes"),
+        votes::count(env, milestone_index),
+        limits::load(env).max_votes_per_milestone,
+    )?;
+
+    // Record the vote
+    votes::cast(env, milestone_index, backer, approve);
+    let action = symbol_short!("vote");
+    receipts::publish(env, action, project, milestone_index, backer, approve)?;
+    rebates::record_participation(env, backer, milestone_index);
+    timelock::note_vote(env, project, milestone_index)?;
+    inactivity::note_active(env, backer);
+
+    Ok(())
+}
+
+/// Whether the backers in `votes` hold at least two thirds of the raised weight.
+pub(crate) fn has_supermajority(
+    env: &Env,
+    project: &Project,
+    votes: &Map<Address, bool>,
+) -> Result<bool, Error> {
+    let mut weight: u128 = 0;
+    for (backer, _) in votes.iter() {
+        weight = weight
+            .checked_add(backers::amount(env, &backer))
+            .ok_or(Error::Overflow)?;
+    }
+    governance::clears_supermajority(weight, project.raised)
+}
+
+/// Gets a milestone that has passed its vote and can be paid out.
+pub(crate) fn approved_milestone(
+    env: &Env,
+    project: &Project,
+    milestone_index: u32,
+) -> Result<Milestone, Error> {
+    emergency::ensure_not_paused(env)?;
+    if !project.goal_met {
+        return Err(Error::GoalNotMet);
+    }
+    // A retro round pays out the whole pot at settlement instead
+    if retro::is_enabled(env) {
+        return Err(Error::FeatureDisabled);
+    }
+
+    let milestone = project
+        .milestones
+        .get(milestone_index)
+        .ok_or(Error::MilestoneInvalidIndex)?;
+
+    if milestone.is_rejected || rejection::is_carried(env, project, milestone_index)? {
+        return Err(Error::MilestoneRejected);
+    }
+    if milestone.is_complete {
+        return Err(Error::MilestoneAlreadyCompleted);
+    }
+    if stream::is_streaming(env, milestone_index) {
+        return Err(Error::MilestoneStreaming);
+    }
+    freeze::ensure_not_frozen(env, milestone_index)?;
+    sequential::ensure_in_order(env, project, milestone_index)?;
+    memos::ensure_attached(env, milestone_index)?;
+
+    // --- Voting Logic ---
+    // Check the approval threshold and quorum by vote weight, unless an
+    // optimistic release has sat out its challenge window, which stands in
+    // for both the vote and the timelock
+    if !optimistic::is_unchallenged(env, project, milestone_index)? {
+        if !is_approved(env, project, milestone_index)? {
+            return Err(Error::MilestoneNotYetApproved);
+        }
+        timelock::ensure_elapsed(env, milestone_index)?;
     }
-}
\ No newline at end of file
+
+    Ok(milestone)
+}
+
+/// Runs the bookkeeping every release path shares once the released
+/// milestone is stored: completion rewards, the on-time bounty, the payout
+/// ledger entry (the milestone's total released so far) and the insurance
+/// premium and platform fee on `amount`. Returns what is left of `amount`
+/// for the caller to pay out, after which it calls `platform::report`.
+pub(crate) fn settle_release(
+    env: &Env,
+    project: &Project,
+    milestone_index: u32,
+    amount: u128,
+) -> Result<u128, Error> {
+    rewards::note_completion(env, project);
+    bounty::settle(env, project, milestone_index)?;
+    let released = project
+        .milestones
+        .get(milestone_index)
+        .ok_or(Error::MilestoneInvalidIndex)?
+        .escrow
+        .released;
+    memos::record(env, milestone_index, released);
+    let net = insurance::deduct_premium(env, project, amount)?;
+    fees::deduct(env, net)
+}
+
+/// Whether a milestone's votes clear the project's approval threshold and
+/// quorum.
+pub(crate) fn is_approved(env: &Env, project: &Project, milestone_index: u32) -> Result<bool, Error> {
+    let (approve_weight, eligible_weight) = vote_weights(env, project, milestone_index)?;
+    let (reject_weight, _) = reject_weights(env, project, milestone_index)?;
+    let turnout = approve_weight
+        .checked_add(reject_weight)
+        .ok_or(Error::Overflow)?;
+    Ok(governance::clears_threshold(project, approve_weight, eligible_weight)?
+        && governance::meets_quorum(project, turnout, eligible_weight)?)
+}
+
+/// Approving weight on a milestone, fallback votes included, and the weight
+/// of every backer but the inactive ones who have not voted.
+pub(crate) fn vote_weights(
+    env: &Env,
+    project: &Project,
+    milestone_index: u32,
+) -> Result<(u128, u128), Error> {
+    if milestone_index >= project.milestones.len() {
+        return Ok((0, 0));
+    }
+    // Plain contribution weighting is the running total, no walk needed
+    if weighting::mode(env) == WeightingMode::Contribution
+        && !delegation::is_active(env, project)
+        && !inactivity::is_enabled(env)
+    {
+        return Ok((votes::approved_weight(env, milestone_index), project.raised));
+    }
+    tally_votes(env, project, &votes::all(env, milestone_index))
+}
+
+/// Rejecting weight on a milestone, fallback votes included, and the same
+/// eligible weight as `vote_weights`.
+pub(crate) fn reject_weights(
+    env: &Env,
+    project: &Project,
+    milestone_index: u32,
+) -> Result<(u128, u128), Error> {
+    if milestone_index >= project.milestones.len() {
+        return Ok((0, 0));
+    }
+    if weighting::mode(env) == WeightingMode::Contribution
+        && !delegation::is_active(env, project)
+        && !inactivity::is_enabled(env)
+    {
+        return Ok((votes::rejected_weight(env, milestone_index), project.raised));
+    }
+    // Flipped, the rejections tally as approvals and delegators follow suit
+    let mut flipped = Map::new(env);
+    for (backer, approve) in votes::all(env, milestone_index).iter() {
+        flipped.set(backer, !approve);
+    }
+    tally_votes(env, project, &flipped)
+}
+
+/// Approving weight in `votes`, fallback votes included, and the weight of
+/// every backer but the inactive ones who have not voted.
+pub(crate) fn tally_votes(
+    env: &Env,
+    project: &Project,
+    votes: &Map<Address, bool>,
+) -> Result<(u128, u128), Error> {
+    let votes = delegation::effective_votes(env, project, votes);
+    let (yes, eligible) = weighting::tally(env, project, &votes);
+    let eligible = eligible
+        .checked_sub(inactivity::excluded_weight(env, &votes)?)
+        .ok_or(Error::Overflow)?;
+    Ok((yes, eligible))
+}
+
+/// Checks every precondition of crediting `amount` to `backer`.
+///
+/// All funding paths and the `can_fund` dry run go through here, so the
+/// dry run always reports the same error a real call would produce.
+pub(crate) fn check_contribution(
+    env: &Env,
+    project: &Project,
+    backer: &Address,
+    amount: u128,
+) -> Result<(), Error> {
+    ensure_funding_open(env, project)?;
+    if amount == 0 || amount < min_contribution(env) {
+        return Err(Error::FundingAmountTooLow);
+    }
+    // Amounts must stay representable as token (i128) amounts
+    let current_funding = backers::amount(env, backer);
+    let limit = i128::MAX as u128;
+    if amount > limit || project.raised > limit - amount || current_funding > limit - amount {
+        return Err(Error::AmountTooLarge);
+    }
+    sybil::check(env, project, backer)?;
+    limits::admit_backer(env, backer)
+}
+
+fn min_contribution(env: &Env) -> u128 {
+    env.storage()
+        .instance()
+        .get(&StorageKey::MinContribution)
+        .unwrap_or(0)
+}
+
+/// Credits `amount` to `backer`, persists the project and emits the funding events.
+pub(crate) fn record_contribution(
+    env: &Env,
+    mut project: Project,
+    backer: &Address,
+    amount: u128,
+) -> Result<(), Error> {
+    personhood::admit(env, backer, amount)?;
+    let joined = !backers::is_backer(env, backer);
+    let amount = burn::burn_share(env, &project, amount)?;
+    project.raised = project
+        .raised
+        .checked_add(amount)
+        .ok_or(Error::AmountTooLarge)?;
+    escrow::earmark(&mut project, amount)?;
+    if limits::backers_full(env, backer) {
+        limits::credit_anonymous_pool(env, backer, amount)?;
+    } else {
+        let funded = backers::amount(env, backer)
+            .checked_add(amount)
+            .ok_or(Error::AmountTooLarge)?;
+        backers::set(env, backer, funded);
+    }
+    stats::record(env, backer, amount);
+    ticker::record(env, backer, amount);
+    weighting::record(env, backer, amount);
+    terms::lock_on_first_contribution(env);
+    sybil::note_seen(env, backer);
+    inactivity::note_active(env, backer);
+
+    // Check if goal is now met
+    let goal_just_met = project.raised >= project.goal;
+    if goal_just_met {
+        project.goal_met = true;
+        // Optionally close funding, or allow over-funding
+    }
+    lifecycle::advance(env, &mut project);
+
+    save_project(env, &project);
+    platform::report(env, &project);
+    cohorts::report(env, backer, amount, joined);
+
+    // Emit an event (good practice)
+    let topics = (symbol_short!("fund"), backer.clone());
+    events::publish(env, topics, amount);
+
+    if goal_just_met {
+        watch::publish_transition(env, symbol_short!("goal_met"), project.raised);
+    }
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Env};
+
+use crate::{
+    breaker, events, platform, save_project, token_sale, watch, Error, MilestoneFund,
+    MilestoneFundClient, Project, StorageKey,
+};
+
+// --- Project Lifecycle ---
+// The project records where it is in its life, so callers and subsystems
+// read one field instead of re-deriving it from the goal, the deadline and
+// the milestones. Every write that can move the campaign along (a
+// contribution meeting the goal, a milestone settling, a refund, a
+// cancellation) advances the state before saving the project.
+//
+// Once the deadline has passed, anyone can `finalize` the campaign: it
+// settles on Succeeded (or wherever its payouts have taken it) or Failed,
+// is marked closed, and a terminal event tells indexers and frontends that
+// releases, respectively refunds, are now the only way forward.
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProjectState {
+    Funding,   // Raising, before the goal is met
+    Succeeded, // Goal met, nothing paid out yet
+    Active,    // Payouts have started
+    Completed, // Every milestone settled
+    Failed,    // Deadline passed with the goal unmet
+    Cancelled, // Creator walked away
+}
+
+impl ProjectState {
+    /// Whether the campaign can no longer leave this state.
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            ProjectState::Completed | ProjectState::Failed | ProjectState::Cancelled
+        )
+    }
+}
+
+/// Moves `project` to the state its books now call for, without persisting
+/// it. Final states are never left.
+pub(crate) fn advance(env: &Env, project: &mut Project) {
+    if project.state.is_final() {
+        return;
+    }
+    let settled = project.milestones.iter().filter(|m| m.is_complete).count() as u32;
+    let started = project
+        .milestones
+        .iter()
+        .any(|m| m.is_complete || m.escrow.released > 0);
+    project.state = if !project.goal_met {
+        if u64::from(env.ledger().sequence()) > project.deadline {
+            ProjectState::Failed
+        } else {
+            ProjectState::Funding
+        }
+    } else if settled == project.milestones.len() {
+        ProjectState::Completed
+    } else if started {
+        ProjectState::Active
+    } else {
+        ProjectState::Succeeded
+    };
+}
+
+fn finalized_at(env: &Env) -> Option<u64> {
+    env.storage().instance().get(&StorageKey::FinalizedAt)
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// Closes the campaign after its deadline, or earlier once it has
+    /// reached a final state: records `Failed` if the goal was missed and
+    /// otherwise the state its payouts have reached, and settles any
+    /// project token sale. Callable by anyone, once. Returns the recorded
+    /// state.
+    pub fn finalize(env: Env) -> Result<ProjectState, Error> {
+        breaker::ensure_writable(&env)?;
+        let mut project = Self::get_project(&env)?;
+        if finalized_at(&env).is_some() {
+            return Err(Error::AlreadyExists);
+        }
+        advance(&env, &mut project);
+        let now = u64::from(env.ledger().sequence());
+        if now <= project.deadline && !project.state.is_final() {
+            return Err(Error::NotDue);
+        }
+        token_sale::settle(&env, &mut project)?;
+        save_project(&env, &project);
+        env.storage().instance().set(&StorageKey::FinalizedAt, &now);
+        platform::report(&env, &project);
+
+        events::publish(&env, (symbol_short!("finalize"),), project.state);
+        watch::publish_transition(&env, symbol_short!("finalized"), project.state);
+        Ok(project.state)
+    }
+
+    /// (View) Gets the ledger the campaign was finalized at, if it was.
+    pub fn get_finalized_at(env: Env) -> Option<u64> {
+        finalized_at(&env)
+    }
+
+    /// (View) Gets the recorded lifecycle state of the project.
+    pub fn get_state(env: Env) -> Result<ProjectState, Error> {
+        Ok(Self::get_project(&env)?.state)
+    }
+}
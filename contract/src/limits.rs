@@ -0,0 +1,196 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env, Symbol};
+
+use crate::{
+    access, backers, breaker, cancellation, escrow, events, lifecycle, pay_out, save_project, ttl,
+    DataKey, Error, MilestoneFund, MilestoneFundClient, StateKey,
+};
+
+// --- Storage Growth Limits ---
+// A hosted deployment must never hit ledger entry size limits mid-campaign,
+// so the admin can cap the maps that grow with participation. Inserts past
+// a cap fail with `CapExceeded`, and a `("storage", "near_cap")` event is
+// published from 90% of a cap on so operators see it coming. New backers
+// arriving at a full backer map can instead be routed to the anonymous
+// pool: their tokens count towards the goal, but they get no vote or
+// reward share. What each of them put in is kept in its own persistent
+// entry, outside the capped index, so they can take it back with
+// `claim_anonymous_refund` when the campaign fails or is cancelled. The
+// creator's metadata updates and the terms amendment log are capped too.
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OverflowPolicy {
+    Reject,
+    AnonymousPool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StorageLimits {
+    pub max_backers: Option<u32>,
+    pub max_votes_per_milestone: Option<u32>,
+    pub max_watchers: Option<u32>,
+    pub max_sources: Option<u32>, // Distinct contribution source tags
+    pub max_updates: Option<u32>, // Published metadata versions
+    pub max_audit_entries: Option<u32>, // Recorded terms amendments
+    pub overflow: OverflowPolicy, // What happens to new backers once `max_backers` is reached
+}
+
+pub(crate) fn load(env: &Env) -> StorageLimits {
+    env.storage()
+        .instance()
+        .get(&DataKey::StorageLimits)
+        .unwrap_or(StorageLimits {
+            max_backers: None,
+            max_votes_per_milestone: None,
+            max_watchers: None,
+            max_sources: None,
+            max_updates: None,
+            max_audit_entries: None,
+            overflow: OverflowPolicy::Reject,
+        })
+}
+
+pub(crate) fn anonymous_pool(env: &Env) -> u128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::AnonymousPool)
+        .unwrap_or(0)
+}
+
+/// What `contributor` has put into the anonymous pool and not taken back.
+fn anonymous_contribution(env: &Env, contributor: &Address) -> u128 {
+    env.storage()
+        .persistent()
+        .get(&StateKey::AnonymousContribution(contributor.clone()))
+        .unwrap_or(0)
+}
+
+/// Books `amount` from `contributor` in the anonymous pool.
+pub(crate) fn credit_anonymous_pool(
+    env: &Env,
+    contributor: &Address,
+    amount: u128,
+) -> Result<(), Error> {
+    let pool = anonymous_pool(env)
+        .checked_add(amount)
+        .ok_or(Error::AmountTooLarge)?;
+    let contributed = anonymous_contribution(env, contributor)
+        .checked_add(amount)
+        .ok_or(Error::AmountTooLarge)?;
+    env.storage().instance().set(&DataKey::AnonymousPool, &pool);
+    let key = StateKey::AnonymousContribution(contributor.clone());
+    ttl::set_persistent(env, &key, &contributed);
+    Ok(())
+}
+
+/// Whether `backer` would need a new backer entry that no longer fits.
+pub(crate) fn backers_full(env: &Env, backer: &Address) -> bool {
+    !backers::is_backer(env, backer)
+        && load(env)
+            .max_backers
+            .is_some_and(|max| backers::count(env) >= max)
+}
+
+/// Checks that `backer` can be admitted as a backer, or routed to the
+/// anonymous pool under the overflow policy.
+pub(crate) fn admit_backer(env: &Env, backer: &Address) -> Result<(), Error> {
+    if backers::is_backer(env, backer) {
+        return Ok(());
+    }
+    let limits = load(env);
+    if backers_full(env, backer) && limits.overflow == OverflowPolicy::AnonymousPool {
+        return Ok(());
+    }
+    ensure_room(
+        env,
+        symbol_short!("backers"),
+        backers::count(env),
+        limits.max_backers,
+    )
+}
+
+/// Checks that one more entry fits next to `used` ones under `max`,
+/// warning once the map is at 90% of its cap.
+pub(crate) fn ensure_room(
+    env: &Env,
+    kind: Symbol,
+    used: u32,
+    max: Option<u32>,
+) -> Result<(), Error> {
+    let Some(max) = max else {
+        return Ok(());
+    };
+    if used >= max {
+        return Err(Error::CapExceeded);
+    }
+    if u64::from(used + 1) * 10 >= u64::from(max) * 9 {
+        let topics = (symbol_short!("storage"), symbol_short!("near_cap"));
+        events::publish(env, topics, (kind, used + 1, max));
+    }
+    Ok(())
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Admin) Sets the storage growth caps and the backer overflow policy.
+    pub fn set_storage_limits(env: Env, limits: StorageLimits) -> Result<(), Error> {
+        access::require_admin(&env)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::StorageLimits, &limits);
+        Ok(())
+    }
+
+    /// (View) Gets the storage growth caps.
+    pub fn get_storage_limits(env: Env) -> StorageLimits {
+        load(&env)
+    }
+
+    /// Pays `contributor` back what they put into the anonymous pool, once
+    /// the campaign has failed or been cancelled. Returns the amount paid.
+    pub fn claim_anonymous_refund(env: Env, contributor: Address) -> Result<u128, Error> {
+        breaker::ensure_writable(&env)?;
+        contributor.require_auth();
+        let mut project = Self::get_project(&env)?;
+        let failed = u64::from(env.ledger().sequence()) > project.deadline && !project.goal_met;
+        if !failed && !cancellation::refunds_open(&env) {
+            return Err(Error::RefundsNotAvailable);
+        }
+        let amount = anonymous_contribution(&env, &contributor);
+        if amount == 0 {
+            return Err(Error::NoRefundsToClaim);
+        }
+        if breaker::trip_on_divergence(&env, &project)? {
+            return Ok(0);
+        }
+
+        env.storage()
+            .persistent()
+            .remove(&StateKey::AnonymousContribution(contributor.clone()));
+        env.storage()
+            .instance()
+            .set(&DataKey::AnonymousPool, &(anonymous_pool(&env) - amount));
+        escrow::refund(&mut project, amount)?;
+        lifecycle::advance(&env, &mut project);
+        save_project(&env, &project);
+        pay_out(&env, &project.token, &contributor, amount)?;
+
+        events::publish(
+            &env,
+            (symbol_short!("refund"), symbol_short!("anon"), contributor),
+            amount,
+        );
+        Ok(amount)
+    }
+
+    /// (View) Gets what `contributor` has in the anonymous pool.
+    pub fn get_anonymous_contribution(env: Env, contributor: Address) -> u128 {
+        anonymous_contribution(&env, &contributor)
+    }
+
+    /// (View) Gets the total contributed by backers routed to the anonymous pool.
+    pub fn get_anonymous_pool(env: Env) -> u128 {
+        anonymous_pool(&env)
+    }
+}
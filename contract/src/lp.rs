@@ -0,0 +1,123 @@
+use soroban_sdk::{
+    contractclient, contractimpl, contracttype, symbol_short, token, Address, Env, IntoVal, Vec,
+};
+
+use crate::{
+    access, auth, breaker, check_contribution, ensure_funding_open, events, record_contribution,
+    ttl, DataKey, Error, MilestoneFund, MilestoneFundClient,
+};
+
+// --- Liquidity Pool Share Contributions ---
+// Backers holding LP shares can contribute without exiting their position
+// first: the shares are handed to a configured adapter that redeems them and
+// swaps the proceeds into the campaign token. The backer is credited with
+// whatever actually arrived, measured by the contract's own balance.
+
+/// Interface a share-token adapter must implement.
+#[contractclient(name = "LpAdapterClient")]
+pub trait LpAdapter {
+    /// Redeems `shares` of `share_token` already held by the adapter, swaps
+    /// the proceeds along `path` into `asset` and sends the result to `to`.
+    /// Returns the amount of `asset` delivered.
+    fn unwind(
+        env: Env,
+        share_token: Address,
+        shares: i128,
+        path: Vec<Address>,
+        asset: Address,
+        to: Address,
+    ) -> i128;
+}
+
+// How shares of one pool are turned into the campaign token
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LpRoute {
+    pub adapter: Address,
+    pub path: Vec<Address>, // Intermediate assets for the swap, may be empty
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Admin) Accepts shares of `share_token`, unwound through `adapter` along `path`.
+    pub fn set_lp_route(
+        env: Env,
+        share_token: Address,
+        adapter: Address,
+        path: Vec<Address>,
+    ) -> Result<(), Error> {
+        access::require_admin(&env)?;
+        let route = LpRoute { adapter, path };
+        ttl::set_persistent(&env, &DataKey::LpRoute(share_token), &route);
+        Ok(())
+    }
+
+    /// (Admin) Stops accepting shares of `share_token`.
+    pub fn remove_lp_route(env: Env, share_token: Address) -> Result<(), Error> {
+        access::require_admin(&env)?;
+        env.storage()
+            .persistent()
+            .remove(&DataKey::LpRoute(share_token));
+        Ok(())
+    }
+
+    /// Funds the project with LP shares, credited at the realized amount of
+    /// the campaign token. Fails with `SlippageExceeded` if fewer than
+    /// `min_amount` tokens arrive. The backer authorizes `(contract,
+    /// share_token, shares, min_amount, nonce)`.
+    pub fn fund_with_lp(
+        env: Env,
+        backer: Address,
+        share_token: Address,
+        shares: i128,
+        min_amount: u128,
+    ) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        auth::require_auth_for_call(
+            &env,
+            &backer,
+            (share_token.clone(), shares, min_amount).into_val(&env),
+        );
+        let project = Self::get_project(&env)?;
+        ensure_funding_open(&env, &project)?;
+
+        if shares <= 0 {
+            return Err(Error::FundingAmountTooLow);
+        }
+        let route: LpRoute = env
+            .storage()
+            .persistent()
+            .get(&DataKey::LpRoute(share_token.clone()))
+            .ok_or(Error::NotFound)?;
+
+        let contract = env.current_contract_address();
+        let asset = token::Client::new(&env, &project.token);
+        let balance_before = asset.balance(&contract);
+
+        token::Client::new(&env, &share_token).transfer(&backer, &route.adapter, &shares);
+        LpAdapterClient::new(&env, &route.adapter).unwind(
+            &share_token,
+            &shares,
+            &route.path,
+            &project.token,
+            &contract,
+        );
+
+        let realized = u128::try_from(asset.balance(&contract) - balance_before).unwrap_or(0);
+        check_contribution(&env, &project, &backer, realized)?;
+        if realized < min_amount {
+            return Err(Error::SlippageExceeded);
+        }
+
+        let topics = (symbol_short!("fund_lp"), backer.clone());
+        events::publish(&env, topics, (share_token, shares, realized));
+        record_contribution(&env, project, &backer, realized)
+    }
+
+    /// (View) Gets the route used to unwind shares of `share_token`, if any.
+    pub fn get_lp_route(env: Env, share_token: Address) -> Option<LpRoute> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::LpRoute(share_token))
+    }
+}
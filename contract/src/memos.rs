@@ -0,0 +1,119 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, BytesN, Env, String};
+
+use crate::{access, events, ttl, Error, MilestoneFund, MilestoneFundClient, StateKey};
+
+// --- Release Memos ---
+// Creators reconcile payouts against invoices and deliverables off-chain.
+// Before a milestone is released the creator can attach a memo and the
+// hash of the invoice it pays; the admin can make one mandatory, in which
+// case an approved milestone without a memo is not released. Every payout
+// of a whole milestone is recorded with its memo in the payout ledger and
+// published as a `payout` event.
+
+/// Longest memo, in bytes.
+const MAX_MEMO_LEN: u32 = 256;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseMemo {
+    pub memo: String,
+    pub invoice_hash: Option<BytesN<32>>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutRecord {
+    pub amount: u128, // Released from the milestone, before premium and fees
+    pub ledger: u64,
+    pub memo: Option<ReleaseMemo>,
+}
+
+fn memo(env: &Env, milestone_index: u32) -> Option<ReleaseMemo> {
+    env.storage()
+        .persistent()
+        .get(&StateKey::ReleaseMemo(milestone_index))
+}
+
+fn is_required(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&StateKey::MemoRequired)
+        .unwrap_or(false)
+}
+
+/// Fails with `NotFound` if memos are mandatory and the milestone has none.
+pub(crate) fn ensure_attached(env: &Env, milestone_index: u32) -> Result<(), Error> {
+    if is_required(env) && memo(env, milestone_index).is_none() {
+        return Err(Error::NotFound);
+    }
+    Ok(())
+}
+
+/// Records the payout of `amount` for a milestone in the payout ledger.
+pub(crate) fn record(env: &Env, milestone_index: u32, amount: u128) {
+    let record = PayoutRecord {
+        amount,
+        ledger: u64::from(env.ledger().sequence()),
+        memo: memo(env, milestone_index),
+    };
+    ttl::set_persistent(env, &StateKey::Payout(milestone_index), &record);
+
+    events::publish(env, (symbol_short!("payout"), milestone_index), record);
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Creator) Attaches `memo` (at most 256 bytes) and the hash of the
+    /// invoice it pays to a milestone's release, replacing any earlier one.
+    /// Only possible until the milestone is released.
+    pub fn set_release_memo(
+        env: Env,
+        milestone_index: u32,
+        memo: String,
+        invoice_hash: Option<BytesN<32>>,
+    ) -> Result<(), Error> {
+        let project = access::require_creator(&env)?;
+        let milestone = project
+            .milestones
+            .get(milestone_index)
+            .ok_or(Error::MilestoneInvalidIndex)?;
+        if milestone.is_complete {
+            return Err(Error::MilestoneAlreadyCompleted);
+        }
+        if memo.is_empty() || memo.len() > MAX_MEMO_LEN {
+            return Err(Error::InvalidArgument);
+        }
+        ttl::set_persistent(
+            &env,
+            &StateKey::ReleaseMemo(milestone_index),
+            &ReleaseMemo { memo, invoice_hash },
+        );
+        Ok(())
+    }
+
+    /// (Admin) Sets whether every release needs a memo.
+    pub fn set_memo_required(env: Env, required: bool) -> Result<(), Error> {
+        access::require_admin(&env)?;
+        env.storage()
+            .instance()
+            .set(&StateKey::MemoRequired, &required);
+        Ok(())
+    }
+
+    /// (View) Checks whether every release needs a memo.
+    pub fn is_memo_required(env: Env) -> bool {
+        is_required(&env)
+    }
+
+    /// (View) Gets the memo attached to a milestone's release, if any.
+    pub fn get_release_memo(env: Env, milestone_index: u32) -> Option<ReleaseMemo> {
+        memo(&env, milestone_index)
+    }
+
+    /// (View) Gets a released milestone's entry in the payout ledger.
+    pub fn get_payout(env: Env, milestone_index: u32) -> Option<PayoutRecord> {
+        env.storage()
+            .persistent()
+            .get(&StateKey::Payout(milestone_index))
+    }
+}
@@ -0,0 +1,135 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, xdr::ToXdr, Bytes, BytesN, Env, Vec};
+
+use crate::{access, events, limits, ttl, Error, MilestoneFund, MilestoneFundClient, StorageKey};
+
+// --- Project Metadata ---
+// Long descriptions and image galleries do not fit the project entry. The
+// creator uploads them as chunks, each stored once under its SHA-256 hash,
+// and then publishes a manifest listing the chunks in order. Publishing
+// never overwrites: every manifest is a new version pointing back at the
+// hash of the one before it, so anyone can walk the history and check
+// which content the campaign showed when.
+
+const MAX_CHUNK_BYTES: u32 = 16_384;
+const MAX_CHUNKS: u32 = 64;
+const MAX_PAGE: u32 = 50;
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MetadataManifest {
+    pub version: u32,
+    pub hash: BytesN<32>,             // Hash of the ordered chunk hashes
+    pub previous: Option<BytesN<32>>, // Hash of the manifest this one replaced
+    pub chunks: Vec<BytesN<32>>,      // Chunk hashes, in order
+    pub size: u32,                    // Total bytes across the chunks
+    pub published_at: u64,
+}
+
+fn versions(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&StorageKey::MetadataVersions)
+        .unwrap_or(0)
+}
+
+fn chunk(env: &Env, hash: &BytesN<32>) -> Option<Bytes> {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::MetadataChunk(hash.clone()))
+}
+
+fn manifest(env: &Env, version: u32) -> Option<MetadataManifest> {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::MetadataManifest(version))
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Creator) Stores a metadata chunk of at most 16 KiB under its
+    /// SHA-256 hash, which it returns. Storing the same bytes twice is a
+    /// no-op.
+    pub fn put_metadata_chunk(env: Env, data: Bytes) -> Result<BytesN<32>, Error> {
+        access::require_creator(&env)?;
+        if data.is_empty() || data.len() > MAX_CHUNK_BYTES {
+            return Err(Error::InvalidArgument);
+        }
+        let hash = env.crypto().sha256(&data).to_bytes();
+        if chunk(&env, &hash).is_none() {
+            ttl::set_persistent(&env, &StorageKey::MetadataChunk(hash.clone()), &data);
+        }
+        Ok(hash)
+    }
+
+    /// (Creator) Publishes a new metadata manifest made of the stored
+    /// chunks `chunks`, in order. Returns its version number.
+    pub fn publish_metadata(env: Env, chunks: Vec<BytesN<32>>) -> Result<u32, Error> {
+        access::require_creator(&env)?;
+        if chunks.is_empty() || chunks.len() > MAX_CHUNKS {
+            return Err(Error::InvalidArgument);
+        }
+        let mut size: u32 = 0;
+        for hash in chunks.iter() {
+            size += chunk(&env, &hash).ok_or(Error::NotFound)?.len();
+        }
+
+        let version = versions(&env);
+        let max = limits::load(&env).max_updates;
+        limits::ensure_room(&env, symbol_short!("updates"), version, max)?;
+        let previous = version
+            .checked_sub(1)
+            .and_then(|latest| manifest(&env, latest))
+            .map(|latest| latest.hash);
+        let hash = env.crypto().sha256(&chunks.clone().to_xdr(&env)).to_bytes();
+        if previous.as_ref() == Some(&hash) {
+            return Err(Error::AlreadyExists);
+        }
+        let record = MetadataManifest {
+            version,
+            hash: hash.clone(),
+            previous,
+            chunks,
+            size,
+            published_at: u64::from(env.ledger().sequence()),
+        };
+        ttl::set_persistent(&env, &StorageKey::MetadataManifest(version), &record);
+        env.storage()
+            .instance()
+            .set(&StorageKey::MetadataVersions, &(version + 1));
+
+        events::publish(&env, (symbol_short!("metadata"), version), hash);
+        Ok(version)
+    }
+
+    /// (View) Gets a stored metadata chunk by its hash.
+    pub fn get_metadata_chunk(env: Env, hash: BytesN<32>) -> Option<Bytes> {
+        chunk(&env, &hash)
+    }
+
+    /// (View) Gets the latest metadata manifest, if any was published.
+    pub fn get_metadata(env: Env) -> Option<MetadataManifest> {
+        versions(&env)
+            .checked_sub(1)
+            .and_then(|latest| manifest(&env, latest))
+    }
+
+    /// (View) Lists up to `limit` (at most 50) metadata manifests from
+    /// version `start` on, oldest first.
+    pub fn get_metadata_history(
+        env: Env,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<MetadataManifest>, Error> {
+        if limit > MAX_PAGE {
+            return Err(Error::CapExceeded);
+        }
+        let end = versions(&env).min(start.saturating_add(limit));
+        let mut history = Vec::new(&env);
+        for version in start..end {
+            if let Some(record) = manifest(&env, version) {
+                history.push_back(record);
+            }
+        }
+        Ok(history)
+    }
+}
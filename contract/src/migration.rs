@@ -0,0 +1,137 @@
+use soroban_sdk::{
+    contractclient, contractimpl, contracttype, symbol_short, token, xdr::ToXdr, Address, Bytes,
+    Env,
+};
+
+use crate::{
+    access, backers, breaker, events, has_supermajority, polls, DataKey, Error, MilestoneFund,
+    MilestoneFundClient,
+};
+
+// --- Emergency Migration ---
+// A last-resort escape hatch for a critical bug an upgrade alone can't fix.
+// The admin proposes a rescue contract, backers holding a two-thirds
+// supermajority of the raised weight approve it, and the admin then ships
+// the whole token balance plus an XDR snapshot of the project to the
+// rescue contract. Afterwards the contract refuses funding and payouts.
+
+/// Interface a rescue contract must implement.
+#[contractclient(name = "RescueContractClient")]
+pub trait RescueContract {
+    /// Called after `amount` of `token` has been sent to the rescue contract.
+    /// `snapshot` is the XDR-encoded `Project` of the migrating contract `from`.
+    fn receive_migration(env: Env, from: Address, token: Address, amount: i128, snapshot: Bytes);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MigrationProposal {
+    pub rescue: Address,
+    pub round: u32, // Scope of the proposal's poll
+    pub executed: bool,
+}
+
+fn load(env: &Env) -> Option<MigrationProposal> {
+    env.storage().instance().get(&DataKey::Migration)
+}
+
+fn save(env: &Env, proposal: &MigrationProposal) {
+    env.storage().instance().set(&DataKey::Migration, proposal);
+}
+
+/// Fails with `Migrated` once the funds have moved to a rescue contract.
+pub(crate) fn ensure_not_migrated(env: &Env) -> Result<(), Error> {
+    match load(env) {
+        Some(proposal) if proposal.executed => Err(Error::Migrated),
+        _ => Ok(()),
+    }
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Admin) Proposes migrating to `rescue`, discarding any earlier proposal's votes.
+    pub fn propose_migration(env: Env, rescue: Address) -> Result<(), Error> {
+        access::require_admin(&env)?;
+        ensure_not_migrated(&env)?;
+
+        let proposal = MigrationProposal {
+            rescue: rescue.clone(),
+            round: polls::next_round(&env, &symbol_short!("migrate")),
+            executed: false,
+        };
+        save(&env, &proposal);
+
+        events::publish(
+            &env,
+            (symbol_short!("migrate"), symbol_short!("proposed")),
+            rescue,
+        );
+        Ok(())
+    }
+
+    /// Approves the pending migration proposal.
+    pub fn vote_migration(env: Env, backer: Address) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        backer.require_auth();
+        Self::get_project(&env)?;
+        let proposal = load(&env).ok_or(Error::NotFound)?;
+        if proposal.executed {
+            return Err(Error::Migrated);
+        }
+        if !backers::is_backer(&env, &backer) {
+            return Err(Error::NotABacker);
+        }
+        polls::cast(
+            &env,
+            &symbol_short!("migrate"),
+            proposal.round,
+            &backer,
+            true,
+        )
+    }
+
+    /// (Admin) Sends every token the contract holds and a project snapshot
+    /// to the approved rescue contract.
+    pub fn execute_migration(env: Env) -> Result<(), Error> {
+        access::require_admin(&env)?;
+        let project = Self::get_project(&env)?;
+        let mut proposal = load(&env).ok_or(Error::NotFound)?;
+        if proposal.executed {
+            return Err(Error::Migrated);
+        }
+
+        let votes = polls::all(&env, &symbol_short!("migrate"), proposal.round);
+        if !has_supermajority(&env, &project, &votes)? {
+            return Err(Error::NotApproved);
+        }
+
+        proposal.executed = true;
+        save(&env, &proposal);
+
+        let this = env.current_contract_address();
+        let token_client = token::Client::new(&env, &project.token);
+        let amount = token_client.balance(&this);
+        if amount > 0 {
+            token_client.transfer(&this, &proposal.rescue, &amount);
+        }
+        let snapshot = project.clone().to_xdr(&env);
+        RescueContractClient::new(&env, &proposal.rescue).receive_migration(
+            &this,
+            &project.token,
+            &amount,
+            &snapshot,
+        );
+
+        events::publish(
+            &env,
+            (symbol_short!("migrate"), symbol_short!("executed")),
+            (proposal.rescue, amount),
+        );
+        Ok(())
+    }
+
+    /// (View) Gets the current migration proposal, if any.
+    pub fn get_migration(env: Env) -> Option<MigrationProposal> {
+        load(&env)
+    }
+}
@@ -0,0 +1,166 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Env, Map};
+
+use crate::{
+    access, breaker, events, governance, projects, reject_weights, Error, MilestoneFund,
+    MilestoneFundClient, Project, StorageKey,
+};
+
+// --- Optimistic Release ---
+// Gathering a majority of backers for every milestone is slow. The creator
+// can opt the campaign into optimistic releases while it is still raising:
+// once the goal is met they `request_release` a milestone, which opens a
+// challenge window. Backers object by voting against the milestone; unless
+// the objecting weight clears the objection threshold, anyone can release
+// it once the window has closed, without a single approving vote. Votes
+// keep working as before, so a carried milestone need not wait for its
+// window.
+
+const BPS_DENOMINATOR: u128 = 10_000;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OptimisticRelease {
+    pub challenge_ledgers: u64,
+    pub objection_bps: u32, // Objecting weight that blocks a release, of the raised amount
+    pub requested_at: Map<u32, u64>, // Milestone index -> ledger its release was requested
+}
+
+fn load(env: &Env) -> Option<OptimisticRelease> {
+    env.storage().instance().get(&StorageKey::OptimisticRelease)
+}
+
+fn save(env: &Env, optimistic: &OptimisticRelease) {
+    env.storage()
+        .instance()
+        .set(&StorageKey::OptimisticRelease, optimistic);
+}
+
+/// Whether the objections to a milestone clear the objection threshold.
+fn is_challenged(
+    env: &Env,
+    project: &Project,
+    optimistic: &OptimisticRelease,
+    index: u32,
+) -> Result<bool, Error> {
+    let (object_weight, eligible_weight) = reject_weights(env, project, index)?;
+    let (object_weight, needed) =
+        governance::scaled(object_weight, eligible_weight, optimistic.objection_bps)?;
+    Ok(object_weight > needed)
+}
+
+/// Whether a milestone's requested release has sat out its challenge
+/// window without being challenged.
+pub(crate) fn is_unchallenged(
+    env: &Env,
+    project: &Project,
+    milestone_index: u32,
+) -> Result<bool, Error> {
+    let Some(optimistic) = load(env) else {
+        return Ok(false);
+    };
+    let Some(requested_at) = optimistic.requested_at.get(milestone_index) else {
+        return Ok(false);
+    };
+    Ok(
+        u64::from(env.ledger().sequence()) >= requested_at + optimistic.challenge_ledgers
+            && !is_challenged(env, project, &optimistic, milestone_index)?,
+    )
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Creator) Lets milestones be released after a challenge window of
+    /// `challenge_ledgers` unless backers holding more than `objection_bps`
+    /// of the raised amount object. Only possible while the campaign is
+    /// still raising.
+    pub fn set_optimistic_release(
+        env: Env,
+        challenge_ledgers: u64,
+        objection_bps: u32,
+    ) -> Result<(), Error> {
+        let project = access::require_creator(&env)?;
+        if project.goal_met {
+            return Err(Error::TermsLocked);
+        }
+        if challenge_ledgers == 0
+            || objection_bps == 0
+            || u128::from(objection_bps) >= BPS_DENOMINATOR
+        {
+            return Err(Error::InvalidArgument);
+        }
+        let optimistic = OptimisticRelease {
+            challenge_ledgers,
+            objection_bps,
+            requested_at: Map::new(&env),
+        };
+        save(&env, &optimistic);
+        Ok(())
+    }
+
+    /// (Creator) Opens the challenge window of a milestone's release.
+    /// Returns the ledger from which it can be executed.
+    pub fn request_release(env: Env, milestone_index: u32) -> Result<u64, Error> {
+        let project = access::require_creator(&env)?;
+        let mut optimistic = load(&env).ok_or(Error::FeatureDisabled)?;
+        if !project.goal_met {
+            return Err(Error::GoalNotMet);
+        }
+        let milestone = project
+            .milestones
+            .get(milestone_index)
+            .ok_or(Error::MilestoneInvalidIndex)?;
+        if milestone.is_rejected {
+            return Err(Error::MilestoneRejected);
+        }
+        if milestone.is_complete {
+            return Err(Error::MilestoneAlreadyCompleted);
+        }
+        if optimistic.requested_at.contains_key(milestone_index) {
+            return Err(Error::AlreadyExists);
+        }
+
+        let now = u64::from(env.ledger().sequence());
+        optimistic.requested_at.set(milestone_index, now);
+        save(&env, &optimistic);
+
+        let executable_at = now + optimistic.challenge_ledgers;
+        events::publish(
+            &env,
+            (symbol_short!("request"), milestone_index),
+            executable_at,
+        );
+        Ok(executable_at)
+    }
+
+    /// Releases a milestone whose challenge window has closed without
+    /// enough objections. Callable by anyone.
+    pub fn execute_release(env: Env, milestone_index: u32) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        let project = Self::get_project(&env)?;
+        let optimistic = load(&env).ok_or(Error::FeatureDisabled)?;
+        let requested_at = optimistic
+            .requested_at
+            .get(milestone_index)
+            .ok_or(Error::NotFound)?;
+        if u64::from(env.ledger().sequence()) < requested_at + optimistic.challenge_ledgers {
+            return Err(Error::NotDue);
+        }
+        if is_challenged(&env, &project, &optimistic, milestone_index)? {
+            return Err(Error::NotApproved);
+        }
+        Self::release_funds(env.clone(), projects::own_id(&env), milestone_index)?;
+
+        events::publish(
+            &env,
+            (symbol_short!("optrel"), milestone_index),
+            requested_at,
+        );
+        Ok(())
+    }
+
+    /// (View) Gets the optimistic release terms and the pending requests,
+    /// if the campaign uses them.
+    pub fn get_optimistic_release(env: Env) -> Option<OptimisticRelease> {
+        load(&env)
+    }
+}
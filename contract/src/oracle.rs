@@ -0,0 +1,58 @@
+use soroban_sdk::{contractclient, contractimpl, Address, Env};
+
+use crate::{access, Error, MilestoneFund, MilestoneFundClient, StorageKey};
+
+// --- Price Oracle ---
+// Amounts held in an asset other than the campaign token (collateral posted
+// in the creator's own project token or XLM, for instance) are valued in a
+// quote asset through an oracle contract set by the admin. The oracle does
+// the unit conversion itself, so callers never deal with prices or decimals.
+
+/// Interface the price oracle must implement.
+#[contractclient(name = "PriceOracleClient")]
+pub trait PriceOracle {
+    /// Current value in `quote` of `amount` of `asset`.
+    fn value(env: Env, asset: Address, amount: i128, quote: Address) -> i128;
+}
+
+fn oracle(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&StorageKey::PriceOracle)
+}
+
+/// Current value in `quote` of `amount` of `asset`. Fails without an oracle.
+pub(crate) fn value(
+    env: &Env,
+    asset: &Address,
+    amount: u128,
+    quote: &Address,
+) -> Result<u128, Error> {
+    if asset == quote {
+        return Ok(amount);
+    }
+    let oracle = oracle(env).ok_or(Error::FeatureDisabled)?;
+    let amount = i128::try_from(amount).map_err(|_| Error::AmountTooLarge)?;
+    let value = PriceOracleClient::new(env, &oracle).value(asset, &amount, quote);
+    u128::try_from(value).map_err(|_| Error::InvalidArgument)
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Admin) Sets the oracle foreign assets are valued through, or stops
+    /// accepting them with `None`.
+    pub fn set_price_oracle(env: Env, oracle: Option<Address>) -> Result<(), Error> {
+        access::require_admin(&env)?;
+        match oracle {
+            Some(oracle) => env
+                .storage()
+                .instance()
+                .set(&StorageKey::PriceOracle, &oracle),
+            None => env.storage().instance().remove(&StorageKey::PriceOracle),
+        }
+        Ok(())
+    }
+
+    /// (View) Gets the price oracle, if one is set.
+    pub fn get_price_oracle(env: Env) -> Option<Address> {
+        oracle(&env)
+    }
+}
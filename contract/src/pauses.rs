@@ -0,0 +1,129 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Env, Vec};
+
+use crate::{
+    access, ensure_funding_open, events, save_project, DataKey, Error, MilestoneFund,
+    MilestoneFundClient,
+};
+
+// --- Funding Pauses ---
+// The creator may pause funding (e.g. to fix a listing issue) for up to an
+// admin-configured total number of ledgers. Pausing pushes the deadline out
+// by the whole remaining allowance straight away and resuming early hands
+// the unused part back, so a pause nobody resumes simply runs out on its
+// own and the deadline always reflects the time funding was really open.
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PauseInterval {
+    pub start: u64,
+    pub end: u64, // In the future while the pause is ongoing
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FundingPauses {
+    pub allowance: u64, // Total ledgers funding may be paused for
+    pub used: u64,
+    pub intervals: Vec<PauseInterval>,
+}
+
+fn load(env: &Env) -> FundingPauses {
+    env.storage()
+        .instance()
+        .get(&DataKey::FundingPauses)
+        .unwrap_or(FundingPauses {
+            allowance: 0,
+            used: 0,
+            intervals: Vec::new(env),
+        })
+}
+
+fn save(env: &Env, pauses: &FundingPauses) {
+    env.storage()
+        .instance()
+        .set(&DataKey::FundingPauses, pauses);
+}
+
+/// The ongoing pause, if funding is paused at the current ledger.
+fn ongoing(env: &Env, pauses: &FundingPauses) -> Option<PauseInterval> {
+    let now = u64::from(env.ledger().sequence());
+    pauses
+        .intervals
+        .last()
+        .filter(|interval| interval.end > now)
+}
+
+pub(crate) fn is_paused(env: &Env) -> bool {
+    ongoing(env, &load(env)).is_some()
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Admin) Sets the total number of ledgers the creator may pause funding for.
+    pub fn set_pause_allowance(env: Env, allowance: u64) -> Result<(), Error> {
+        access::require_admin(&env)?;
+        let mut pauses = load(&env);
+        pauses.allowance = allowance;
+        save(&env, &pauses);
+        Ok(())
+    }
+
+    /// (Creator) Pauses funding for the rest of the pause allowance, or
+    /// until `resume_funding`. The deadline moves out accordingly.
+    pub fn pause_funding(env: Env) -> Result<(), Error> {
+        let mut project = access::require_creator(&env)?;
+        ensure_funding_open(&env, &project)?;
+
+        let mut pauses = load(&env);
+        let remaining = pauses.allowance.saturating_sub(pauses.used);
+        if remaining == 0 {
+            return Err(Error::CapExceeded);
+        }
+        let now = u64::from(env.ledger().sequence());
+        pauses.intervals.push_back(PauseInterval {
+            start: now,
+            end: now + remaining,
+        });
+        pauses.used += remaining;
+        save(&env, &pauses);
+        project.deadline += remaining;
+        save_project(&env, &project);
+
+        events::publish(
+            &env,
+            (symbol_short!("pause"),),
+            (now + remaining, project.deadline),
+        );
+        Ok(())
+    }
+
+    /// (Creator) Ends the ongoing pause early, pulling the deadline back by
+    /// the unused part of it.
+    pub fn resume_funding(env: Env) -> Result<(), Error> {
+        let mut project = access::require_creator(&env)?;
+
+        let mut pauses = load(&env);
+        let mut interval = ongoing(&env, &pauses).ok_or(Error::NotFound)?;
+        let now = u64::from(env.ledger().sequence());
+        let unused = interval.end - now;
+        interval.end = now;
+        pauses.intervals.set(pauses.intervals.len() - 1, interval);
+        pauses.used -= unused;
+        save(&env, &pauses);
+        project.deadline -= unused;
+        save_project(&env, &project);
+
+        events::publish(&env, (symbol_short!("resume"),), project.deadline);
+        Ok(())
+    }
+
+    /// (View) Gets the pause allowance and every pause interval so far.
+    pub fn get_funding_pauses(env: Env) -> FundingPauses {
+        load(&env)
+    }
+
+    /// (View) Checks whether funding is paused right now.
+    pub fn is_funding_paused(env: Env) -> bool {
+        is_paused(&env)
+    }
+}
@@ -0,0 +1,106 @@
+use soroban_sdk::{contractclient, contractimpl, contracttype, Address, BytesN, Env};
+
+use crate::{access, ttl, Error, MilestoneFund, MilestoneFundClient, StorageKey};
+
+// --- Proof of Personhood ---
+// A per-address cap does nothing against a backer who splits their funds
+// over fresh addresses to capture the votes. The creator can gate
+// contributions behind a proof-of-personhood attestation contract instead:
+// every contribution must come from an address attested to a person, and
+// the cap applies to the attestation id, so all the addresses of one
+// person share a single cap. Pledges taken back free up the cap again.
+
+/// Interface the proof-of-personhood attestation contract must implement.
+#[contractclient(name = "PersonhoodClient")]
+pub trait Personhood {
+    /// Attestation id of the person `subject` is attested to, if any.
+    fn attestation(env: Env, subject: Address) -> Option<BytesN<32>>;
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PersonhoodGate {
+    pub attestor: Address,    // Attestation contract
+    pub cap_per_person: u128, // Most one person may have pledged at a time
+}
+
+fn load(env: &Env) -> Option<PersonhoodGate> {
+    env.storage().instance().get(&StorageKey::PersonhoodGate)
+}
+
+fn pledged(env: &Env, person: &BytesN<32>) -> u128 {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::PersonPledged(person.clone()))
+        .unwrap_or(0)
+}
+
+fn set_pledged(env: &Env, person: &BytesN<32>, amount: u128) {
+    ttl::set_persistent(env, &StorageKey::PersonPledged(person.clone()), &amount);
+}
+
+/// Charges a contribution of `amount` from `backer` to the cap of the
+/// person they are attested to, failing if they are not attested or it
+/// would exceed the cap.
+pub(crate) fn admit(env: &Env, backer: &Address, amount: u128) -> Result<(), Error> {
+    let Some(gate) = load(env) else {
+        return Ok(());
+    };
+    let person = PersonhoodClient::new(env, &gate.attestor)
+        .attestation(backer)
+        .ok_or(Error::NotEligible)?;
+    let total = pledged(env, &person)
+        .checked_add(amount)
+        .ok_or(Error::AmountTooLarge)?;
+    if total > gate.cap_per_person {
+        return Err(Error::CapExceeded);
+    }
+    set_pledged(env, &person, total);
+    ttl::set_persistent(env, &StorageKey::PersonOf(backer.clone()), &person);
+    Ok(())
+}
+
+/// Gives `amount` of a withdrawn pledge back to the cap of the person it
+/// was charged to.
+pub(crate) fn release(env: &Env, backer: &Address, amount: u128) {
+    let person: Option<BytesN<32>> = env
+        .storage()
+        .persistent()
+        .get(&StorageKey::PersonOf(backer.clone()));
+    if let Some(person) = person {
+        set_pledged(env, &person, pledged(env, &person).saturating_sub(amount));
+    }
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Creator) Gates contributions behind a proof-of-personhood
+    /// attestation with a cap per person, or lifts the gate with `None`.
+    /// Only possible before the first contribution.
+    pub fn set_personhood_gate(env: Env, gate: Option<PersonhoodGate>) -> Result<(), Error> {
+        let project = access::require_creator(&env)?;
+        if project.raised > 0 {
+            return Err(Error::TermsLocked);
+        }
+        match gate {
+            Some(gate) if gate.cap_per_person == 0 => return Err(Error::InvalidArgument),
+            Some(gate) => env
+                .storage()
+                .instance()
+                .set(&StorageKey::PersonhoodGate, &gate),
+            None => env.storage().instance().remove(&StorageKey::PersonhoodGate),
+        }
+        Ok(())
+    }
+
+    /// (View) Gets the proof-of-personhood gate, if contributions are gated.
+    pub fn get_personhood_gate(env: Env) -> Option<PersonhoodGate> {
+        load(&env)
+    }
+
+    /// (View) Gets what the person behind attestation `person` has
+    /// pledged, across all their addresses.
+    pub fn get_person_pledged(env: Env, person: BytesN<32>) -> u128 {
+        pledged(&env, &person)
+    }
+}
@@ -0,0 +1,202 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, token, Address, Env, Map};
+
+use crate::{
+    breaker, cleanup, events, factory, ttl, Error, MilestoneFund, MilestoneFundClient, Project,
+    ProjectState, StorageKey,
+};
+
+// --- Platform Metrics ---
+// A factory keeps running totals across its campaigns: the value each
+// token has locked in them, what each has raised all-time, and how many
+// campaigns are active, succeeded or failed. Every campaign the factory
+// deployed reports its raised amount, balance and state to it after each
+// transition; the factory folds the difference from the campaign's last
+// report into the totals. A report a campaign could not deliver (the
+// factory paused, or the campaign called from the factory itself) is
+// caught up by `sync_campaign`, which anyone can call to have the factory
+// read a campaign directly.
+
+const BPS_DENOMINATOR: u32 = 10_000;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CampaignReport {
+    pub token: Address,
+    pub raised: u128,
+    pub held: u128, // Campaign's balance of `token`
+    pub state: ProjectState,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlatformStats {
+    pub tvl: Map<Address, u128>,    // Token -> held by the campaigns
+    pub raised: Map<Address, u128>, // Token -> raised by the campaigns all-time
+    pub campaigns: u32,
+    pub active: u32,           // Not yet in a final state
+    pub succeeded: u32,        // Goal met
+    pub failed: u32,           // Failed or cancelled
+    pub success_rate_bps: u32, // Succeeded share of the succeeded and failed
+}
+
+fn load(env: &Env) -> PlatformStats {
+    env.storage()
+        .instance()
+        .get(&StorageKey::PlatformStats)
+        .unwrap_or(PlatformStats {
+            tvl: Map::new(env),
+            raised: Map::new(env),
+            campaigns: 0,
+            active: 0,
+            succeeded: 0,
+            failed: 0,
+            success_rate_bps: 0,
+        })
+}
+
+fn report_of(env: &Env, campaign: &Address) -> Option<CampaignReport> {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::CampaignReport(campaign.clone()))
+}
+
+fn add(totals: &mut Map<Address, u128>, token: &Address, old: u128, new: u128) {
+    let total = totals.get(token.clone()).unwrap_or(0);
+    totals.set(token.clone(), total.saturating_sub(old).saturating_add(new));
+}
+
+fn count(stats: &mut PlatformStats, state: ProjectState, counted: bool) {
+    let delta = |n: u32| if counted { n + 1 } else { n.saturating_sub(1) };
+    if !state.is_final() {
+        stats.active = delta(stats.active);
+    }
+    match state {
+        ProjectState::Succeeded | ProjectState::Active | ProjectState::Completed => {
+            stats.succeeded = delta(stats.succeeded)
+        }
+        ProjectState::Failed | ProjectState::Cancelled => stats.failed = delta(stats.failed),
+        ProjectState::Funding => {}
+    }
+}
+
+/// Replaces a campaign's last report, folding the difference into the
+/// platform totals. `None` forgets the campaign.
+fn apply(env: &Env, campaign: &Address, report: Option<CampaignReport>) {
+    let key = StorageKey::CampaignReport(campaign.clone());
+    let old = report_of(env, campaign);
+    let mut stats = load(env);
+    if let Some(old) = &old {
+        add(&mut stats.tvl, &old.token, old.held, 0);
+        add(&mut stats.raised, &old.token, old.raised, 0);
+        count(&mut stats, old.state, false);
+        stats.campaigns -= 1;
+    }
+    if let Some(new) = &report {
+        add(&mut stats.tvl, &new.token, 0, new.held);
+        add(&mut stats.raised, &new.token, 0, new.raised);
+        count(&mut stats, new.state, true);
+        stats.campaigns += 1;
+    }
+    let decided = stats.succeeded + stats.failed;
+    stats.success_rate_bps = match decided {
+        0 => 0,
+        _ => stats.succeeded * BPS_DENOMINATOR / decided,
+    };
+    env.storage()
+        .instance()
+        .set(&StorageKey::PlatformStats, &stats);
+
+    match report {
+        Some(report) => ttl::set_persistent(env, &key, &report),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+fn report_for(env: &Env, campaign: &Address, project: &Project) -> CampaignReport {
+    let held = token::Client::new(env, &project.token).balance(campaign);
+    CampaignReport {
+        token: project.token.clone(),
+        raised: project.raised,
+        held: u128::try_from(held).unwrap_or(0),
+        state: project.state,
+    }
+}
+
+/// Starts tracking a campaign the factory just deployed.
+pub(crate) fn register(env: &Env, campaign: &Address, project: &Project) {
+    apply(env, campaign, Some(report_for(env, campaign, project)));
+}
+
+/// Stops tracking a campaign the factory purged.
+pub(crate) fn forget(env: &Env, campaign: &Address) {
+    apply(env, campaign, None);
+}
+
+/// Reports this campaign to the factory that deployed it, if any. A
+/// report the factory refuses is left to `sync_campaign`.
+pub(crate) fn report(env: &Env, project: &Project) {
+    let Some(parent) = cleanup::parent(env) else {
+        return;
+    };
+    let campaign = env.current_contract_address();
+    let report = report_for(env, &campaign, project);
+    let _ = MilestoneFundClient::new(env, &parent).try_report_campaign(&campaign, &report);
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Campaign) Records a deployed campaign's latest figures in this
+    /// factory's platform totals.
+    pub fn report_campaign(
+        env: Env,
+        campaign: Address,
+        report: CampaignReport,
+    ) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        campaign.require_auth();
+        if !factory::is_factory(&env) {
+            return Err(Error::FeatureDisabled);
+        }
+        let last = report_of(&env, &campaign).ok_or(Error::NotFound)?;
+        if report.token != last.token {
+            return Err(Error::InvalidArgument);
+        }
+        apply(&env, &campaign, Some(report));
+        Ok(())
+    }
+
+    /// Reads the factory's campaign number `id` and records its latest
+    /// figures in the platform totals. Callable by anyone. Returns the
+    /// figures recorded.
+    pub fn sync_campaign(env: Env, id: u32) -> Result<CampaignReport, Error> {
+        breaker::ensure_writable(&env)?;
+        if !factory::is_factory(&env) {
+            return Err(Error::FeatureDisabled);
+        }
+        let campaign = Self::get_campaign(env.clone(), id).ok_or(Error::NotFound)?;
+        let project = MilestoneFundClient::new(&env, &campaign)
+            .try_get_project()
+            .ok()
+            .and_then(Result::ok)
+            .ok_or(Error::NotFound)?;
+        let report = report_for(&env, &campaign, &project);
+        apply(&env, &campaign, Some(report.clone()));
+
+        let topics = (symbol_short!("factory"), symbol_short!("synced"), id);
+        events::publish(&env, topics, report.clone());
+        Ok(report)
+    }
+
+    /// (View) Gets a campaign's last figures as the factory recorded them.
+    pub fn get_campaign_report(env: Env, campaign: Address) -> Option<CampaignReport> {
+        report_of(&env, &campaign)
+    }
+
+    /// (View) Gets the platform totals across this factory's campaigns.
+    pub fn get_platform_stats(env: Env) -> Result<PlatformStats, Error> {
+        if !factory::is_factory(&env) {
+            return Err(Error::FeatureDisabled);
+        }
+        Ok(load(&env))
+    }
+}
@@ -0,0 +1,65 @@
+use soroban_sdk::{Address, Env, Map, Symbol};
+
+use crate::{backers, ttl, Error, StateKey};
+
+// --- Backer Polls ---
+// The one-off backer votes outside the milestone flow (halting a stream or
+// the stipend, migrations, successions, cost revisions) keep each vote in
+// its own persistent entry, keyed by the poll, a scope and the backer, so
+// a poll costs the same to vote on however many backers have voted. The
+// scope tells apart the polls of one kind: a milestone index, or a round
+// drawn with `next_round` for each new proposal, which leaves the votes of
+// earlier proposals behind to expire. Tallies walk the backer index.
+
+/// Draws a fresh scope for a new proposal of `poll`.
+pub(crate) fn next_round(env: &Env, poll: &Symbol) -> u32 {
+    let key = StateKey::PollRound(poll.clone());
+    let round = env.storage().instance().get(&key).unwrap_or(0u32) + 1;
+    env.storage().instance().set(&key, &round);
+    round
+}
+
+/// `backer`'s vote in a poll, if they cast one.
+pub(crate) fn get(env: &Env, poll: &Symbol, scope: u32, backer: &Address) -> Option<bool> {
+    env.storage()
+        .persistent()
+        .get(&StateKey::PollVote(poll.clone(), scope, backer.clone()))
+}
+
+/// Records `backer`'s vote in a poll. A backer votes once per poll.
+pub(crate) fn cast(
+    env: &Env,
+    poll: &Symbol,
+    scope: u32,
+    backer: &Address,
+    approve: bool,
+) -> Result<(), Error> {
+    let key = StateKey::PollVote(poll.clone(), scope, backer.clone());
+    if env.storage().persistent().has(&key) {
+        return Err(Error::AlreadyVoted);
+    }
+    ttl::set_persistent(env, &key, &approve);
+    Ok(())
+}
+
+/// Every vote cast in a poll.
+pub(crate) fn all(env: &Env, poll: &Symbol, scope: u32) -> Map<Address, bool> {
+    let mut votes = Map::new(env);
+    for backer in backers::all(env).iter() {
+        if let Some(approve) = get(env, poll, scope, &backer) {
+            votes.set(backer, approve);
+        }
+    }
+    votes
+}
+
+/// Contribution weight of the backers who voted in a poll.
+pub(crate) fn weight(env: &Env, poll: &Symbol, scope: u32) -> Result<u128, Error> {
+    let mut weight: u128 = 0;
+    for (voter, _) in all(env, poll, scope).iter() {
+        weight = weight
+            .checked_add(backers::amount(env, &voter))
+            .ok_or(Error::Overflow)?;
+    }
+    Ok(weight)
+}
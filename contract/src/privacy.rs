@@ -0,0 +1,49 @@
+use soroban_sdk::{contractimpl, Address, Env, Map};
+
+use crate::{backers, claims, Error, MilestoneFund, MilestoneFundClient, Project};
+
+// --- Privacy-Tiered Views ---
+// Views answering for a single backer (contribution amounts, refund
+// entitlements, raw contribution records) take a `viewer` who must sign the
+// call: the backer concerned, the creator or the admin. Public views stick
+// to aggregates such as `get_summary` and the statistics buckets. This only
+// tiers the read API; the ledger itself stays public.
+
+/// Authenticates `viewer` and checks they may see `subject`'s data, or
+/// every backer's when `subject` is `None`.
+pub(crate) fn authorize_viewer(
+    env: &Env,
+    project: &Project,
+    viewer: &Address,
+    subject: Option<&Address>,
+) -> Result<(), Error> {
+    viewer.require_auth();
+    if subject == Some(viewer) || *viewer == project.creator {
+        return Ok(());
+    }
+    if *viewer == MilestoneFund::get_admin(env.clone())? {
+        return Ok(());
+    }
+    Err(Error::NotEligible)
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Creator or admin view) Gets every backer with a refund-pool
+    /// amount still to claim.
+    pub fn get_refund_beneficiaries(
+        env: Env,
+        viewer: Address,
+    ) -> Result<Map<Address, u128>, Error> {
+        let project = Self::get_project(&env)?;
+        authorize_viewer(&env, &project, &viewer, None)?;
+        let mut beneficiaries = Map::new(&env);
+        for backer in backers::all(&env).iter() {
+            let claimable = claims::claimable(&env, &project, &backer)?;
+            if claimable > 0 {
+                beneficiaries.set(backer, claimable);
+            }
+        }
+        Ok(beneficiaries)
+    }
+}
@@ -0,0 +1,108 @@
+use soroban_sdk::{contractimpl, Address, Env, Symbol};
+
+use crate::{
+    cleanup, cohorts, factory, ttl, DataKey, Error, MilestoneFund, MilestoneFundClient, StateKey,
+    StorageKey,
+};
+
+// --- Project IDs ---
+// One deployment serves many campaigns. A factory numbers every project it
+// creates and keeps the address of its campaign instance under
+// `DataKey::Project(id)`; each campaign runs in its own instance, so its
+// state has that instance's storage to itself. `fund`, `vote`,
+// `release_funds` and `claim_refund` take the project ID: called on the
+// factory, they forward to that project's campaign; called on a campaign,
+// the ID must be its own, the number its factory gave it, or 0 for a
+// campaign deployed on its own. Campaigns a factory deployed before IDs
+// were recorded answer to any ID, since only the factory knows theirs.
+
+/// ID of a campaign deployed on its own rather than by a factory.
+pub const STANDALONE_PROJECT_ID: u32 = 0;
+
+/// Address of project `id` on this factory, including projects registered
+/// before `DataKey::Project` existed.
+pub(crate) fn address(env: &Env, id: u32) -> Option<Address> {
+    let storage = env.storage().persistent();
+    storage
+        .get(&DataKey::Project(id))
+        .or_else(|| storage.get(&StorageKey::Campaign(id)))
+}
+
+/// Registers `campaign` as project `id` on this factory.
+pub(crate) fn register(env: &Env, id: u32, campaign: &Address) {
+    ttl::set_persistent(env, &DataKey::Project(id), campaign);
+}
+
+/// Drops project `id` from this factory's registry.
+pub(crate) fn forget(env: &Env, id: u32) {
+    let storage = env.storage().persistent();
+    storage.remove(&DataKey::Project(id));
+    storage.remove(&StorageKey::Campaign(id));
+}
+
+/// Records the ID this campaign has on the factory that deployed it.
+pub(crate) fn record_id(env: &Env, id: u32) {
+    env.storage().instance().set(&StateKey::ProjectId, &id);
+}
+
+/// The ID this campaign answers to.
+pub(crate) fn own_id(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&StateKey::ProjectId)
+        .unwrap_or(STANDALONE_PROJECT_ID)
+}
+
+/// Resolves `project_id` for a call to this instance: on a factory, the
+/// campaign to forward the call to; on a campaign, `None` once the ID is
+/// found to be its own. Fails with `NotFound` for any other ID.
+pub(crate) fn route(env: &Env, project_id: u32) -> Result<Option<Address>, Error> {
+    if factory::is_factory(env) {
+        return address(env, project_id).map(Some).ok_or(Error::NotFound);
+    }
+    let recorded = env.storage().instance().has(&StateKey::ProjectId);
+    let legacy = !recorded && cleanup::parent(env).is_some();
+    if !legacy && project_id != own_id(env) {
+        return Err(Error::NotFound);
+    }
+    Ok(None)
+}
+
+/// Brings the factory's platform figures for project `id` up to date after
+/// a forwarded call, which the campaign cannot report back itself.
+pub(crate) fn sync(env: &Env, id: u32) -> Result<(), Error> {
+    MilestoneFund::sync_campaign(env.clone(), id)?;
+    Ok(())
+}
+
+/// Forwards a contribution to project `id`'s campaign, then records it in
+/// the factory's cohorts and platform figures.
+pub(crate) fn fund(
+    env: &Env,
+    id: u32,
+    campaign: &Address,
+    backer: &Address,
+    amount: i128,
+    source: &Option<Symbol>,
+) -> Result<(), Error> {
+    let client = MilestoneFundClient::new(env, campaign);
+    let raised = client.get_project().raised;
+    let backers = client.get_backer_count();
+    client.fund(&id, backer, &amount, source);
+    let funded = client
+        .get_project()
+        .raised
+        .checked_sub(raised)
+        .ok_or(Error::Overflow)?;
+    let joined = client.get_backer_count() > backers;
+    cohorts::record(env, campaign, backer, funded, joined)?;
+    sync(env, id)
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (View) Gets the project ID this campaign answers to.
+    pub fn get_project_id(env: Env) -> u32 {
+        own_id(&env)
+    }
+}
@@ -0,0 +1,90 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Env};
+
+use crate::{
+    backers, breaker, events, ttl, votes, DataKey, Error, MilestoneFund, MilestoneFundClient,
+    Project,
+};
+
+// --- Vote Pruning ---
+// A released milestone's per-backer votes are dead weight in the project
+// entry. Keepers call `prune` to remove them in batches small enough to
+// stay within resource limits. The first call snapshots the final tally so
+// views that need it keep working once the votes are gone.
+
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct VoteTally {
+    pub approve_weight: u128,
+    pub reject_weight: u128,
+    pub voters: u32,
+}
+
+fn load_tally(env: &Env, milestone_index: u32) -> Option<VoteTally> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::VoteTally(milestone_index))
+}
+
+fn count(env: &Env, milestone_index: u32) -> VoteTally {
+    let mut tally = VoteTally::default();
+    for (backer, approve) in votes::all(env, milestone_index).iter() {
+        let weight = backers::amount(env, &backer);
+        if approve {
+            tally.approve_weight += weight;
+        } else {
+            tally.reject_weight += weight;
+        }
+        tally.voters += 1;
+    }
+    tally
+}
+
+/// The tally of a milestone, from its snapshot once pruning has started.
+pub(crate) fn tally(env: &Env, project: &Project, milestone_index: u32) -> VoteTally {
+    load_tally(env, milestone_index).unwrap_or_else(|| {
+        project
+            .milestones
+            .get(milestone_index)
+            .map(|_| count(env, milestone_index))
+            .unwrap_or_default()
+    })
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// Removes up to `batch` vote entries of a completed milestone. Anyone
+    /// can call this. Returns how many entries were removed.
+    pub fn prune(env: Env, milestone_index: u32, batch: u32) -> Result<u32, Error> {
+        breaker::ensure_writable(&env)?;
+        let project = Self::get_project(&env)?;
+        let milestone = project
+            .milestones
+            .get(milestone_index)
+            .ok_or(Error::MilestoneInvalidIndex)?;
+        if !milestone.is_complete {
+            return Err(Error::NotSettled);
+        }
+
+        if load_tally(&env, milestone_index).is_none() {
+            ttl::set_persistent(
+                &env,
+                &DataKey::VoteTally(milestone_index),
+                &count(&env, milestone_index),
+            );
+        }
+
+        let removed = votes::remove(&env, milestone_index, batch)?;
+
+        events::publish(&env, (symbol_short!("prune"), milestone_index), removed);
+        Ok(removed)
+    }
+
+    /// (View) Gets a milestone's vote tally, pruned or not.
+    pub fn get_vote_tally(env: Env, milestone_index: u32) -> Result<VoteTally, Error> {
+        let project = Self::get_project(&env)?;
+        if milestone_index >= project.milestones.len() {
+            return Err(Error::MilestoneInvalidIndex);
+        }
+        Ok(tally(&env, &project, milestone_index))
+    }
+}
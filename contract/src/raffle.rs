@@ -0,0 +1,103 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, BytesN, Env, Vec};
+
+use crate::{access, backers, events, DataKey, Error, MilestoneFund, MilestoneFundClient};
+
+// --- Perk Raffle ---
+// Once funding has closed with the goal met, the creator can draw backer
+// winners for a promised perk, each backer's odds proportional to their
+// contribution. The draw seeds the host PRNG with a fresh seed and records
+// it next to the winners, so anyone can replay the selection with
+// `verify_raffle` instead of trusting an off-chain draw.
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RaffleResult {
+    pub seed: BytesN<32>,
+    pub drawn_at: u64,      // Ledger sequence of the draw
+    pub total_weight: u128, // Sum of contributions entered into the draw
+    pub entrants: u32,
+    pub winners: Vec<Address>,
+}
+
+fn load(env: &Env) -> Option<RaffleResult> {
+    env.storage().instance().get(&DataKey::Raffle)
+}
+
+/// Draws up to `count` winners without replacement, weighted by contribution.
+fn select(env: &Env, seed: &BytesN<32>, count: u32) -> Result<Vec<Address>, Error> {
+    env.prng().seed(seed.clone().into());
+    let mut remaining = backers::contributions(env);
+    let mut total: u128 = remaining.values().iter().sum();
+    let mut winners = Vec::new(env);
+    while winners.len() < count && total > 0 {
+        let bound = u64::try_from(total).map_err(|_| Error::AmountTooLarge)?;
+        let mut point = u128::from(env.prng().gen_range::<u64>(0..bound));
+        for (backer, weight) in remaining.iter() {
+            if point < weight {
+                remaining.remove(backer.clone());
+                total -= weight;
+                winners.push_back(backer);
+                break;
+            }
+            point -= weight;
+        }
+    }
+    Ok(winners)
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// Draws `count` raffle winners once funding has closed with the goal
+    /// met. Can only be drawn once.
+    pub fn draw_raffle(env: Env, count: u32) -> Result<Vec<Address>, Error> {
+        let project = access::require_creator(&env)?;
+        if !project.goal_met {
+            return Err(Error::GoalNotMet);
+        }
+        if u64::from(env.ledger().sequence()) <= project.deadline {
+            return Err(Error::NotDue);
+        }
+        if count == 0 {
+            return Err(Error::InvalidArgument);
+        }
+        if load(&env).is_some() {
+            return Err(Error::AlreadyExists);
+        }
+
+        let seed: BytesN<32> = env.prng().gen();
+        let winners = select(&env, &seed, count)?;
+        let result = RaffleResult {
+            seed,
+            drawn_at: u64::from(env.ledger().sequence()),
+            total_weight: backers::contributions(&env).values().iter().sum(),
+            entrants: backers::count(&env),
+            winners: winners.clone(),
+        };
+        env.storage().instance().set(&DataKey::Raffle, &result);
+
+        events::publish(
+            &env,
+            (symbol_short!("raffle"), symbol_short!("drawn")),
+            winners.clone(),
+        );
+        Ok(winners)
+    }
+
+    /// (View) Gets the recorded raffle draw.
+    pub fn get_raffle(env: Env) -> Result<RaffleResult, Error> {
+        load(&env).ok_or(Error::NotFound)
+    }
+
+    /// (View) Checks whether `backer` won the raffle.
+    pub fn is_raffle_winner(env: Env, backer: Address) -> bool {
+        load(&env).is_some_and(|result| result.winners.contains(backer))
+    }
+
+    /// (View) Replays the draw from its recorded seed and checks that it
+    /// selects the recorded winners.
+    pub fn verify_raffle(env: Env) -> Result<bool, Error> {
+        let result = load(&env).ok_or(Error::NotFound)?;
+        let winners = select(&env, &result.seed, result.winners.len())?;
+        Ok(winners == result.winners)
+    }
+}
@@ -0,0 +1,162 @@
+use soroban_sdk::{contractimpl, symbol_short, Address, Env};
+
+use crate::{
+    access, auth, breaker, collect, events, pay_out, ttl, DataKey, Error, MilestoneFund,
+    MilestoneFundClient, Project, StateKey,
+};
+
+// --- Vote Participation Rebates ---
+// To counter voter apathy, a backer who votes on every milestone can claim a
+// fixed rebate once the project has settled. Rebates are paid from the fee
+// pool, so the total paid out can never exceed the fees available.
+// Participation is flagged per milestone, so voting again after a revoked
+// or cleared vote counts the milestone once, and revoking uncounts it.
+
+pub(crate) fn fee_pool(env: &Env) -> u128 {
+    env.storage().instance().get(&DataKey::FeePool).unwrap_or(0)
+}
+
+fn set_fee_pool(env: &Env, amount: u128) {
+    env.storage().instance().set(&DataKey::FeePool, &amount);
+}
+
+pub(crate) fn rebate_amount(env: &Env) -> u128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::RebateAmount)
+        .unwrap_or(0)
+}
+
+pub(crate) fn votes_cast(env: &Env, backer: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::VotesCast(backer.clone()))
+        .unwrap_or(0)
+}
+
+fn set_votes_cast(env: &Env, backer: &Address, count: u32) {
+    let key = DataKey::VotesCast(backer.clone());
+    ttl::set_persistent(env, &key, &count);
+}
+
+/// Counts milestone `milestone_index` as voted on by `backer`.
+pub(crate) fn record_participation(env: &Env, backer: &Address, milestone_index: u32) {
+    let key = StateKey::Participated(backer.clone(), milestone_index);
+    if env.storage().persistent().has(&key) {
+        return;
+    }
+    ttl::set_persistent(env, &key, &true);
+    set_votes_cast(env, backer, votes_cast(env, backer) + 1);
+}
+
+/// Uncounts milestone `milestone_index` for `backer` after they withdrew
+/// their vote on it.
+pub(crate) fn forget_participation(env: &Env, backer: &Address, milestone_index: u32) {
+    let key = StateKey::Participated(backer.clone(), milestone_index);
+    if !env.storage().persistent().has(&key) {
+        return;
+    }
+    env.storage().persistent().remove(&key);
+    set_votes_cast(env, backer, votes_cast(env, backer).saturating_sub(1));
+}
+
+pub(crate) fn rebate_claimed(env: &Env, backer: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::RebateClaimed(backer.clone()))
+}
+
+fn is_settled(project: &Project) -> bool {
+    project
+        .milestones
+        .iter()
+        .all(|milestone| milestone.is_complete)
+}
+
+fn has_full_participation(env: &Env, project: &Project, backer: &Address) -> bool {
+    votes_cast(env, backer) >= project.milestones.len()
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Admin) Sets the rebate paid to each fully participating backer.
+    /// Zero disables rebates.
+    pub fn set_vote_rebate(env: Env, amount: u128) -> Result<(), Error> {
+        access::require_admin(&env)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::RebateAmount, &amount);
+        Ok(())
+    }
+
+    /// Adds `amount` of the project token from `from` to the fee pool.
+    pub fn deposit_fee_pool(env: Env, from: Address, amount: u128) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        auth::require_auth_for_amount(&env, &from, amount);
+        let project = Self::get_project(&env)?;
+        collect(&env, &project.token, &from, amount)?;
+        let pool = fee_pool(&env).checked_add(amount).ok_or(Error::Overflow)?;
+        set_fee_pool(&env, pool);
+
+        events::publish(&env, (symbol_short!("fee_pool"), from), amount);
+        Ok(())
+    }
+
+    /// Pays `backer` the vote rebate once every milestone has been released,
+    /// provided they voted on all of them.
+    pub fn claim_vote_rebate(env: Env, backer: Address) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        backer.require_auth();
+        let project = Self::get_project(&env)?;
+
+        let amount = rebate_amount(&env);
+        if amount == 0 {
+            return Err(Error::FeatureDisabled);
+        }
+        if !is_settled(&project) {
+            return Err(Error::NotSettled);
+        }
+        if !has_full_participation(&env, &project, &backer) {
+            return Err(Error::NotEligible);
+        }
+        if rebate_claimed(&env, &backer) {
+            return Err(Error::AlreadyExists);
+        }
+        let pool = fee_pool(&env);
+        if pool < amount {
+            return Err(Error::InsufficientFunds);
+        }
+        if breaker::trip_on_divergence(&env, &project)? {
+            return Ok(());
+        }
+
+        let claimed_key = DataKey::RebateClaimed(backer.clone());
+        ttl::set_persistent(&env, &claimed_key, &true);
+        set_fee_pool(&env, pool - amount);
+        pay_out(&env, &project.token, &backer, amount)?;
+
+        events::publish(&env, (symbol_short!("rebate"), backer), amount);
+        Ok(())
+    }
+
+    /// (View) Gets the fees available to fund rebates.
+    pub fn get_fee_pool(env: Env) -> u128 {
+        fee_pool(&env)
+    }
+
+    /// (View) Gets the configured per-backer vote rebate.
+    pub fn get_vote_rebate(env: Env) -> u128 {
+        rebate_amount(&env)
+    }
+
+    /// (View) Gets how many milestones `backer` has voted on.
+    pub fn get_votes_cast(env: Env, backer: Address) -> u32 {
+        votes_cast(&env, &backer)
+    }
+
+    /// (View) Checks whether `backer` has voted on every milestone.
+    pub fn is_rebate_eligible(env: Env, backer: Address) -> Result<bool, Error> {
+        let project = Self::get_project(&env)?;
+        Ok(has_full_participation(&env, &project, &backer))
+    }
+}
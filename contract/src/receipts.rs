@@ -0,0 +1,69 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use crate::{events, reject_weights, rejection, vote_weights, Error, Project};
+
+// --- Vote Receipts ---
+// Every vote, changed vote and revoked vote publishes the milestone's
+// tally as it stands afterwards, under the same weighting, delegation and
+// inactivity rules the release and rejection checks use, so live campaign
+// pages can animate progress straight from the event stream instead of
+// simulating a read per vote.
+
+const BPS_DENOMINATOR: u128 = 10_000;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteReceipt {
+    pub approve: bool, // The vote cast, changed to or revoked
+    pub yes_weight: u128,
+    pub no_weight: u128,
+    pub abstain_weight: u128,        // Eligible weight that has not voted
+    pub approval_progress_bps: u32,  // Share of the approval threshold reached
+    pub rejection_progress_bps: u32, // Share of the rejection threshold reached
+}
+
+/// Share of `threshold_bps` of `eligible` that `weight` reaches, in basis
+/// points. Past 10 000 once the threshold is met.
+fn progress_bps(weight: u128, eligible: u128, threshold_bps: u128) -> u32 {
+    let needed = eligible.saturating_mul(threshold_bps) / BPS_DENOMINATOR;
+    if needed == 0 {
+        return 0;
+    }
+    let progress = weight.saturating_mul(BPS_DENOMINATOR) / needed;
+    u32::try_from(progress).unwrap_or(u32::MAX)
+}
+
+/// Publishes the receipt of `backer`'s vote just recorded on a milestone,
+/// under `action` (`vote`, `revote` or `unvote`).
+pub(crate) fn publish(
+    env: &Env,
+    action: Symbol,
+    project: &Project,
+    milestone_index: u32,
+    backer: &Address,
+    approve: bool,
+) -> Result<(), Error> {
+    let (yes_weight, eligible) = vote_weights(env, project, milestone_index)?;
+    let (no_weight, _) = reject_weights(env, project, milestone_index)?;
+    let turnout = yes_weight.checked_add(no_weight).ok_or(Error::Overflow)?;
+    let receipt = VoteReceipt {
+        approve,
+        yes_weight,
+        no_weight,
+        abstain_weight: eligible.saturating_sub(turnout),
+        approval_progress_bps: progress_bps(
+            yes_weight,
+            eligible,
+            u128::from(project.approval_threshold_bps),
+        ),
+        rejection_progress_bps: progress_bps(
+            no_weight,
+            eligible,
+            u128::from(rejection::threshold_bps(env)),
+        ),
+    };
+
+    let topics = (action, milestone_index, backer.clone());
+    events::publish(env, topics, receipt);
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use soroban_sdk::{contractclient, contractimpl, Address, Env};
+
+use crate::{access, Error, MilestoneFund, MilestoneFundClient, StorageKey};
+
+// --- Shared Token Registry ---
+// Instead of curating accepted assets itself, a deployment can point at an
+// external registry contract that several platform deployments share. Every
+// campaign this contract deploys (follow-on renewals, factory campaigns) is
+// only created once the registry allows its token. Without a registry every
+// token is accepted.
+
+/// Interface the curated asset registry must implement.
+#[contractclient(name = "TokenRegistryClient")]
+pub trait TokenRegistry {
+    /// Whether campaigns may raise in `token`.
+    fn is_allowed(env: Env, token: Address) -> bool;
+}
+
+fn registry(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&StorageKey::TokenRegistry)
+}
+
+/// Whether a campaign raising in `token` may be created.
+pub(crate) fn is_allowed(env: &Env, token: &Address) -> bool {
+    registry(env).is_none_or(|registry| TokenRegistryClient::new(env, &registry).is_allowed(token))
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Admin) Sets the registry deployed campaigns' tokens are checked
+    /// against, or stops checking with `None`.
+    pub fn set_token_registry(env: Env, registry: Option<Address>) -> Result<(), Error> {
+        access::require_admin(&env)?;
+        match registry {
+            Some(registry) => env
+                .storage()
+                .instance()
+                .set(&StorageKey::TokenRegistry, &registry),
+            None => env.storage().instance().remove(&StorageKey::TokenRegistry),
+        }
+        Ok(())
+    }
+
+    /// (View) Gets the token registry, if one is set.
+    pub fn get_token_registry(env: Env) -> Option<Address> {
+        registry(&env)
+    }
+}
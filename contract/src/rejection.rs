@@ -0,0 +1,113 @@
+use soroban_sdk::{contractimpl, symbol_short, Env};
+
+use crate::{
+    access, breaker, claims, events, freeze, governance, lifecycle, reject_weights, save_project,
+    stream, watch, Error, MilestoneFund, MilestoneFundClient, Project, StorageKey,
+};
+
+// --- Milestone Rejection ---
+// Backers vote against a milestone as well as for it. Once the rejecting
+// weight clears the rejection threshold the milestone can no longer be
+// released: it is marked rejected and settled, and what its escrow still
+// holds goes to the refund pool, where every backer claims their share.
+// The threshold is exclusive like the approval one, defaults to half the
+// raised weight and is fixed by the first contribution.
+
+const BPS_DENOMINATOR: u128 = 10_000;
+/// Rejecting weight a milestone needs by default, as a share of the raised
+/// amount in basis points.
+const DEFAULT_REJECTION_THRESHOLD_BPS: u32 = 5_000;
+
+/// Rejecting weight a milestone needs, in basis points of the raised amount.
+pub(crate) fn threshold_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&StorageKey::RejectionThreshold)
+        .unwrap_or(DEFAULT_REJECTION_THRESHOLD_BPS)
+}
+
+/// Whether rejecting `weight` of `eligible` clears the rejection threshold.
+pub(crate) fn clears_threshold(env: &Env, weight: u128, eligible: u128) -> Result<bool, Error> {
+    let (weight, needed) = governance::scaled(weight, eligible, threshold_bps(env))?;
+    Ok(weight > needed)
+}
+
+/// Whether the rejections on a milestone clear the rejection threshold.
+pub(crate) fn is_carried(
+    env: &Env,
+    project: &Project,
+    milestone_index: u32,
+) -> Result<bool, Error> {
+    let (reject_weight, eligible_weight) = reject_weights(env, project, milestone_index)?;
+    clears_threshold(env, reject_weight, eligible_weight)
+}
+
+/// Rejects a milestone if its rejections clear the threshold, moving its
+/// escrow to the refund pool. Returns whether it was rejected.
+pub(crate) fn reject_if_carried(env: &Env, milestone_index: u32) -> Result<bool, Error> {
+    let mut project = MilestoneFund::get_project(env)?;
+    let mut milestone = project
+        .milestones
+        .get(milestone_index)
+        .ok_or(Error::MilestoneInvalidIndex)?;
+    if milestone.is_complete
+        || stream::is_streaming(env, milestone_index)
+        || freeze::is_frozen(env, milestone_index)
+        || !is_carried(env, &project, milestone_index)?
+    {
+        return Ok(false);
+    }
+
+    let remainder = claims::refund_milestone(&mut project, &mut milestone)?;
+    milestone.is_rejected = true;
+    project.milestones.set(milestone_index, milestone);
+    lifecycle::advance(env, &mut project);
+    save_project(env, &project);
+
+    events::publish(env, (symbol_short!("reject"), milestone_index), remainder);
+    watch::publish_transition(env, symbol_short!("rejected"), milestone_index);
+    Ok(true)
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Admin) Sets the rejecting weight that rejects a milestone, in basis
+    /// points of the raised amount. Only possible before the first
+    /// contribution.
+    pub fn set_rejection_threshold(env: Env, threshold_bps: u32) -> Result<(), Error> {
+        access::require_admin(&env)?;
+        if threshold_bps == 0 || u128::from(threshold_bps) >= BPS_DENOMINATOR {
+            return Err(Error::InvalidArgument);
+        }
+        if Self::get_project(&env)?.raised > 0 {
+            return Err(Error::TermsLocked);
+        }
+        env.storage()
+            .instance()
+            .set(&StorageKey::RejectionThreshold, &threshold_bps);
+        Ok(())
+    }
+
+    /// Rejects a milestone whose rejections clear the threshold, for votes
+    /// that did not reject it themselves (bulk votes, ballots, delegation).
+    /// Callable by anyone.
+    pub fn reject_milestone(env: Env, milestone_index: u32) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        let project = Self::get_project(&env)?;
+        let milestone = project
+            .milestones
+            .get(milestone_index)
+            .ok_or(Error::MilestoneInvalidIndex)?;
+        if milestone.is_complete {
+            return Err(Error::MilestoneAlreadyCompleted);
+        }
+        if stream::is_streaming(&env, milestone_index) {
+            return Err(Error::MilestoneStreaming);
+        }
+        freeze::ensure_not_frozen(&env, milestone_index)?;
+        if !reject_if_carried(&env, milestone_index)? {
+            return Err(Error::NotApproved);
+        }
+        Ok(())
+    }
+}
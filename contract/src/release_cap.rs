@@ -0,0 +1,133 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Env};
+
+use crate::{
+    access, breaker, events, pay_out, Error, MilestoneFund, MilestoneFundClient, StorageKey,
+};
+
+// --- Release Rate Cap ---
+// The admin can cap how much reaches the creator per window of ledgers,
+// however many milestones are approved, so a compromised creator key
+// cannot drain every carried milestone at once. A payout over the window's
+// remaining room is paid up to the room and the rest queues; queued funds
+// go out first, as the next windows open, through `release_queued`.
+// Swapped settlements cannot be split and must fit the room whole.
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseCap {
+    pub window_ledgers: u64,
+    pub max_per_window: u128,
+    pub window_start: u64, // Ledger the current window opened at
+    pub released: u128,    // Paid to the creator in the current window
+    pub queued: u128,      // Released by the books, waiting for room
+}
+
+fn load(env: &Env) -> Option<ReleaseCap> {
+    let mut cap: ReleaseCap = env.storage().instance().get(&StorageKey::ReleaseCap)?;
+    let now = u64::from(env.ledger().sequence());
+    if now >= cap.window_start + cap.window_ledgers {
+        cap.window_start = now - (now - cap.window_start) % cap.window_ledgers;
+        cap.released = 0;
+    }
+    Some(cap)
+}
+
+fn save(env: &Env, cap: &ReleaseCap) {
+    env.storage().instance().set(&StorageKey::ReleaseCap, cap);
+}
+
+/// Queues a creator payout of `amount` behind anything already queued and
+/// returns how much may be paid right now.
+pub(crate) fn admit(env: &Env, amount: u128) -> u128 {
+    let Some(mut cap) = load(env) else {
+        return amount;
+    };
+    cap.queued += amount;
+    let payable = cap.queued.min(cap.max_per_window - cap.released);
+    cap.queued -= payable;
+    cap.released += payable;
+    save(env, &cap);
+    payable
+}
+
+/// Books a creator payout of `amount` that cannot be split, failing unless
+/// nothing is queued and it fits the window whole.
+pub(crate) fn take(env: &Env, amount: u128) -> Result<(), Error> {
+    let Some(mut cap) = load(env) else {
+        return Ok(());
+    };
+    if cap.queued > 0 || amount > cap.max_per_window - cap.released {
+        return Err(Error::CapExceeded);
+    }
+    cap.released += amount;
+    save(env, &cap);
+    Ok(())
+}
+
+/// Payouts waiting for room in a later window.
+pub(crate) fn queued(env: &Env) -> u128 {
+    load(env).map_or(0, |cap| cap.queued)
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Admin) Caps creator payouts at `max_per_window` per
+    /// `window_ledgers`. Anything already queued stays queued.
+    pub fn set_release_cap(
+        env: Env,
+        window_ledgers: u64,
+        max_per_window: u128,
+    ) -> Result<(), Error> {
+        access::require_admin(&env)?;
+        if window_ledgers == 0 || max_per_window == 0 {
+            return Err(Error::InvalidArgument);
+        }
+        let now = u64::from(env.ledger().sequence());
+        let cap = match load(&env) {
+            Some(cap) => ReleaseCap {
+                window_ledgers,
+                max_per_window,
+                released: cap.released.min(max_per_window),
+                ..cap
+            },
+            None => ReleaseCap {
+                window_ledgers,
+                max_per_window,
+                window_start: now,
+                released: 0,
+                queued: 0,
+            },
+        };
+        save(&env, &cap);
+        Ok(())
+    }
+
+    /// Pays the creator as much of the queued payouts as the current
+    /// window has room for. Callable by anyone. Returns the amount paid.
+    pub fn release_queued(env: Env) -> Result<u128, Error> {
+        breaker::ensure_writable(&env)?;
+        let project = Self::get_project(&env)?;
+        if queued(&env) == 0 {
+            return Err(Error::NotFound);
+        }
+        if breaker::trip_on_divergence(&env, &project)? {
+            return Ok(0);
+        }
+        let paid = admit(&env, 0);
+        if paid > 0 {
+            pay_out(&env, &project.token, &project.creator, paid)?;
+        }
+
+        events::publish(
+            &env,
+            (symbol_short!("release"), symbol_short!("queued")),
+            paid,
+        );
+        Ok(paid)
+    }
+
+    /// (View) Gets the release cap and the current window, if capped.
+    pub fn get_release_cap(env: Env) -> Option<ReleaseCap> {
+        load(&env)
+    }
+}
@@ -0,0 +1,149 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env};
+
+use crate::{
+    backers, breaker, claims, events, lifecycle, pay_out, privacy, save_project, stream, ttl,
+    Error, MilestoneFund, MilestoneFundClient, Project, StateKey,
+};
+
+// --- Remainder Refunds ---
+// A rejected or expired milestone only hands back its own escrow; the
+// milestones after it still hold funds for work the campaign will most
+// likely never deliver. Once any milestone has been rejected or has failed,
+// backers can call `claim_remainder_refund`: the first call winds the
+// project down, settling every open milestone that is not streaming and
+// moving what their escrows and the surplus hold, everything never
+// released, into a remainder pool. Each backer then claims their pro-rata
+// share of that pool once. The pool is kept apart from the refund pool so
+// its payouts can be audited on their own.
+
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RemainderRefund {
+    pub pool: u128,    // Unreleased funds set aside when the project wound down
+    pub claimed: u128, // Part of the pool already paid out
+    pub opened_at: u64,
+}
+
+pub(crate) fn load(env: &Env) -> Option<RemainderRefund> {
+    env.storage().instance().get(&StateKey::RemainderRefund)
+}
+
+fn has_claimed(env: &Env, backer: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&StateKey::RemainderClaimed(backer.clone()))
+}
+
+/// Part of the remainder pool still owed to backers.
+pub(crate) fn outstanding(env: &Env) -> u128 {
+    load(env).map_or(0, |remainder| remainder.pool - remainder.claimed)
+}
+
+fn has_failed(project: &Project) -> bool {
+    project
+        .milestones
+        .iter()
+        .any(|milestone| milestone.is_rejected || milestone.is_failed)
+}
+
+/// Winds the project down: settles every open milestone that is not
+/// streaming and moves their escrow and the surplus into the remainder
+/// pool. The caller stores the project.
+fn open(env: &Env, project: &mut Project) -> Result<RemainderRefund, Error> {
+    let mut pool = project.surplus;
+    project.surplus = 0;
+    for index in 0..project.milestones.len() {
+        let mut milestone = project.milestones.get_unchecked(index);
+        if milestone.is_complete || stream::is_streaming(env, index) {
+            continue;
+        }
+        let balance = milestone.escrow.balance()?;
+        milestone.escrow.refunded = milestone
+            .escrow
+            .refunded
+            .checked_add(balance)
+            .ok_or(Error::Overflow)?;
+        milestone.is_complete = true;
+        project.milestones.set(index, milestone);
+        pool = pool.checked_add(balance).ok_or(Error::Overflow)?;
+    }
+    lifecycle::advance(env, project);
+
+    events::publish(env, (symbol_short!("remainder"),), pool);
+    Ok(RemainderRefund {
+        pool,
+        claimed: 0,
+        opened_at: u64::from(env.ledger().sequence()),
+    })
+}
+
+fn share(
+    project: &Project,
+    remainder: &RemainderRefund,
+    contribution: u128,
+) -> Result<u128, Error> {
+    claims::pro_rata(contribution, remainder.pool, project.raised)
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// Pays `backer` their pro-rata share of the funds never released,
+    /// once a milestone has been rejected or has failed. The first claim
+    /// winds the project down. Each backer claims once.
+    pub fn claim_remainder_refund(env: Env, backer: Address) -> Result<u128, Error> {
+        breaker::ensure_writable(&env)?;
+        backer.require_auth();
+        let mut project = Self::get_project(&env)?;
+        if !project.goal_met || !has_failed(&project) {
+            return Err(Error::RefundsNotAvailable);
+        }
+        if has_claimed(&env, &backer) {
+            return Err(Error::AlreadyExists);
+        }
+        if breaker::trip_on_divergence(&env, &project)? {
+            return Ok(0);
+        }
+
+        let mut remainder = match load(&env) {
+            Some(remainder) => remainder,
+            None => open(&env, &mut project)?,
+        };
+        let amount = share(&project, &remainder, backers::amount(&env, &backer))?;
+        if amount == 0 {
+            return Err(Error::NoRefundsToClaim);
+        }
+
+        remainder.claimed += amount;
+        ttl::set_persistent(&env, &StateKey::RemainderClaimed(backer.clone()), &amount);
+        env.storage()
+            .instance()
+            .set(&StateKey::RemainderRefund, &remainder);
+        save_project(&env, &project);
+
+        pay_out(&env, &project.token, &backer, amount)?;
+
+        events::publish(&env, (symbol_short!("remainder"), backer), amount);
+        Ok(amount)
+    }
+
+    /// (View) Gets the remainder pool, once the project has wound down.
+    pub fn get_remainder_refund(env: Env) -> Option<RemainderRefund> {
+        load(&env)
+    }
+
+    /// (Authenticated view) Gets what `backer` has claimed from the
+    /// remainder pool, or `None` before they claim. `viewer` must be the
+    /// backer, the creator or the admin.
+    pub fn get_remainder_claimed(
+        env: Env,
+        viewer: Address,
+        backer: Address,
+    ) -> Result<Option<u128>, Error> {
+        let project = Self::get_project(&env)?;
+        privacy::authorize_viewer(&env, &project, &viewer, Some(&backer))?;
+        Ok(env
+            .storage()
+            .persistent()
+            .get(&StateKey::RemainderClaimed(backer)))
+    }
+}
@@ -0,0 +1,198 @@
+use soroban_sdk::{
+    contractimpl, contracttype, symbol_short, xdr::ToXdr, Address, BytesN, Env, String, Vec,
+};
+
+use crate::{
+    access, backers, cancellation, events, registry, DataKey, Error, MilestoneFund,
+    MilestoneFundClient, Project,
+};
+
+// --- Follow-On Rounds ---
+// Recurring programs (seasonal grants, say) can opt into renewal: once every
+// milestone has been completed, the contract deploys a follow-on campaign
+// from `wasm_hash` with the same admin, creator and token, a goal scaled
+// from this one and milestones split by the template's shares, then tells
+// every prior backer about it through events. A cancelled project does not
+// renew, and neither does one whose token the shared registry no longer
+// allows. The scaled terms are checked when the template is set and again
+// before deploying; a renewal that still fails is skipped with a
+// `("renewal", "skipped")` event carrying the error, so it can never hold
+// up the final release.
+
+const BPS_DENOMINATOR: u128 = 10_000;
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RenewalTemplate {
+    pub wasm_hash: BytesN<32>,          // Code the follow-on is deployed from
+    pub goal_bps: u32,                  // Follow-on goal as a share of this goal
+    pub funding_ledgers: u64,           // Follow-on funding window
+    pub milestones: Vec<(String, u32)>, // Titles and shares of the goal, in bps
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Renewal {
+    pub template: RenewalTemplate,
+    pub follow_on: Option<Address>, // Set once deployed
+}
+
+fn load(env: &Env) -> Option<Renewal> {
+    env.storage().instance().get(&DataKey::Renewal)
+}
+
+/// The follow-on goal, `goal` scaled by the template. Fails with
+/// `GoalMustBePositive` if it rounds down to nothing.
+fn scaled_goal(template: &RenewalTemplate, goal: u128) -> Result<u128, Error> {
+    let scaled = goal
+        .checked_mul(u128::from(template.goal_bps))
+        .ok_or(Error::Overflow)?
+        / BPS_DENOMINATOR;
+    if scaled == 0 {
+        return Err(Error::GoalMustBePositive);
+    }
+    Ok(scaled)
+}
+
+/// Splits `goal` by the template's shares, the last milestone taking the
+/// rounding remainder.
+fn scaled_milestones(
+    env: &Env,
+    template: &RenewalTemplate,
+    goal: u128,
+) -> Result<Vec<(String, u128)>, Error> {
+    let mut milestones = Vec::new(env);
+    let mut assigned: u128 = 0;
+    let last = template.milestones.len() - 1;
+    for (index, (title, share_bps)) in template.milestones.iter().enumerate() {
+        let amount = if index as u32 == last {
+            goal.checked_sub(assigned).ok_or(Error::Overflow)?
+        } else {
+            goal.checked_mul(u128::from(share_bps))
+                .ok_or(Error::Overflow)?
+                / BPS_DENOMINATOR
+        };
+        assigned = assigned.checked_add(amount).ok_or(Error::Overflow)?;
+        milestones.push_back((title, amount));
+    }
+    Ok(milestones)
+}
+
+/// The follow-on's goal and milestones.
+fn scaled_terms(
+    env: &Env,
+    template: &RenewalTemplate,
+    goal: u128,
+) -> Result<(u128, Vec<(String, u128)>), Error> {
+    let goal = scaled_goal(template, goal)?;
+    Ok((goal, scaled_milestones(env, template, goal)?))
+}
+
+fn skip(env: &Env, error: Error) {
+    let topics = (symbol_short!("renewal"), symbol_short!("skipped"));
+    events::publish(env, topics, error as u32);
+}
+
+/// Deploys the follow-on campaign if renewal is configured and has not run
+/// yet. Called once every milestone of `project` is complete.
+pub(crate) fn renew(env: &Env, project: &Project) {
+    let Some(mut renewal) = load(env) else {
+        return;
+    };
+    if renewal.follow_on.is_some() || cancellation::is_cancelled(env) {
+        return;
+    }
+    if !registry::is_allowed(env, &project.token) {
+        return;
+    }
+    let Ok(admin) = MilestoneFund::get_admin(env.clone()) else {
+        return;
+    };
+
+    let (goal, milestones) = match scaled_terms(env, &renewal.template, project.goal) {
+        Ok(terms) => terms,
+        Err(error) => return skip(env, error),
+    };
+    let deadline =
+        match u64::from(env.ledger().sequence()).checked_add(renewal.template.funding_ledgers) {
+            Some(deadline) => deadline,
+            None => return skip(env, Error::Overflow),
+        };
+    let salt = env
+        .crypto()
+        .sha256(&env.current_contract_address().to_xdr(env));
+    let follow_on = env
+        .deployer()
+        .with_current_contract(salt.to_bytes())
+        .deploy(renewal.template.wasm_hash.clone());
+    let initialized = MilestoneFundClient::new(env, &follow_on).try_initialize(
+        &admin,
+        &project.creator,
+        &project.token,
+        &goal,
+        &deadline,
+        &milestones,
+        &project.approval_threshold_bps,
+        &project.quorum_bps,
+    );
+    match initialized {
+        Ok(Ok(())) => {}
+        Err(Ok(error)) => return skip(env, error),
+        _ => return skip(env, Error::InvalidArgument),
+    }
+    renewal.follow_on = Some(follow_on.clone());
+    env.storage().instance().set(&DataKey::Renewal, &renewal);
+
+    events::publish(env, (symbol_short!("renewal"),), (follow_on.clone(), goal));
+    for backer in backers::all(env).iter() {
+        events::publish(env, (symbol_short!("renewal"), backer), follow_on.clone());
+    }
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Creator) Opts into a follow-on round deployed from `template` once
+    /// every milestone is complete. Only possible while the campaign is
+    /// still raising.
+    pub fn set_renewal(env: Env, template: RenewalTemplate) -> Result<(), Error> {
+        let project = access::require_creator(&env)?;
+        if project.goal_met {
+            return Err(Error::TermsLocked);
+        }
+        if template.milestones.is_empty() {
+            return Err(Error::MilestoneListEmpty);
+        }
+        let shares: u128 = template
+            .milestones
+            .iter()
+            .map(|(_, share_bps)| u128::from(share_bps))
+            .sum();
+        if shares != BPS_DENOMINATOR || template.goal_bps == 0 || template.funding_ledgers == 0 {
+            return Err(Error::InvalidArgument);
+        }
+        scaled_terms(&env, &template, project.goal)?;
+
+        let renewal = Renewal {
+            template,
+            follow_on: None,
+        };
+        env.storage().instance().set(&DataKey::Renewal, &renewal);
+        Ok(())
+    }
+
+    /// (Creator) Opts back out of renewal before it has run.
+    pub fn cancel_renewal(env: Env) -> Result<(), Error> {
+        access::require_creator(&env)?;
+        let renewal = load(&env).ok_or(Error::NotFound)?;
+        if renewal.follow_on.is_some() {
+            return Err(Error::AlreadyExists);
+        }
+        env.storage().instance().remove(&DataKey::Renewal);
+        Ok(())
+    }
+
+    /// (View) Gets the renewal configuration and the follow-on, if deployed.
+    pub fn get_renewal(env: Env) -> Result<Renewal, Error> {
+        load(&env).ok_or(Error::NotFound)
+    }
+}
@@ -0,0 +1,230 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env, String, Vec};
+
+use crate::{
+    access, backers, breaker, claims, events, lifecycle, pay_out, rewards, save_project, ttl,
+    watch, DataKey, Error, MilestoneFund, MilestoneFundClient,
+};
+
+// --- Retroactive Funding Round ---
+// In a retro round the raise happens first into one pooled pot and the
+// milestones are not released one by one. Anyone can submit a claim for
+// work already completed until the funding deadline; for `voting_ledgers`
+// after it each backer puts their whole contribution weight behind one
+// claim. Settling the round pays the pot out pro rata to the weight each
+// claim received. Rounding dust, or the whole pot if nobody voted, goes to
+// the backers' refund pool.
+
+/// Upper bound on submitted claims so settlement fits in one transaction.
+const MAX_WORK_CLAIMS: u32 = 20;
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct WorkClaim {
+    pub claimant: Address, // Receives the claim's share of the pot
+    pub description: String,
+    pub weight: u128, // Contribution weight of the backers who voted for it
+    pub paid: u128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetroRound {
+    pub voting_ledgers: u64, // Length of the voting window after the deadline
+    pub claims: Vec<WorkClaim>,
+    pub settled: bool,
+}
+
+fn load(env: &Env) -> Option<RetroRound> {
+    env.storage().instance().get(&DataKey::RetroRound)
+}
+
+fn save(env: &Env, round: &RetroRound) {
+    env.storage().instance().set(&DataKey::RetroRound, round);
+}
+
+/// Length of the retro voting window, if the project runs as a retro round.
+pub(crate) fn voting_ledgers(env: &Env) -> Option<u64> {
+    load(env).map(|round| round.voting_ledgers)
+}
+
+/// Whether the project runs as a retro round instead of releasing milestones.
+pub(crate) fn is_enabled(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::RetroRound)
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Creator) Turns the project into a retro round with a voting window
+    /// of `voting_ledgers` after the deadline. Only possible before the
+    /// first contribution.
+    pub fn enable_retro_round(env: Env, voting_ledgers: u64) -> Result<(), Error> {
+        let project = access::require_creator(&env)?;
+        if project.raised > 0 {
+            return Err(Error::TermsLocked);
+        }
+        if voting_ledgers == 0 {
+            return Err(Error::InvalidArgument);
+        }
+
+        let round = RetroRound {
+            voting_ledgers,
+            claims: Vec::new(&env),
+            settled: false,
+        };
+        save(&env, &round);
+
+        events::publish(
+            &env,
+            (symbol_short!("retro"), symbol_short!("enabled")),
+            voting_ledgers,
+        );
+        Ok(())
+    }
+
+    /// Submits a claim for completed work, paid to `claimant` if backers
+    /// vote for it. Returns the claim's index.
+    pub fn submit_work_claim(
+        env: Env,
+        claimant: Address,
+        description: String,
+    ) -> Result<u32, Error> {
+        breaker::ensure_writable(&env)?;
+        claimant.require_auth();
+        let project = Self::get_project(&env)?;
+        let mut round = load(&env).ok_or(Error::FeatureDisabled)?;
+        if u64::from(env.ledger().sequence()) > project.deadline {
+            return Err(Error::DeadlinePassed);
+        }
+        if round.claims.len() >= MAX_WORK_CLAIMS {
+            return Err(Error::CapExceeded);
+        }
+
+        let claim_index = round.claims.len();
+        round.claims.push_back(WorkClaim {
+            claimant: claimant.clone(),
+            description,
+            weight: 0,
+            paid: 0,
+        });
+        save(&env, &round);
+
+        events::publish(
+            &env,
+            (symbol_short!("retro"), symbol_short!("claim")),
+            (claim_index, claimant),
+        );
+        Ok(claim_index)
+    }
+
+    /// Puts `backer`'s contribution weight behind the claim at `claim_index`.
+    pub fn vote_work_claim(env: Env, backer: Address, claim_index: u32) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        backer.require_auth();
+        let project = Self::get_project(&env)?;
+        let mut round = load(&env).ok_or(Error::FeatureDisabled)?;
+
+        if !project.goal_met {
+            return Err(Error::GoalNotMet);
+        }
+        let now = u64::from(env.ledger().sequence());
+        if now <= project.deadline {
+            return Err(Error::NotDue);
+        }
+        if now > project.deadline + round.voting_ledgers {
+            return Err(Error::DeadlinePassed);
+        }
+        let weight = backers::contribution(&env, &backer).ok_or(Error::NotABacker)?;
+        let key = DataKey::RetroVote(backer.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(Error::AlreadyVoted);
+        }
+        let mut claim = round.claims.get(claim_index).ok_or(Error::NotFound)?;
+
+        claim.weight += weight;
+        round.claims.set(claim_index, claim);
+        save(&env, &round);
+        ttl::set_persistent(&env, &key, &claim_index);
+
+        events::publish(
+            &env,
+            (symbol_short!("retro"), symbol_short!("vote")),
+            (backer, claim_index),
+        );
+        Ok(())
+    }
+
+    /// Pays out the pot across the claims once the voting window has
+    /// closed. Anyone can call this.
+    pub fn settle_retro_round(env: Env) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        let mut project = Self::get_project(&env)?;
+        let mut round = load(&env).ok_or(Error::FeatureDisabled)?;
+
+        if round.settled {
+            return Err(Error::AlreadyExists);
+        }
+        if !project.goal_met {
+            return Err(Error::GoalNotMet);
+        }
+        if u64::from(env.ledger().sequence()) <= project.deadline + round.voting_ledgers {
+            return Err(Error::NotDue);
+        }
+        if breaker::trip_on_divergence(&env, &project)? {
+            return Ok(());
+        }
+
+        // The pot is everything still held for the milestones plus surplus
+        let mut pot = project.surplus;
+        project.surplus = 0;
+        for index in 0..project.milestones.len() {
+            let mut milestone = project.milestones.get_unchecked(index);
+            let balance = milestone.escrow.balance()?;
+            milestone.escrow.released = milestone
+                .escrow
+                .released
+                .checked_add(balance)
+                .ok_or(Error::Overflow)?;
+            milestone.is_complete = true;
+            project.milestones.set(index, milestone);
+            pot = pot.checked_add(balance).ok_or(Error::Overflow)?;
+        }
+
+        let mut total_weight: u128 = 0;
+        for claim in round.claims.iter() {
+            total_weight += claim.weight;
+        }
+        let mut paid: u128 = 0;
+        for index in 0..round.claims.len() {
+            let mut claim = round.claims.get_unchecked(index);
+            claim.paid = (pot * claim.weight).checked_div(total_weight).unwrap_or(0);
+            paid += claim.paid;
+            round.claims.set(index, claim);
+        }
+        claims::credit_refund_pool(&mut project, pot - paid);
+
+        round.settled = true;
+        save(&env, &round);
+        lifecycle::advance(&env, &mut project);
+        save_project(&env, &project);
+        rewards::note_completion(&env, &project);
+
+        for claim in round.claims.iter() {
+            if claim.paid > 0 {
+                pay_out(&env, &project.token, &claim.claimant, claim.paid)?;
+            }
+        }
+
+        events::publish(
+            &env,
+            (symbol_short!("retro"), symbol_short!("settled")),
+            (pot, paid),
+        );
+        watch::publish_transition(&env, symbol_short!("settled"), pot);
+        Ok(())
+    }
+
+    /// (View) Gets the retro round, if the project runs as one.
+    pub fn get_retro_round(env: Env) -> Option<RetroRound> {
+        load(&env)
+    }
+}
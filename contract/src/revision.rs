@@ -0,0 +1,162 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env};
+
+use crate::{
+    access, backers, breaker, events, governance, polls, save_project, stream, tally_votes, votes,
+    Error, MilestoneFund, MilestoneFundClient, Project, StorageKey,
+};
+
+// --- Milestone Cost Revisions ---
+// Real-world costs drift after a campaign is funded. The creator can
+// propose a new amount for a milestone that has not been released; backers
+// ratify it with the same weighted vote that releases milestones. A raise
+// is drawn from the surplus into the milestone's escrow and a cut hands the
+// difference back to the surplus. Approvals already cast on the milestone
+// were given for the old amount, so a ratified revision clears them.
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CostRevision {
+    pub milestone_index: u32,
+    pub new_amount: u128,
+    pub round: u32, // Scope of the revision's poll
+    pub proposed_at: u64,
+}
+
+fn load(env: &Env) -> Option<CostRevision> {
+    env.storage().instance().get(&StorageKey::CostRevision)
+}
+
+/// Checks `new_amount` can replace milestone `index`'s current amount.
+fn check_revisable(
+    env: &Env,
+    project: &Project,
+    index: u32,
+    new_amount: u128,
+) -> Result<(), Error> {
+    let milestone = project
+        .milestones
+        .get(index)
+        .ok_or(Error::MilestoneInvalidIndex)?;
+    if milestone.is_complete {
+        return Err(Error::MilestoneAlreadyCompleted);
+    }
+    if stream::is_streaming(env, index) {
+        return Err(Error::MilestoneStreaming);
+    }
+    let current = milestone.amount_to_release;
+    if new_amount == 0 || new_amount == current {
+        return Err(Error::InvalidArgument);
+    }
+    if new_amount > current && new_amount - current > project.surplus {
+        return Err(Error::InsufficientFunds);
+    }
+    if new_amount < current && current - new_amount > milestone.escrow.balance()? {
+        return Err(Error::InsufficientFunds);
+    }
+    Ok(())
+}
+
+/// Moves milestone `index` to `new_amount`, balancing its escrow against
+/// the surplus.
+fn apply(env: &Env, project: &mut Project, index: u32, new_amount: u128) -> Result<(), Error> {
+    let mut milestone = project.milestones.get_unchecked(index);
+    let current = milestone.amount_to_release;
+    if new_amount > current {
+        let raise = new_amount - current;
+        project.surplus -= raise;
+        milestone.escrow.allocated = milestone
+            .escrow
+            .allocated
+            .checked_add(raise)
+            .ok_or(Error::Overflow)?;
+    } else {
+        let cut = current - new_amount;
+        milestone.escrow.allocated -= cut;
+        project.surplus = project.surplus.checked_add(cut).ok_or(Error::Overflow)?;
+    }
+    milestone.amount_to_release = new_amount;
+    project.milestones.set(index, milestone);
+    votes::remove(env, index, u32::MAX)?;
+    Ok(())
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Creator) Proposes `new_amount` for an unreleased milestone. Only one
+    /// revision can be pending at a time.
+    pub fn propose_cost_revision(
+        env: Env,
+        milestone_index: u32,
+        new_amount: u128,
+    ) -> Result<(), Error> {
+        let project = access::require_creator(&env)?;
+        if !project.goal_met {
+            return Err(Error::GoalNotMet);
+        }
+        if load(&env).is_some() {
+            return Err(Error::AlreadyExists);
+        }
+        check_revisable(&env, &project, milestone_index, new_amount)?;
+
+        let revision = CostRevision {
+            milestone_index,
+            new_amount,
+            round: polls::next_round(&env, &symbol_short!("revision")),
+            proposed_at: u64::from(env.ledger().sequence()),
+        };
+        env.storage()
+            .instance()
+            .set(&StorageKey::CostRevision, &revision);
+
+        let topics = (symbol_short!("revision"), milestone_index);
+        events::publish(&env, topics, new_amount);
+        Ok(())
+    }
+
+    /// Votes on the pending cost revision. The revision is applied as soon
+    /// as approvals clear the milestone approval threshold. Returns whether
+    /// it was applied.
+    pub fn vote_cost_revision(env: Env, backer: Address, approve: bool) -> Result<bool, Error> {
+        breaker::ensure_writable(&env)?;
+        backer.require_auth();
+        let mut project = Self::get_project(&env)?;
+        let revision = load(&env).ok_or(Error::NotFound)?;
+        if !backers::is_backer(&env, &backer) {
+            return Err(Error::NotABacker);
+        }
+        let poll = symbol_short!("revision");
+        polls::cast(&env, &poll, revision.round, &backer, approve)?;
+
+        let votes = polls::all(&env, &poll, revision.round);
+        let (yes, eligible) = tally_votes(&env, &project, &votes)?;
+        if !governance::clears_threshold(&project, yes, eligible)? {
+            return Ok(false);
+        }
+
+        // The milestone may have moved on since the proposal
+        let index = revision.milestone_index;
+        check_revisable(&env, &project, index, revision.new_amount)?;
+        apply(&env, &mut project, index, revision.new_amount)?;
+        save_project(&env, &project);
+        env.storage().instance().remove(&StorageKey::CostRevision);
+
+        let topics = (symbol_short!("revision"), symbol_short!("ratified"), index);
+        events::publish(&env, topics, revision.new_amount);
+        Ok(true)
+    }
+
+    /// (Creator) Withdraws the pending cost revision.
+    pub fn withdraw_cost_revision(env: Env) -> Result<(), Error> {
+        access::require_creator(&env)?;
+        if load(&env).is_none() {
+            return Err(Error::NotFound);
+        }
+        env.storage().instance().remove(&StorageKey::CostRevision);
+        Ok(())
+    }
+
+    /// (View) Gets the pending cost revision, if any.
+    pub fn get_cost_revision(env: Env) -> Option<CostRevision> {
+        load(&env)
+    }
+}
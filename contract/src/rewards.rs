@@ -0,0 +1,180 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env};
+
+use crate::{
+    access, auth, backers, breaker, claims, collect, events, pay_out, renewal, ttl, DataKey, Error,
+    MilestoneFund, MilestoneFundClient, Project,
+};
+
+// --- Reward Token Escrow ---
+// The creator can escrow a reward asset (typically a token they issued)
+// for backers, shared pro rata to contributions. To prevent an immediate
+// dump, each backer's share vests linearly over `vesting_ledgers` starting
+// at the ledger the last milestone was completed; `claim_rewards` pays out
+// whatever has vested and not been claimed yet.
+
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RewardEscrow {
+    pub token: Option<Address>, // Fixed by the first deposit
+    pub deposited: u128,
+    pub claimed: u128,
+    pub vesting_ledgers: u64,
+    pub completed_at: Option<u64>, // Ledger the project completed, vesting starts here
+}
+
+fn load(env: &Env) -> RewardEscrow {
+    env.storage()
+        .instance()
+        .get(&DataKey::Rewards)
+        .unwrap_or_default()
+}
+
+fn save(env: &Env, escrow: &RewardEscrow) {
+    env.storage().instance().set(&DataKey::Rewards, escrow);
+}
+
+fn claimed(env: &Env, backer: &Address) -> u128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RewardClaimed(backer.clone()))
+        .unwrap_or(0)
+}
+
+/// Starts the vesting clock once every milestone of `project` is complete,
+/// and deploys the follow-on round if the project renews.
+pub(crate) fn note_completion(env: &Env, project: &Project) {
+    let mut escrow = load(env);
+    if escrow.completed_at.is_some() || !project.milestones.iter().all(|m| m.is_complete) {
+        return;
+    }
+    escrow.completed_at = Some(u64::from(env.ledger().sequence()));
+    save(env, &escrow);
+    renewal::renew(env, project);
+}
+
+/// The reward asset and `backer`'s unclaimed pro-rata share of the escrow,
+/// vested or not, if anything was deposited.
+pub(crate) fn share(env: &Env, project: &Project, backer: &Address) -> Option<(Address, u128)> {
+    let escrow = load(env);
+    let token = escrow.token?;
+    if project.raised == 0 {
+        return None;
+    }
+    let entitlement = backers::amount(env, backer) * escrow.deposited / project.raised;
+    Some((token, entitlement.saturating_sub(claimed(env, backer))))
+}
+
+/// Books `amount` of the reward escrow as paid to `backer`.
+pub(crate) fn book_claim(env: &Env, backer: &Address, amount: u128) {
+    ttl::set_persistent(
+        env,
+        &DataKey::RewardClaimed(backer.clone()),
+        &(claimed(env, backer) + amount),
+    );
+    let mut escrow = load(env);
+    escrow.claimed += amount;
+    save(env, &escrow);
+}
+
+/// Reward tokens `backer` could claim right now.
+fn claimable(
+    env: &Env,
+    project: &Project,
+    escrow: &RewardEscrow,
+    backer: &Address,
+) -> Result<u128, Error> {
+    let Some(completed_at) = escrow.completed_at else {
+        return Ok(0);
+    };
+    let contribution = backers::amount(env, backer);
+    let entitlement = claims::pro_rata(contribution, escrow.deposited, project.raised)?;
+
+    let elapsed = u64::from(env.ledger().sequence()).saturating_sub(completed_at);
+    let vested = if elapsed >= escrow.vesting_ledgers {
+        entitlement
+    } else {
+        entitlement
+            .checked_mul(u128::from(elapsed))
+            .ok_or(Error::Overflow)?
+            / u128::from(escrow.vesting_ledgers)
+    };
+    Ok(vested.saturating_sub(claimed(env, backer)))
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Creator) Escrows `amount` of `reward_token` for backers. Every
+    /// deposit must use the asset of the first one.
+    pub fn deposit_rewards(env: Env, reward_token: Address, amount: u128) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        let project = Self::get_project(&env)?;
+        auth::require_auth_for_amount(&env, &project.creator, amount);
+        let mut escrow = load(&env);
+        if amount == 0
+            || escrow
+                .token
+                .as_ref()
+                .is_some_and(|token| *token != reward_token)
+        {
+            return Err(Error::InvalidArgument);
+        }
+
+        collect(&env, &reward_token, &project.creator, amount)?;
+        escrow.token = Some(reward_token);
+        escrow.deposited += amount;
+        save(&env, &escrow);
+
+        events::publish(
+            &env,
+            (symbol_short!("reward"), symbol_short!("deposit")),
+            amount,
+        );
+        Ok(())
+    }
+
+    /// (Creator) Sets how many ledgers reward claims vest over. Can only be
+    /// changed while the campaign is still raising.
+    pub fn set_reward_vesting(env: Env, vesting_ledgers: u64) -> Result<(), Error> {
+        let project = access::require_creator(&env)?;
+        if project.goal_met {
+            return Err(Error::TermsLocked);
+        }
+        let mut escrow = load(&env);
+        escrow.vesting_ledgers = vesting_ledgers;
+        save(&env, &escrow);
+        Ok(())
+    }
+
+    /// Pays `backer` the vested, unclaimed part of their reward share.
+    pub fn claim_rewards(env: Env, backer: Address) -> Result<u128, Error> {
+        breaker::ensure_writable(&env)?;
+        backer.require_auth();
+        let project = Self::get_project(&env)?;
+        let escrow = load(&env);
+        let token = escrow.token.clone().ok_or(Error::NotFound)?;
+        if escrow.completed_at.is_none() {
+            return Err(Error::NotSettled);
+        }
+        let amount = claimable(&env, &project, &escrow, &backer)?;
+        if amount == 0 {
+            return Err(Error::NotEligible);
+        }
+
+        book_claim(&env, &backer, amount);
+        pay_out(&env, &token, &backer, amount)?;
+
+        events::publish(&env, (symbol_short!("reward"), backer), amount);
+        Ok(amount)
+    }
+
+    /// (View) Gets the reward escrow and its vesting terms.
+    pub fn get_reward_escrow(env: Env) -> RewardEscrow {
+        load(&env)
+    }
+
+    /// (View) Gets the reward tokens `backer` could claim right now.
+    pub fn get_claimable_rewards(env: Env, backer: Address) -> Result<u128, Error> {
+        let project = Self::get_project(&env)?;
+        claimable(&env, &project, &load(&env), &backer)
+    }
+}
@@ -0,0 +1,109 @@
+use soroban_sdk::{contractimpl, contracttype, Address, Env, String, Vec};
+
+use crate::{evidence, governance, DataKey, Error, MilestoneFund, MilestoneFundClient};
+
+// --- Monthly Budget Schedules ---
+// Grant committees budget per month rather than per deliverable. A
+// scheduled project is initialized from a list of monthly budgets: each
+// month becomes a milestone, the goal is their sum, and month `i` is due
+// (as its evidence deadline) `i + 1` months after funding closes. The
+// burn-down view compares what the plan has scheduled so far with what has
+// actually been released.
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BudgetSchedule {
+    pub start: u64,         // Ledger the first month starts at (the funding deadline)
+    pub month_ledgers: u64, // Length of a month in ledgers (~518_400 at 5s per ledger)
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BurnDown {
+    pub months_elapsed: u32,
+    pub planned: u128,   // Budget of the months elapsed so far
+    pub released: u128,  // Actually paid out so far
+    pub remaining: u128, // Goal not yet released
+}
+
+fn load(env: &Env) -> Option<BudgetSchedule> {
+    env.storage().instance().get(&DataKey::BudgetSchedule)
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// Initializes a project whose goal is the sum of `months`, a list of
+    /// monthly budget titles and amounts, each month a milestone due
+    /// `month_ledgers` after the previous one. Can only be called once.
+    pub fn initialize_scheduled(
+        env: Env,
+        admin: Address,
+        creator: Address,
+        token: Address,
+        deadline: u64,
+        months: Vec<(String, u128)>,
+        month_ledgers: u64,
+    ) -> Result<(), Error> {
+        if month_ledgers == 0 {
+            return Err(Error::InvalidArgument);
+        }
+        let mut goal: u128 = 0;
+        for (_, amount) in months.iter() {
+            goal = goal.checked_add(amount).ok_or(Error::AmountTooLarge)?;
+        }
+        Self::initialize(
+            env.clone(),
+            admin,
+            creator,
+            token,
+            goal,
+            deadline,
+            months.clone(),
+            governance::DEFAULT_APPROVAL_THRESHOLD_BPS,
+            0,
+        )?;
+
+        for index in 0..months.len() {
+            let mut record = evidence::load(&env, index);
+            record.deadline = Some(deadline + u64::from(index + 1) * month_ledgers);
+            evidence::save(&env, index, &record);
+        }
+        let schedule = BudgetSchedule {
+            start: deadline,
+            month_ledgers,
+        };
+        env.storage()
+            .instance()
+            .set(&DataKey::BudgetSchedule, &schedule);
+        Ok(())
+    }
+
+    /// (View) Gets the month length and start of a scheduled project.
+    pub fn get_budget_schedule(env: Env) -> Option<BudgetSchedule> {
+        load(&env)
+    }
+
+    /// (View) Compares the budget scheduled so far with what was released.
+    pub fn get_burn_down(env: Env) -> Result<BurnDown, Error> {
+        let project = Self::get_project(&env)?;
+        let schedule = load(&env).ok_or(Error::NotFound)?;
+
+        let elapsed = u64::from(env.ledger().sequence()).saturating_sub(schedule.start);
+        let months = (elapsed / schedule.month_ledgers).min(u64::from(project.milestones.len()));
+        let mut planned: u128 = 0;
+        let mut released: u128 = 0;
+        for (index, milestone) in project.milestones.iter().enumerate() {
+            if (index as u64) < months {
+                planned += milestone.amount_to_release;
+            }
+            released += milestone.escrow.released;
+        }
+
+        Ok(BurnDown {
+            months_elapsed: months as u32,
+            planned,
+            released,
+            remaining: project.goal.saturating_sub(released),
+        })
+    }
+}
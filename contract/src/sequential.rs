@@ -0,0 +1,59 @@
+use soroban_sdk::{contractimpl, Env};
+
+use crate::{access, Error, MilestoneFund, MilestoneFundClient, Project, StorageKey};
+
+// --- Sequential Releases ---
+// Most campaigns plan their work in order, yet any approved milestone can
+// be released whatever state the ones before it are in. The creator can
+// require releases to follow the milestone order instead: milestone N is
+// only released, streamed or downscoped once milestone N - 1 is complete.
+// A predecessor settled without a release (refunded or expired) counts as
+// complete; a rejected one holds back everything after it.
+
+fn is_enabled(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&StorageKey::SequentialReleases)
+        .unwrap_or(false)
+}
+
+/// Fails with `MilestoneOutOfOrder` while the milestone before
+/// `milestone_index` is still open and releases must follow the order.
+pub(crate) fn ensure_in_order(
+    env: &Env,
+    project: &Project,
+    milestone_index: u32,
+) -> Result<(), Error> {
+    if milestone_index == 0 || !is_enabled(env) {
+        return Ok(());
+    }
+    let previous = project
+        .milestones
+        .get(milestone_index - 1)
+        .ok_or(Error::MilestoneInvalidIndex)?;
+    if !previous.is_complete {
+        return Err(Error::MilestoneOutOfOrder);
+    }
+    Ok(())
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Creator) Sets whether milestones must be released in order. Only
+    /// possible before the first contribution.
+    pub fn set_sequential_releases(env: Env, sequential: bool) -> Result<(), Error> {
+        let project = access::require_creator(&env)?;
+        if project.raised > 0 {
+            return Err(Error::TermsLocked);
+        }
+        env.storage()
+            .instance()
+            .set(&StorageKey::SequentialReleases, &sequential);
+        Ok(())
+    }
+
+    /// (View) Whether milestones must be released in order.
+    pub fn is_sequential_releases(env: Env) -> bool {
+        is_enabled(&env)
+    }
+}
@@ -0,0 +1,169 @@
+use soroban_sdk::{
+    contractclient, contractimpl, contracttype, symbol_short, token, Address, Env, Vec,
+};
+
+use crate::{
+    access, approved_milestone, breaker, events, evidence, lifecycle, pay_out, platform,
+    release_cap, save_project, settle_release, ttl, watch, DataKey, Error, MilestoneFund,
+    MilestoneFundClient,
+};
+
+// --- Settlement Currency ---
+// The creator may take a milestone payout in another whitelisted asset.
+// The allocation is handed to the asset's swap adapter at release time and
+// whatever arrives at the creator must be within the maximum slippage from
+// the adapter's own quote. That maximum is set by the creator while the
+// campaign is raising, so backers approve it by pledging under it.
+
+const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Interface a settlement swap adapter must implement.
+#[contractclient(name = "SwapAdapterClient")]
+pub trait SwapAdapter {
+    /// Amount of `to_asset` that swapping `amount` of `from_asset` along
+    /// `path` would currently deliver.
+    fn quote(
+        env: Env,
+        from_asset: Address,
+        amount: i128,
+        path: Vec<Address>,
+        to_asset: Address,
+    ) -> i128;
+
+    /// Swaps `amount` of `from_asset`, already held by the adapter, along
+    /// `path` and sends at least `min_out` of `to_asset` to `recipient`.
+    /// Returns the amount delivered.
+    fn swap(
+        env: Env,
+        from_asset: Address,
+        amount: i128,
+        path: Vec<Address>,
+        to_asset: Address,
+        min_out: i128,
+        recipient: Address,
+    ) -> i128;
+}
+
+// How the campaign token is swapped into one settlement asset
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SwapRoute {
+    pub adapter: Address,
+    pub path: Vec<Address>, // Intermediate assets for the swap, may be empty
+}
+
+fn max_slippage_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::SettlementSlippageBps)
+        .unwrap_or(0)
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Admin) Whitelists `asset` as a settlement asset, swapped through `adapter` along `path`.
+    pub fn set_settlement_route(
+        env: Env,
+        asset: Address,
+        adapter: Address,
+        path: Vec<Address>,
+    ) -> Result<(), Error> {
+        access::require_admin(&env)?;
+        let route = SwapRoute { adapter, path };
+        ttl::set_persistent(&env, &DataKey::SettlementRoute(asset), &route);
+        Ok(())
+    }
+
+    /// (Admin) Removes `asset` from the settlement whitelist.
+    pub fn remove_settlement_route(env: Env, asset: Address) -> Result<(), Error> {
+        access::require_admin(&env)?;
+        env.storage()
+            .persistent()
+            .remove(&DataKey::SettlementRoute(asset));
+        Ok(())
+    }
+
+    /// (Creator) Sets the maximum slippage from the adapter quote accepted
+    /// on settlement swaps. Can only be changed while the campaign is still
+    /// raising.
+    pub fn set_settlement_slippage(env: Env, max_slippage_bps: u32) -> Result<(), Error> {
+        let project = access::require_creator(&env)?;
+        if project.goal_met {
+            return Err(Error::TermsLocked);
+        }
+        if i128::from(max_slippage_bps) > BPS_DENOMINATOR {
+            return Err(Error::InvalidArgument);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::SettlementSlippageBps, &max_slippage_bps);
+        Ok(())
+    }
+
+    /// (Creator) Releases an approved milestone paid out in the whitelisted
+    /// `asset` instead of the campaign token. Returns the amount of `asset`
+    /// the creator received.
+    pub fn release_funds_in(env: Env, milestone_index: u32, asset: Address) -> Result<i128, Error> {
+        let mut project = access::require_creator(&env)?;
+        evidence::apply_penalty(&env, &mut project, milestone_index)?;
+        let mut milestone = approved_milestone(&env, &project, milestone_index)?;
+        let route: SwapRoute = env
+            .storage()
+            .persistent()
+            .get(&DataKey::SettlementRoute(asset.clone()))
+            .ok_or(Error::NotFound)?;
+        if breaker::trip_on_divergence(&env, &project)? {
+            return Ok(0);
+        }
+
+        let amount = milestone.amount_to_release;
+        milestone.is_complete = true;
+        milestone.escrow.released = amount;
+        project.milestones.set(milestone_index, milestone);
+        lifecycle::advance(&env, &mut project);
+        save_project(&env, &project);
+
+        let amount = settle_release(&env, &project, milestone_index, amount)?;
+        release_cap::take(&env, amount)?;
+        let amount_in = i128::try_from(amount).map_err(|_| Error::AmountTooLarge)?;
+        let adapter = SwapAdapterClient::new(&env, &route.adapter);
+        let quote = adapter.quote(&project.token, &amount_in, &route.path, &asset);
+        let min_out = quote - quote * i128::from(max_slippage_bps(&env)) / BPS_DENOMINATOR;
+
+        let settlement = token::Client::new(&env, &asset);
+        let balance_before = settlement.balance(&project.creator);
+        pay_out(&env, &project.token, &route.adapter, amount)?;
+        adapter.swap(
+            &project.token,
+            &amount_in,
+            &route.path,
+            &asset,
+            &min_out,
+            &project.creator,
+        );
+        let realized = settlement.balance(&project.creator) - balance_before;
+        if realized < min_out {
+            return Err(Error::SlippageExceeded);
+        }
+        platform::report(&env, &project);
+
+        let topics = (symbol_short!("release"), project.creator);
+        events::publish(&env, topics, amount);
+        let topics = (symbol_short!("settle"), milestone_index);
+        events::publish(&env, topics, (asset, realized));
+        watch::publish_transition(&env, symbol_short!("released"), milestone_index);
+        Ok(realized)
+    }
+
+    /// (View) Gets the swap route of settlement asset `asset`, if whitelisted.
+    pub fn get_settlement_route(env: Env, asset: Address) -> Option<SwapRoute> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SettlementRoute(asset))
+    }
+
+    /// (View) Gets the maximum settlement slippage in basis points.
+    pub fn get_settlement_slippage(env: Env) -> u32 {
+        max_slippage_bps(&env)
+    }
+}
@@ -0,0 +1,35 @@
+use soroban_sdk::{contractimpl, vec, Address, Env, String};
+
+use crate::{governance, Error, MilestoneFund, MilestoneFundClient};
+
+// --- Simple Escrow Mode ---
+// Plain all-or-nothing crowdfunding without a milestone plan: the whole
+// goal is one implicit milestone, released by the usual simple majority of
+// backers once the goal is met, or refunded if it is not.
+
+#[contractimpl]
+impl MilestoneFund {
+    /// Initializes a project whose single milestone is the whole `goal`.
+    /// Can only be called once.
+    pub fn create_simple_escrow(
+        env: Env,
+        admin: Address,
+        creator: Address,
+        token: Address,
+        goal: u128,
+        deadline: u64,
+    ) -> Result<(), Error> {
+        let milestones = vec![&env, (String::from_str(&env, "Goal"), goal)];
+        Self::initialize(
+            env,
+            admin,
+            creator,
+            token,
+            goal,
+            deadline,
+            milestones,
+            governance::DEFAULT_APPROVAL_THRESHOLD_BPS,
+            0,
+        )
+    }
+}
@@ -0,0 +1,69 @@
+use soroban_sdk::{contractimpl, contracttype, Address, Env, Map, Vec};
+
+use crate::{
+    backers, governance, rejection, tally_votes, votes, Error, MilestoneFund, MilestoneFundClient,
+};
+
+// --- Vote Simulation ---
+// Before chasing the backers who have not voted, a creator (or a backer
+// rallying the others) wants to know whether their votes would change the
+// outcome. `simulate_vote_outcome` tallies a milestone as if the given
+// votes had been cast on top of the recorded ones, replacing the recorded
+// vote of any backer listed, under the same weighting, delegation and
+// inactivity rules as the real tally. Nothing is written.
+
+/// Most hypothetical votes one simulation takes.
+const MAX_HYPOTHETICAL_VOTES: u32 = 100;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteSimulation {
+    pub yes_weight: u128,
+    pub no_weight: u128,
+    pub abstain_weight: u128, // Eligible weight that would not have voted
+    pub approved: bool,       // Clears the approval threshold and quorum
+    pub rejected: bool,       // Clears the rejection threshold
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (View) Tallies a milestone as if `hypothetical_votes`, as
+    /// `(backer, approve)` pairs, were cast on top of the recorded votes.
+    pub fn simulate_vote_outcome(
+        env: Env,
+        milestone_index: u32,
+        hypothetical_votes: Vec<(Address, bool)>,
+    ) -> Result<VoteSimulation, Error> {
+        let project = Self::get_project(&env)?;
+        if project.milestones.get(milestone_index).is_none() {
+            return Err(Error::MilestoneInvalidIndex);
+        }
+        if hypothetical_votes.len() > MAX_HYPOTHETICAL_VOTES {
+            return Err(Error::CapExceeded);
+        }
+
+        let mut approvals = votes::all(&env, milestone_index);
+        for (backer, approve) in hypothetical_votes.iter() {
+            if !backers::is_backer(&env, &backer) {
+                return Err(Error::NotABacker);
+            }
+            approvals.set(backer, approve);
+        }
+        let mut rejections = Map::new(&env);
+        for (backer, approve) in approvals.iter() {
+            rejections.set(backer, !approve);
+        }
+        let (yes_weight, eligible) = tally_votes(&env, &project, &approvals)?;
+        let (no_weight, _) = tally_votes(&env, &project, &rejections)?;
+        let turnout = yes_weight.checked_add(no_weight).ok_or(Error::Overflow)?;
+
+        Ok(VoteSimulation {
+            yes_weight,
+            no_weight,
+            abstain_weight: eligible.saturating_sub(turnout),
+            approved: governance::clears_threshold(&project, yes_weight, eligible)?
+                && governance::meets_quorum(&project, turnout, eligible)?,
+            rejected: rejection::clears_threshold(&env, no_weight, eligible)?,
+        })
+    }
+}
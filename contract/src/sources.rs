@@ -0,0 +1,106 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Env, Symbol, Vec};
+
+use crate::{limits, ttl, DataKey, Error, MilestoneFund, MilestoneFundClient, StateKey};
+
+// --- Contribution Sources ---
+// `fund` takes an optional source tag ("web", "mobile", "partner_x", ...).
+// Tagged contributions are aggregated per source so creators get
+// attribution analytics on-chain, without correlating events off-chain.
+// Anyone can make up a tag, so the list of tags seen is kept in persistent
+// pages rather than in the instance, and the admin can cap its length
+// through the storage limits; contributions with a new tag past the cap
+// still go through, untagged.
+
+/// Tags per index page.
+const PAGE_SIZE: u32 = 50;
+
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SourceStats {
+    pub contributions: u32,
+    pub amount: u128,
+}
+
+fn count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&StateKey::SourceCount)
+        .unwrap_or(0)
+}
+
+fn page(env: &Env, index: u32) -> Vec<Symbol> {
+    env.storage()
+        .persistent()
+        .get(&StateKey::SourcePage(index))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn load_stats(env: &Env, source: &Symbol) -> SourceStats {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SourceStats(source.clone()))
+        .unwrap_or_default()
+}
+
+/// Pushes the TTL of every index page out.
+pub(crate) fn extend_index(env: &Env) {
+    for index in 0..count(env).div_ceil(PAGE_SIZE) {
+        ttl::extend_persistent(env, &StateKey::SourcePage(index));
+    }
+}
+
+/// Adds a contribution of `amount` to `source`'s counters. A new tag past
+/// the cap is not recorded.
+pub(crate) fn record(env: &Env, source: &Symbol, amount: u128) {
+    let mut stats = load_stats(env, source);
+    if stats.contributions == 0 {
+        let count = count(env);
+        let max = limits::load(env).max_sources;
+        if limits::ensure_room(env, symbol_short!("sources"), count, max).is_err() {
+            return;
+        }
+        let mut last = page(env, count / PAGE_SIZE);
+        last.push_back(source.clone());
+        let key = StateKey::SourcePage(count / PAGE_SIZE);
+        ttl::set_persistent(env, &key, &last);
+        env.storage()
+            .instance()
+            .set(&StateKey::SourceCount, &(count + 1));
+    }
+    stats.contributions = stats.contributions.saturating_add(1);
+    stats.amount = stats.amount.saturating_add(amount);
+    let key = DataKey::SourceStats(source.clone());
+    ttl::set_persistent(env, &key, &stats);
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (View) Lists up to `limit` (at most 50) source tags from position
+    /// `start` on, in first-seen order.
+    pub fn get_sources(env: Env, start: u32, limit: u32) -> Result<Vec<Symbol>, Error> {
+        if limit > PAGE_SIZE {
+            return Err(Error::CapExceeded);
+        }
+        let end = count(&env).min(start.saturating_add(limit));
+        let mut sources = Vec::new(&env);
+        let mut position = start;
+        while position < end {
+            let tags = page(&env, position / PAGE_SIZE);
+            let offset = position % PAGE_SIZE;
+            let taken = (end - position).min(PAGE_SIZE - offset);
+            sources.append(&tags.slice(offset..offset + taken));
+            position += taken;
+        }
+        Ok(sources)
+    }
+
+    /// (View) Gets the number of source tags seen so far.
+    pub fn get_source_count(env: Env) -> u32 {
+        count(&env)
+    }
+
+    /// (View) Gets the contribution counters for `source`.
+    pub fn get_source_stats(env: Env, source: Symbol) -> SourceStats {
+        load_stats(&env, &source)
+    }
+}
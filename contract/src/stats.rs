@@ -0,0 +1,124 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env, Vec};
+
+use crate::{events, privacy, ttl, DataKey, Error, MilestoneFund, MilestoneFundClient};
+
+// --- Statistics Rollup ---
+// Every contribution appends a raw record to a log. Keepers call
+// `rollup_stats` to fold pending records into per-period buckets of
+// `BUCKET_LEDGERS` ledgers and to drop folded records older than
+// `RETENTION_LEDGERS`, so the analytics stay queryable on-chain while the
+// log stays small. The retention window is longer than a bucket, so every
+// earlier record of the bucket a new record lands in is still in the log
+// when it is folded, which is how unique backers are counted.
+
+/// Ledgers per statistics bucket.
+pub const BUCKET_LEDGERS: u64 = 1_000;
+/// How long folded records stay in the log (~1 day).
+const RETENTION_LEDGERS: u64 = 17_280;
+/// Records folded per `rollup_stats` call, to stay within resource limits.
+const MAX_ROLLUP: u32 = 100;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContributionRecord {
+    pub backer: Address,
+    pub amount: u128,
+    pub ledger: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StatsLog {
+    pub records: Vec<ContributionRecord>,
+    pub folded: u32, // Leading records already folded into buckets
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct StatsBucket {
+    pub amount: u128,
+    pub count: u32,
+    pub unique_backers: u32,
+}
+
+fn load_log(env: &Env) -> StatsLog {
+    env.storage()
+        .persistent()
+        .get(&DataKey::StatsLog)
+        .unwrap_or(StatsLog {
+            records: Vec::new(env),
+            folded: 0,
+        })
+}
+
+fn load_bucket(env: &Env, bucket: u64) -> StatsBucket {
+    env.storage()
+        .persistent()
+        .get(&DataKey::StatsBucket(bucket))
+        .unwrap_or_default()
+}
+
+/// Appends a contribution to the raw log.
+pub(crate) fn record(env: &Env, backer: &Address, amount: u128) {
+    let mut log = load_log(env);
+    log.records.push_back(ContributionRecord {
+        backer: backer.clone(),
+        amount,
+        ledger: u64::from(env.ledger().sequence()),
+    });
+    ttl::set_persistent(env, &DataKey::StatsLog, &log);
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// Folds up to `MAX_ROLLUP` pending contribution records into their
+    /// buckets and drops folded records past the retention window. Callable
+    /// by anyone. Returns how many records were folded.
+    pub fn rollup_stats(env: Env) -> u32 {
+        let mut log = load_log(&env);
+        let end = log.records.len().min(log.folded + MAX_ROLLUP);
+        for index in log.folded..end {
+            let record = log.records.get_unchecked(index);
+            let bucket_index = record.ledger / BUCKET_LEDGERS;
+            let mut bucket = load_bucket(&env, bucket_index);
+            bucket.amount += record.amount;
+            bucket.count += 1;
+            let seen = log.records.slice(0..index).iter().any(|earlier| {
+                earlier.ledger / BUCKET_LEDGERS == bucket_index && earlier.backer == record.backer
+            });
+            if !seen {
+                bucket.unique_backers += 1;
+            }
+            ttl::set_persistent(&env, &DataKey::StatsBucket(bucket_index), &bucket);
+        }
+        let rolled = end - log.folded;
+        log.folded = end;
+
+        let now = u64::from(env.ledger().sequence());
+        let mut expired = 0;
+        while expired < log.folded
+            && log.records.get_unchecked(expired).ledger + RETENTION_LEDGERS < now
+        {
+            expired += 1;
+        }
+        log.records = log.records.slice(expired..);
+        log.folded -= expired;
+        ttl::set_persistent(&env, &DataKey::StatsLog, &log);
+
+        events::publish(&env, (symbol_short!("rollup"),), (rolled, expired));
+        rolled
+    }
+
+    /// (View) Gets the aggregates of the bucket starting at ledger
+    /// `bucket * BUCKET_LEDGERS`.
+    pub fn get_stats_bucket(env: Env, bucket: u64) -> StatsBucket {
+        load_bucket(&env, bucket)
+    }
+
+    /// (Creator or admin view) Gets the raw contribution log still held.
+    pub fn get_stats_log(env: Env, viewer: Address) -> Result<StatsLog, Error> {
+        let project = Self::get_project(&env)?;
+        privacy::authorize_viewer(&env, &project, &viewer, None)?;
+        Ok(load_log(&env))
+    }
+}
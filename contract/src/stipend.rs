@@ -0,0 +1,178 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env, String, Vec};
+
+use crate::{
+    backers, breaker, claims, events, governance, pay_out, polls, release_cap, save_project, Error,
+    MilestoneFund, MilestoneFundClient, StorageKey,
+};
+
+// --- Operations Stipend ---
+// A small recurring budget for the creator's overhead, kept apart from the
+// milestones so it never has to be won in a milestone vote. The stipend's
+// total is approved with the project: `initialize_with_stipend` raises it
+// on top of the milestones, where it sits in the surplus. Once the goal is
+// met, keepers call `advance_stipend` to pay `per_interval` every
+// `interval` ledgers until the total is paid. Payments count against the
+// release cap, and whatever the window has no room for queues. Backers
+// holding a majority of the raised funds can halt it; the unpaid rest goes
+// to the refund pool.
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StipendTerms {
+    pub total: u128,
+    pub per_interval: u128,
+    pub interval: u64, // Ledgers between payments
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stipend {
+    pub terms: StipendTerms,
+    pub paid: u128,
+    pub next_at: Option<u64>, // Ledger the next payment is due at, once started
+    pub halted: bool,
+}
+
+fn load(env: &Env) -> Option<Stipend> {
+    env.storage().instance().get(&StorageKey::Stipend)
+}
+
+fn save(env: &Env, stipend: &Stipend) {
+    env.storage().instance().set(&StorageKey::Stipend, stipend);
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// Initializes a project like `initialize`, raising `stipend.total` on
+    /// top of the milestones as the operations stipend. The goal is the sum
+    /// of both. Can only be called once.
+    pub fn initialize_with_stipend(
+        env: Env,
+        admin: Address,
+        creator: Address,
+        token: Address,
+        deadline: u64,
+        milestones: Vec<(String, u128)>,
+        stipend: StipendTerms,
+    ) -> Result<(), Error> {
+        if stipend.total == 0 || stipend.per_interval == 0 || stipend.interval == 0 {
+            return Err(Error::InvalidArgument);
+        }
+        let mut milestones_total: u128 = 0;
+        for (_, amount) in milestones.iter() {
+            milestones_total = milestones_total
+                .checked_add(amount)
+                .ok_or(Error::AmountTooLarge)?;
+        }
+        Self::initialize(
+            env.clone(),
+            admin,
+            creator,
+            token,
+            milestones_total,
+            deadline,
+            milestones,
+            governance::DEFAULT_APPROVAL_THRESHOLD_BPS,
+            0,
+        )?;
+
+        let mut project = Self::get_project(&env)?;
+        project.goal = milestones_total
+            .checked_add(stipend.total)
+            .ok_or(Error::AmountTooLarge)?;
+        save_project(&env, &project);
+        let stipend = Stipend {
+            terms: stipend,
+            paid: 0,
+            next_at: None,
+            halted: false,
+        };
+        save(&env, &stipend);
+        Ok(())
+    }
+
+    /// Pays the creator the next due stipend payment. Callable by anyone.
+    /// Returns the payment, including any part the release cap queued.
+    pub fn advance_stipend(env: Env) -> Result<u128, Error> {
+        breaker::ensure_writable(&env)?;
+        let mut project = Self::get_project(&env)?;
+        let mut stipend = load(&env).ok_or(Error::NotFound)?;
+        if !project.goal_met {
+            return Err(Error::GoalNotMet);
+        }
+        if stipend.halted {
+            return Err(Error::NotEligible);
+        }
+        if stipend.paid >= stipend.terms.total {
+            return Err(Error::CapExceeded);
+        }
+        let now = u64::from(env.ledger().sequence());
+        let due_at = stipend.next_at.unwrap_or(now);
+        if now < due_at {
+            return Err(Error::NotDue);
+        }
+        if breaker::trip_on_divergence(&env, &project)? {
+            return Ok(0);
+        }
+
+        let amount = stipend
+            .terms
+            .per_interval
+            .min(stipend.terms.total - stipend.paid)
+            .min(project.surplus);
+        if amount == 0 {
+            return Err(Error::InsufficientFunds);
+        }
+        stipend.paid += amount;
+        stipend.next_at = Some(due_at + stipend.terms.interval);
+        save(&env, &stipend);
+        project.surplus -= amount;
+        save_project(&env, &project);
+        let payable = release_cap::admit(&env, amount);
+        if payable > 0 {
+            pay_out(&env, &project.token, &project.creator, payable)?;
+        }
+
+        events::publish(&env, (symbol_short!("stipend"), project.creator), amount);
+        Ok(amount)
+    }
+
+    /// Votes to halt the stipend. Once backers holding a majority of the
+    /// raised funds agree, the unpaid rest goes to the refund pool.
+    pub fn vote_halt_stipend(env: Env, backer: Address) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        backer.require_auth();
+        let mut project = Self::get_project(&env)?;
+        let mut stipend = load(&env).ok_or(Error::NotFound)?;
+        if stipend.halted {
+            return Err(Error::AlreadyExists);
+        }
+        if !backers::is_backer(&env, &backer) {
+            return Err(Error::NotABacker);
+        }
+        let poll = symbol_short!("stipend");
+        polls::cast(&env, &poll, 0, &backer, true)?;
+
+        let halt_weight = polls::weight(&env, &poll, 0)?;
+        if halt_weight.checked_mul(2).ok_or(Error::Overflow)? > project.raised {
+            let unpaid = (stipend.terms.total - stipend.paid).min(project.surplus);
+            stipend.halted = true;
+            project.surplus -= unpaid;
+            claims::credit_refund_pool(&mut project, unpaid);
+            save_project(&env, &project);
+
+            events::publish(
+                &env,
+                (symbol_short!("stipend"), symbol_short!("halted")),
+                unpaid,
+            );
+        }
+        save(&env, &stipend);
+        Ok(())
+    }
+
+    /// (View) Gets the operations stipend, if the project has one.
+    pub fn get_stipend(env: Env) -> Result<Stipend, Error> {
+        load(&env).ok_or(Error::NotFound)
+    }
+}
@@ -0,0 +1,194 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env};
+
+use crate::{
+    access, approved_milestone, backers, breaker, claims, events, evidence, lifecycle, pay_out,
+    platform, polls, release_cap, rewards, save_project, settle_release, ttl, watch, DataKey,
+    Error, MilestoneFund, MilestoneFundClient,
+};
+
+// --- Milestone Payroll Streams ---
+// Instead of a one-shot release, the creator can have an approved milestone
+// paid to a payroll contract in equal installments, one per `interval`
+// ledgers. Keepers call `advance_stream` to pay each installment once due.
+// Installments go through the same bookkeeping as any release (bounty,
+// payout ledger, premium, fees, platform report) and count against the
+// release cap; since the cap's queue pays the creator, an installment must
+// fit the window's room whole.
+// If delivery stalls, backers holding a majority of the raised funds can vote
+// to halt the stream; the unpaid rest goes to the backers' refund pool.
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MilestoneStream {
+    pub payroll: Address,
+    pub installments: u32,
+    pub installments_paid: u32,
+    pub interval: u64, // Ledgers between installments
+    pub next_at: u64,  // Ledger the next installment is due at
+    pub halted: bool,
+    pub halt_round: u32, // Scope of the stream's halt poll
+}
+
+fn load(env: &Env, milestone_index: u32) -> Option<MilestoneStream> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Stream(milestone_index))
+}
+
+fn save(env: &Env, milestone_index: u32, stream: &MilestoneStream) {
+    ttl::set_persistent(env, &DataKey::Stream(milestone_index), stream);
+}
+
+/// Checks whether a milestone is paid out through a stream.
+pub(crate) fn is_streaming(env: &Env, milestone_index: u32) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Stream(milestone_index))
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Creator) Streams an approved milestone to `payroll` in
+    /// `installments` equal parts, the first due immediately and each
+    /// following one `interval` ledgers later.
+    pub fn start_stream(
+        env: Env,
+        milestone_index: u32,
+        payroll: Address,
+        installments: u32,
+        interval: u64,
+    ) -> Result<(), Error> {
+        let mut project = access::require_creator(&env)?;
+
+        evidence::apply_penalty(&env, &mut project, milestone_index)?;
+        approved_milestone(&env, &project, milestone_index)?;
+        if installments == 0 || interval == 0 {
+            return Err(Error::InvalidArgument);
+        }
+
+        let stream = MilestoneStream {
+            payroll: payroll.clone(),
+            installments,
+            installments_paid: 0,
+            interval,
+            next_at: u64::from(env.ledger().sequence()),
+            halted: false,
+            halt_round: polls::next_round(&env, &symbol_short!("halt")),
+        };
+        save(&env, milestone_index, &stream);
+
+        let topics = (symbol_short!("stream"), milestone_index);
+        events::publish(&env, topics, (payroll, installments, interval));
+        Ok(())
+    }
+
+    /// Pays the next due installment of a milestone stream. Callable by anyone.
+    pub fn advance_stream(env: Env, milestone_index: u32) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        let mut project = Self::get_project(&env)?;
+        let mut stream = load(&env, milestone_index).ok_or(Error::NotFound)?;
+        let mut milestone = project
+            .milestones
+            .get(milestone_index)
+            .ok_or(Error::MilestoneInvalidIndex)?;
+
+        if milestone.is_complete {
+            return Err(Error::MilestoneAlreadyCompleted);
+        }
+        if u64::from(env.ledger().sequence()) < stream.next_at {
+            return Err(Error::NotDue);
+        }
+        if breaker::trip_on_divergence(&env, &project)? {
+            return Ok(());
+        }
+
+        // The last installment also carries the rounding remainder
+        stream.installments_paid = stream
+            .installments_paid
+            .checked_add(1)
+            .ok_or(Error::Overflow)?;
+        let amount = if stream.installments_paid == stream.installments {
+            milestone
+                .amount_to_release
+                .saturating_sub(milestone.escrow.released)
+        } else {
+            milestone.amount_to_release / u128::from(stream.installments)
+        };
+        stream.next_at = stream
+            .next_at
+            .checked_add(stream.interval)
+            .ok_or(Error::Overflow)?;
+        milestone.escrow.released = milestone
+            .escrow
+            .released
+            .checked_add(amount)
+            .ok_or(Error::Overflow)?;
+        let finished = stream.installments_paid == stream.installments;
+        milestone.is_complete = finished;
+        project.milestones.set(milestone_index, milestone);
+        lifecycle::advance(&env, &mut project);
+        save_project(&env, &project);
+        save(&env, milestone_index, &stream);
+
+        let net = settle_release(&env, &project, milestone_index, amount)?;
+        release_cap::take(&env, net)?;
+        pay_out(&env, &project.token, &stream.payroll, net)?;
+        platform::report(&env, &project);
+
+        let topics = (symbol_short!("streampay"), milestone_index);
+        events::publish(&env, topics, (stream.installments_paid, amount));
+        if finished {
+            watch::publish_transition(&env, symbol_short!("released"), milestone_index);
+        }
+        Ok(())
+    }
+
+    /// Votes to halt a milestone stream. Once backers holding a majority of
+    /// the raised funds agree, the unpaid installments go to the refund pool.
+    pub fn vote_halt_stream(env: Env, backer: Address, milestone_index: u32) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        backer.require_auth();
+        let mut project = Self::get_project(&env)?;
+        let mut stream = load(&env, milestone_index).ok_or(Error::NotFound)?;
+        let mut milestone = project
+            .milestones
+            .get(milestone_index)
+            .ok_or(Error::MilestoneInvalidIndex)?;
+
+        if milestone.is_complete {
+            return Err(Error::MilestoneAlreadyCompleted);
+        }
+        if !backers::is_backer(&env, &backer) {
+            return Err(Error::NotABacker);
+        }
+        let poll = symbol_short!("halt");
+        polls::cast(&env, &poll, stream.halt_round, &backer, true)?;
+
+        let halt_weight = polls::weight(&env, &poll, stream.halt_round)?;
+        if halt_weight.checked_mul(2).ok_or(Error::Overflow)? > project.raised {
+            let unpaid = milestone.amount_to_release - milestone.escrow.released;
+            stream.halted = true;
+            milestone.escrow.refunded = milestone
+                .escrow
+                .refunded
+                .checked_add(unpaid)
+                .ok_or(Error::Overflow)?;
+            milestone.is_complete = true;
+            project.milestones.set(milestone_index, milestone);
+            claims::credit_refund_pool(&mut project, unpaid);
+            lifecycle::advance(&env, &mut project);
+            save_project(&env, &project);
+            rewards::note_completion(&env, &project);
+
+            events::publish(&env, (symbol_short!("halt"), milestone_index), unpaid);
+            watch::publish_transition(&env, symbol_short!("halted"), milestone_index);
+        }
+        save(&env, milestone_index, &stream);
+        Ok(())
+    }
+
+    /// (View) Gets the payroll stream of a milestone, if it has one.
+    pub fn get_stream(env: Env, milestone_index: u32) -> Option<MilestoneStream> {
+        load(&env, milestone_index)
+    }
+}
@@ -0,0 +1,168 @@
+use soroban_sdk::{contractclient, contractimpl, contracttype, symbol_short, Address, Env};
+
+use crate::{
+    auth, breaker, check_contribution, ensure_funding_open, events, record_contribution, ttl,
+    Error, MilestoneFund, MilestoneFundClient, StorageKey,
+};
+
+// --- Stream-Backed Pledges ---
+// A backer with an incoming payment stream can pledge against it, payroll
+// deduction style: they approve this contract as an operator on the stream
+// and pledge a target, to be collected `per_period` every `period` ledgers.
+// Keepers call `collect_stream_pledge` when a period is due; whatever the
+// stream can pay of the instalment is claimed straight into the campaign
+// as an ordinary contribution. The pledge completes once the target is
+// collected. If funding closes first (the goal is met, the deadline passes
+// or the campaign is cancelled), the next collection ends it instead, with
+// the uncollected rest recorded as its shortfall.
+
+/// Interface the payment stream contract must implement.
+#[contractclient(name = "PaymentStreamClient")]
+pub trait PaymentStream {
+    /// Asset stream `stream_id` pays in.
+    fn token(env: Env, stream_id: u64) -> Address;
+    /// Amount the recipient of stream `stream_id` could withdraw right now.
+    fn withdrawable(env: Env, stream_id: u64) -> i128;
+    /// Withdraws `amount` of stream `stream_id` to `to` on the recipient's
+    /// behalf. `operator` has been approved by the recipient and authorizes
+    /// the call.
+    fn claim(env: Env, stream_id: u64, operator: Address, amount: i128, to: Address);
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StreamPledgeStatus {
+    Active,
+    Completed, // The target was collected
+    Shortfall, // Funding closed before the target was collected
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamPledge {
+    pub stream: Address, // Payment stream contract
+    pub stream_id: u64,
+    pub target: u128,
+    pub per_period: u128,
+    pub period: u64, // Ledgers between collections
+    pub collected: u128,
+    pub next_at: u64, // Ledger the next collection is due at
+    pub status: StreamPledgeStatus,
+}
+
+fn load(env: &Env, backer: &Address) -> Option<StreamPledge> {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::StreamPledge(backer.clone()))
+}
+
+fn save(env: &Env, backer: &Address, pledge: &StreamPledge) {
+    ttl::set_persistent(env, &StorageKey::StreamPledge(backer.clone()), pledge);
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Backer) Pledges `target` against the backer's stream `stream_id`,
+    /// collected `per_period` every `period` ledgers from now on. The
+    /// backer must have approved this contract as an operator on the
+    /// stream. Replaces a pledge that has ended.
+    pub fn pledge_stream(
+        env: Env,
+        backer: Address,
+        stream: Address,
+        stream_id: u64,
+        target: u128,
+        per_period: u128,
+        period: u64,
+    ) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        auth::require_auth_for_amount(&env, &backer, target);
+        let project = Self::get_project(&env)?;
+        ensure_funding_open(&env, &project)?;
+        if target == 0 || per_period == 0 || per_period > target || period == 0 {
+            return Err(Error::InvalidArgument);
+        }
+        if load(&env, &backer).is_some_and(|pledge| pledge.status == StreamPledgeStatus::Active) {
+            return Err(Error::AlreadyExists);
+        }
+        if PaymentStreamClient::new(&env, &stream).token(&stream_id) != project.token {
+            return Err(Error::InvalidArgument);
+        }
+
+        let pledge = StreamPledge {
+            stream,
+            stream_id,
+            target,
+            per_period,
+            period,
+            collected: 0,
+            next_at: u64::from(env.ledger().sequence()),
+            status: StreamPledgeStatus::Active,
+        };
+        save(&env, &backer, &pledge);
+
+        events::publish(&env, (symbol_short!("spledge"), backer), target);
+        Ok(())
+    }
+
+    /// Collects the instalment of `backer`'s stream pledge that is due, or
+    /// ends the pledge once funding has closed. Callable by anyone.
+    /// Returns the amount collected.
+    pub fn collect_stream_pledge(env: Env, backer: Address) -> Result<u128, Error> {
+        breaker::ensure_writable(&env)?;
+        let project = Self::get_project(&env)?;
+        let mut pledge = load(&env, &backer).ok_or(Error::NotFound)?;
+        if pledge.status != StreamPledgeStatus::Active {
+            return Err(Error::NotEligible);
+        }
+
+        if ensure_funding_open(&env, &project).is_err() {
+            pledge.status = StreamPledgeStatus::Shortfall;
+            save(&env, &backer, &pledge);
+            let shortfall = pledge.target - pledge.collected;
+            let topics = (symbol_short!("spledge"), symbol_short!("short"), backer);
+            events::publish(&env, topics, shortfall);
+            return Ok(0);
+        }
+        let now = u64::from(env.ledger().sequence());
+        if now < pledge.next_at {
+            return Err(Error::NotDue);
+        }
+
+        let client = PaymentStreamClient::new(&env, &pledge.stream);
+        let available = u128::try_from(client.withdrawable(&pledge.stream_id)).unwrap_or(0);
+        let amount = pledge
+            .per_period
+            .min(pledge.target - pledge.collected)
+            .min(available);
+        if amount == 0 {
+            return Err(Error::InsufficientFunds);
+        }
+        check_contribution(&env, &project, &backer, amount)?;
+        if breaker::trip_on_divergence(&env, &project)? {
+            return Ok(0);
+        }
+
+        pledge.collected += amount;
+        pledge.next_at = now + pledge.period;
+        if pledge.collected == pledge.target {
+            pledge.status = StreamPledgeStatus::Completed;
+        }
+        save(&env, &backer, &pledge);
+
+        let units = i128::try_from(amount).map_err(|_| Error::AmountTooLarge)?;
+        client.claim(
+            &pledge.stream_id,
+            &env.current_contract_address(),
+            &units,
+            &env.current_contract_address(),
+        );
+        record_contribution(&env, project, &backer, amount)?;
+        Ok(amount)
+    }
+
+    /// (View) Gets `backer`'s stream pledge, if they made one.
+    pub fn get_stream_pledge(env: Env, backer: Address) -> Option<StreamPledge> {
+        load(&env, &backer)
+    }
+}
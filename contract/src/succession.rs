@@ -0,0 +1,116 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env};
+
+use crate::{
+    access, backers, breaker, events, has_supermajority, polls, save_project, watch, DataKey,
+    Error, MilestoneFund, MilestoneFundClient,
+};
+
+// --- Creator Succession ---
+// If the creator goes unresponsive, backers can nominate a successor (e.g.
+// a co-founder). Once backers holding two thirds of the raised weight have
+// voted for the nomination, a challenge window opens in which the current
+// creator can cancel it simply by showing up. After the window anyone can
+// execute the replacement, which hands the successor every creator
+// permission and payout right, since those all follow `Project::creator`.
+
+/// Ledgers between reaching the supermajority and the replacement (~1 day).
+pub(crate) const CHALLENGE_WINDOW: u64 = 17_280;
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SuccessionProposal {
+    pub successor: Address,
+    pub round: u32,               // Scope of the nomination's poll
+    pub approved_at: Option<u64>, // Ledger the supermajority was reached
+}
+
+fn load(env: &Env) -> Option<SuccessionProposal> {
+    env.storage().instance().get(&DataKey::Succession)
+}
+
+fn save(env: &Env, proposal: &SuccessionProposal) {
+    env.storage().instance().set(&DataKey::Succession, proposal);
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// Votes to replace the creator with `successor`, opening the
+    /// nomination if there is none yet.
+    pub fn vote_successor(env: Env, backer: Address, successor: Address) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        backer.require_auth();
+        let project = Self::get_project(&env)?;
+        if !backers::is_backer(&env, &backer) {
+            return Err(Error::NotABacker);
+        }
+
+        let poll = symbol_short!("successor");
+        let mut proposal = match load(&env) {
+            Some(proposal) => proposal,
+            None => SuccessionProposal {
+                successor: successor.clone(),
+                round: polls::next_round(&env, &poll),
+                approved_at: None,
+            },
+        };
+        if proposal.successor != successor || successor == project.creator {
+            return Err(Error::InvalidArgument);
+        }
+        polls::cast(&env, &poll, proposal.round, &backer, true)?;
+
+        let votes = polls::all(&env, &poll, proposal.round);
+        if proposal.approved_at.is_none() && has_supermajority(&env, &project, &votes)? {
+            let now = u64::from(env.ledger().sequence());
+            proposal.approved_at = Some(now);
+            watch::publish_transition(&env, symbol_short!("successor"), now + CHALLENGE_WINDOW);
+        }
+        save(&env, &proposal);
+
+        events::publish(&env, (symbol_short!("successor"), backer), successor);
+        Ok(())
+    }
+
+    /// (Creator) Cancels the pending nomination, proving the creator is
+    /// still responsive.
+    pub fn challenge_succession(env: Env) -> Result<(), Error> {
+        access::require_creator(&env)?;
+        let proposal = load(&env).ok_or(Error::NotFound)?;
+
+        env.storage().instance().remove(&DataKey::Succession);
+        events::publish(
+            &env,
+            (symbol_short!("successor"), symbol_short!("challenge")),
+            proposal.successor,
+        );
+        Ok(())
+    }
+
+    /// Hands the creator role to the approved successor once the challenge
+    /// window has passed. Anyone can call this.
+    pub fn execute_succession(env: Env) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        let mut project = Self::get_project(&env)?;
+        let proposal = load(&env).ok_or(Error::NotFound)?;
+        let approved_at = proposal.approved_at.ok_or(Error::NotApproved)?;
+        if u64::from(env.ledger().sequence()) < approved_at + CHALLENGE_WINDOW {
+            return Err(Error::NotDue);
+        }
+
+        let previous = project.creator.clone();
+        project.creator = proposal.successor.clone();
+        save_project(&env, &project);
+        env.storage().instance().remove(&DataKey::Succession);
+
+        events::publish(
+            &env,
+            (symbol_short!("creator"), previous),
+            proposal.successor,
+        );
+        Ok(())
+    }
+
+    /// (View) Gets the pending creator nomination, if any.
+    pub fn get_succession(env: Env) -> Option<SuccessionProposal> {
+        load(&env)
+    }
+}
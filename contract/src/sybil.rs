@@ -0,0 +1,102 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env};
+
+use crate::{
+    access, breaker, events, ttl, Error, MilestoneFund, MilestoneFundClient, Project, StorageKey,
+};
+
+// --- Sybil Gate ---
+// Fresh addresses showing up just before the deadline are a cheap way to
+// farm votes. With the gate on, a contribution is rejected when the
+// backer's first interaction with the contract (a registration or an
+// earlier contribution) falls within the final `final_ledgers` of the
+// funding window, and, with a `cooldown`, unless the backer registered at
+// least `cooldown` ledgers earlier.
+
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SybilGate {
+    pub final_ledgers: u64, // Closing ledgers in which newcomers are turned away
+    pub cooldown: u64,      // Ledgers between registration and a first contribution
+}
+
+fn load(env: &Env) -> SybilGate {
+    env.storage()
+        .instance()
+        .get(&StorageKey::SybilGate)
+        .unwrap_or_default()
+}
+
+fn first_seen(env: &Env, backer: &Address) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::FirstSeen(backer.clone()))
+}
+
+/// Remembers the ledger `backer` first interacted with the contract.
+pub(crate) fn note_seen(env: &Env, backer: &Address) {
+    if first_seen(env, backer).is_none() {
+        ttl::set_persistent(
+            env,
+            &StorageKey::FirstSeen(backer.clone()),
+            &u64::from(env.ledger().sequence()),
+        );
+    }
+}
+
+/// Rejects a contribution the gate turns away.
+pub(crate) fn check(env: &Env, project: &Project, backer: &Address) -> Result<(), Error> {
+    let gate = load(env);
+    if gate == SybilGate::default() {
+        return Ok(());
+    }
+    let now = u64::from(env.ledger().sequence());
+    let seen = first_seen(env, backer);
+    let arrived = seen.unwrap_or(now);
+    if gate.final_ledgers > 0 && arrived >= project.deadline.saturating_sub(gate.final_ledgers) {
+        return Err(Error::NotEligible);
+    }
+    if gate.cooldown > 0 && seen.is_none_or(|seen| now < seen + gate.cooldown) {
+        return Err(Error::NotEligible);
+    }
+    Ok(())
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Creator) Turns the sybil gate on, or off with zeros. Only possible
+    /// while the campaign is still raising.
+    pub fn set_sybil_gate(env: Env, gate: SybilGate) -> Result<(), Error> {
+        let project = access::require_creator(&env)?;
+        if project.goal_met {
+            return Err(Error::TermsLocked);
+        }
+        env.storage().instance().set(&StorageKey::SybilGate, &gate);
+        Ok(())
+    }
+
+    /// (Backer) Registers `backer` ahead of contributing, starting the
+    /// cooldown. Returns the ledger they were first seen at.
+    pub fn register_backer(env: Env, backer: Address) -> Result<u64, Error> {
+        breaker::ensure_writable(&env)?;
+        backer.require_auth();
+        Self::get_project(&env)?;
+        if first_seen(&env, &backer).is_some() {
+            return Err(Error::AlreadyExists);
+        }
+        note_seen(&env, &backer);
+
+        let now = u64::from(env.ledger().sequence());
+        events::publish(&env, (symbol_short!("register"), backer), now);
+        Ok(now)
+    }
+
+    /// (View) Gets the sybil gate settings.
+    pub fn get_sybil_gate(env: Env) -> SybilGate {
+        load(&env)
+    }
+
+    /// (View) Gets the ledger `backer` first interacted with the contract.
+    pub fn get_first_seen(env: Env, backer: Address) -> Option<u64> {
+        first_seen(&env, &backer)
+    }
+}
@@ -0,0 +1,126 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, xdr::ToXdr, Bytes, BytesN, Env, Vec};
+
+use crate::{access, events, limits, DataKey, Error, MilestoneFund, MilestoneFundClient};
+
+// --- Locked Terms ---
+// Backers pledge under a set of terms; this anchors them. The creator can
+// anchor the hash of the terms document before funding opens, and the
+// first contribution locks the baseline: that document hash plus a hash of
+// the governance parameters as `get_governance_config` reports them. The
+// baseline never changes afterwards. Any later change of terms is recorded
+// with `amend_terms` as an amendment carrying the new document hash, the
+// governance hash at that point and a reference to the diff against the
+// baseline, so backers can audit what changed since they pledged.
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Amendment {
+    pub ledger: u64,
+    pub terms_hash: BytesN<32>,
+    pub governance_hash: BytesN<32>,
+    pub diff_ref: BytesN<32>, // Hash of the diff against the baseline
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TermsLock {
+    pub terms_hash: Option<Bytes>, // 32-byte hash of the terms document
+    pub governance_hash: Option<Bytes>, // 32-byte hash of the governance parameters
+    pub locked_at: Option<u64>,    // Ledger of the first contribution
+    pub amendments: Vec<Amendment>,
+}
+
+fn load(env: &Env) -> TermsLock {
+    env.storage()
+        .instance()
+        .get(&DataKey::TermsLock)
+        .unwrap_or(TermsLock {
+            terms_hash: None,
+            governance_hash: None,
+            locked_at: None,
+            amendments: Vec::new(env),
+        })
+}
+
+fn save(env: &Env, lock: &TermsLock) {
+    env.storage().instance().set(&DataKey::TermsLock, lock);
+}
+
+fn governance_hash(env: &Env) -> Result<BytesN<32>, Error> {
+    let config = MilestoneFund::get_governance_config(env.clone())?;
+    Ok(env.crypto().sha256(&config.to_xdr(env)).to_bytes())
+}
+
+/// Locks the baseline on the first contribution.
+pub(crate) fn lock_on_first_contribution(env: &Env) {
+    let mut lock = load(env);
+    if lock.locked_at.is_some() {
+        return;
+    }
+    let Ok(hash) = governance_hash(env) else {
+        return;
+    };
+    lock.governance_hash = Some(hash.clone().into());
+    lock.locked_at = Some(u64::from(env.ledger().sequence()));
+    save(env, &lock);
+
+    events::publish(env, (symbol_short!("terms"), symbol_short!("locked")), hash);
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Creator) Anchors the hash of the terms document. Only possible
+    /// before the first contribution locks the terms.
+    pub fn anchor_terms(env: Env, terms_hash: BytesN<32>) -> Result<(), Error> {
+        access::require_creator(&env)?;
+        let mut lock = load(&env);
+        if lock.locked_at.is_some() {
+            return Err(Error::TermsLocked);
+        }
+        lock.terms_hash = Some(terms_hash.into());
+        save(&env, &lock);
+        Ok(())
+    }
+
+    /// (Creator) Records an amendment to the locked terms: the new terms
+    /// document and the hash of its diff against the baseline.
+    pub fn amend_terms(
+        env: Env,
+        terms_hash: BytesN<32>,
+        diff_ref: BytesN<32>,
+    ) -> Result<(), Error> {
+        access::require_creator(&env)?;
+        let mut lock = load(&env);
+        if lock.locked_at.is_none() {
+            return Err(Error::NotFound);
+        }
+        let max = limits::load(&env).max_audit_entries;
+        limits::ensure_room(&env, symbol_short!("audit"), lock.amendments.len(), max)?;
+
+        let amendment = Amendment {
+            ledger: u64::from(env.ledger().sequence()),
+            terms_hash,
+            governance_hash: governance_hash(&env)?,
+            diff_ref,
+        };
+        lock.amendments.push_back(amendment.clone());
+        save(&env, &lock);
+
+        events::publish(
+            &env,
+            (symbol_short!("terms"), symbol_short!("amended")),
+            amendment,
+        );
+        Ok(())
+    }
+
+    /// (View) Gets the locked baseline and every amendment since.
+    pub fn get_terms_lock(env: Env) -> TermsLock {
+        load(&env)
+    }
+
+    /// (View) Gets the amendments recorded since the terms were locked.
+    pub fn get_amendment_history(env: Env) -> Vec<Amendment> {
+        load(&env).amendments
+    }
+}
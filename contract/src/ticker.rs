@@ -0,0 +1,87 @@
+use soroban_sdk::{contractimpl, contracttype, Address, Env, String, Vec};
+
+use crate::{MilestoneFund, MilestoneFundClient, StateKey};
+
+// --- Contribution Ticker ---
+// Campaign pages show a live ticker of the latest contributions. Rather
+// than having them subscribe to the event stream and filter it, the last
+// `TICKER_SIZE` contributions are kept in a ring buffer in temporary
+// storage, readable in one call. Each entry shows only the first
+// characters of the backer's address, as the ticker is public. The buffer
+// lives about a day past the last contribution; a quiet campaign simply
+// shows an empty ticker.
+
+/// Contributions kept in the ticker.
+const TICKER_SIZE: u32 = 10;
+/// Characters of the backer's address shown in the ticker.
+const PREFIX_LEN: usize = 8;
+/// Ledgers (about a day) the ticker lives past the last contribution.
+const TICKER_TTL: u32 = 17_280;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecentContribution {
+    pub backer_prefix: String, // Leading characters of the backer's address
+    pub amount: u128,
+    pub ledger: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ticker {
+    pub entries: Vec<RecentContribution>,
+    pub next: u32, // Slot the next contribution overwrites once the ring is full
+}
+
+fn load(env: &Env) -> Ticker {
+    env.storage()
+        .temporary()
+        .get(&StateKey::Ticker)
+        .unwrap_or(Ticker {
+            entries: Vec::new(env),
+            next: 0,
+        })
+}
+
+fn prefix(env: &Env, backer: &Address) -> String {
+    let mut strkey = [0u8; 56];
+    backer.to_string().copy_into_slice(&mut strkey);
+    String::from_bytes(env, &strkey[..PREFIX_LEN])
+}
+
+/// Adds a contribution to the ticker, overwriting the oldest once full.
+pub(crate) fn record(env: &Env, backer: &Address, amount: u128) {
+    let mut ticker = load(env);
+    let entry = RecentContribution {
+        backer_prefix: prefix(env, backer),
+        amount,
+        ledger: env.ledger().sequence(),
+    };
+    if ticker.entries.len() < TICKER_SIZE {
+        ticker.entries.push_back(entry);
+    } else {
+        ticker.entries.set(ticker.next, entry);
+    }
+    ticker.next = (ticker.next + 1) % TICKER_SIZE;
+
+    env.storage().temporary().set(&StateKey::Ticker, &ticker);
+    env.storage()
+        .temporary()
+        .extend_ttl(&StateKey::Ticker, TICKER_TTL, TICKER_TTL);
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (View) Lists the last contributions (at most 10), newest first.
+    /// Empty once the campaign has been quiet for about a day.
+    pub fn get_recent_contributions(env: Env) -> Vec<RecentContribution> {
+        let ticker = load(&env);
+        let len = ticker.entries.len();
+        let mut recent = Vec::new(&env);
+        for offset in 1..=len {
+            let slot = (ticker.next + TICKER_SIZE - offset) % TICKER_SIZE;
+            recent.push_back(ticker.entries.get_unchecked(slot));
+        }
+        recent
+    }
+}
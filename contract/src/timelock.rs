@@ -0,0 +1,145 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Env, Map};
+
+use crate::{
+    access, breaker, events, is_approved, projects, vote_weights, Error, MilestoneFund,
+    MilestoneFundClient, Project, StorageKey,
+};
+
+// --- Release Timelock ---
+// The creator can require approved milestones to wait `ledgers` before
+// they are released, leaving backers time to object. The clock starts at
+// the vote that first carries a milestone; `queue_release` starts it for a
+// milestone carried some other way (e.g. by fallback votes). A unanimous
+// milestone has nothing to wait for, so `fast_release` releases it at once
+// and records that the fast path was used.
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReleaseTimelock {
+    pub ledgers: u64,
+    pub approved_at: Map<u32, u64>, // Milestone index -> ledger it was carried
+    pub fast_released: Map<u32, bool>, // Milestones released unanimously
+}
+
+fn load(env: &Env) -> ReleaseTimelock {
+    env.storage()
+        .instance()
+        .get(&StorageKey::ReleaseTimelock)
+        .unwrap_or(ReleaseTimelock {
+            ledgers: 0,
+            approved_at: Map::new(env),
+            fast_released: Map::new(env),
+        })
+}
+
+fn save(env: &Env, timelock: &ReleaseTimelock) {
+    env.storage()
+        .instance()
+        .set(&StorageKey::ReleaseTimelock, timelock);
+}
+
+/// Configured delay between approval and release.
+pub(crate) fn ledgers(env: &Env) -> u64 {
+    load(env).ledgers
+}
+
+/// Starts the clock if the vote just recorded in `project` carried the
+/// milestone, and stops it if a changed vote uncarried it.
+pub(crate) fn note_vote(env: &Env, project: &Project, milestone_index: u32) -> Result<(), Error> {
+    let mut timelock = load(env);
+    if timelock.ledgers == 0 {
+        return Ok(());
+    }
+    let carried = is_approved(env, project, milestone_index)?;
+    if timelock.approved_at.contains_key(milestone_index) {
+        if !carried {
+            timelock.approved_at.remove(milestone_index);
+            save(env, &timelock);
+        }
+        return Ok(());
+    }
+    if carried {
+        let now = u64::from(env.ledger().sequence());
+        timelock.approved_at.set(milestone_index, now);
+        save(env, &timelock);
+    }
+    Ok(())
+}
+
+/// Rejects the release of a milestone still inside its timelock.
+pub(crate) fn ensure_elapsed(env: &Env, milestone_index: u32) -> Result<(), Error> {
+    let timelock = load(env);
+    if timelock.ledgers == 0 || timelock.fast_released.contains_key(milestone_index) {
+        return Ok(());
+    }
+    let approved_at = timelock
+        .approved_at
+        .get(milestone_index)
+        .ok_or(Error::NotDue)?;
+    if u64::from(env.ledger().sequence()) < approved_at + timelock.ledgers {
+        return Err(Error::NotDue);
+    }
+    Ok(())
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Creator) Sets how many ledgers an approved milestone waits before
+    /// release. Only possible while the campaign is still raising.
+    pub fn set_release_timelock(env: Env, ledgers: u64) -> Result<(), Error> {
+        let project = access::require_creator(&env)?;
+        if project.goal_met {
+            return Err(Error::TermsLocked);
+        }
+        let mut timelock = load(&env);
+        timelock.ledgers = ledgers;
+        save(&env, &timelock);
+        Ok(())
+    }
+
+    /// Starts the timelock of a milestone that has been carried. Callable
+    /// by anyone.
+    pub fn queue_release(env: Env, milestone_index: u32) -> Result<u64, Error> {
+        breaker::ensure_writable(&env)?;
+        let project = Self::get_project(&env)?;
+        let mut timelock = load(&env);
+        if timelock.approved_at.contains_key(milestone_index) {
+            return Err(Error::AlreadyExists);
+        }
+        if !is_approved(&env, &project, milestone_index)? {
+            return Err(Error::MilestoneNotYetApproved);
+        }
+        let now = u64::from(env.ledger().sequence());
+        timelock.approved_at.set(milestone_index, now);
+        save(&env, &timelock);
+        Ok(now + timelock.ledgers)
+    }
+
+    /// Releases a milestone every backer has approved, skipping its
+    /// timelock. Callable by anyone.
+    pub fn fast_release(env: Env, milestone_index: u32) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        let project = Self::get_project(&env)?;
+        let (approve_weight, eligible_weight) = vote_weights(&env, &project, milestone_index)?;
+        if eligible_weight == 0 || approve_weight < eligible_weight {
+            return Err(Error::NotApproved);
+        }
+
+        let mut timelock = load(&env);
+        timelock.fast_released.set(milestone_index, true);
+        save(&env, &timelock);
+        Self::release_funds(env.clone(), projects::own_id(&env), milestone_index)?;
+
+        events::publish(
+            &env,
+            (symbol_short!("fastrel"), milestone_index),
+            approve_weight,
+        );
+        Ok(())
+    }
+
+    /// (View) Gets the timelock and when each milestone was carried.
+    pub fn get_release_timelock(env: Env) -> ReleaseTimelock {
+        load(&env)
+    }
+}
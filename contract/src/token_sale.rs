@@ -0,0 +1,196 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env};
+
+use crate::{
+    auth, backers, breaker, claims, collect, events, pay_out, ttl, Error, MilestoneFund,
+    MilestoneFundClient, Project, ProjectState, StateKey,
+};
+
+// --- Project Token Sale ---
+// A creator raising by selling their own project token lets the raise set
+// its price instead of fixing one up front. They escrow `supply` project
+// tokens and a price band before the first contribution; contributions are
+// bids, and when the campaign is finalized every backer pays one clearing
+// price: the raised amount spread over the supply, held within the band.
+// Below the floor, the tokens the raise cannot buy at the floor return to
+// the creator. Above the ceiling, the whole supply sells at the ceiling and
+// the part of the raise it did not fill goes to the refund pool, where
+// each backer claims their pro-rata share. Each backer then claims their
+// share of the sold tokens once. A failed or cancelled campaign sells
+// nothing and returns the supply to the creator.
+
+/// Prices are project-token prices in campaign-token units, scaled by this.
+pub const PRICE_SCALE: u128 = 10_000_000;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenSale {
+    pub token: Address, // Project token on sale
+    pub supply: u128,
+    pub min_price: u128,              // Scaled by `PRICE_SCALE`
+    pub max_price: u128,              // Scaled by `PRICE_SCALE`
+    pub clearing_price: Option<u128>, // Set at settlement, scaled by `PRICE_SCALE`
+    pub sold: u128,                   // Project tokens allocated to backers
+    pub unfilled: u128,               // Raise above the ceiling, refunded
+}
+
+fn load(env: &Env) -> Option<TokenSale> {
+    env.storage().instance().get(&StateKey::TokenSale)
+}
+
+fn save(env: &Env, sale: &TokenSale) {
+    env.storage().instance().set(&StateKey::TokenSale, sale);
+}
+
+/// Clearing price, project tokens sold and raise left unfilled if the sale
+/// of a successful campaign settled with `raised`.
+fn clear(sale: &TokenSale, raised: u128) -> Result<(u128, u128, u128), Error> {
+    let scaled = raised.checked_mul(PRICE_SCALE).ok_or(Error::Overflow)?;
+    let price = (scaled / sale.supply).clamp(sale.min_price, sale.max_price);
+    let sold = (scaled / price).min(sale.supply);
+    let capacity = sale.supply.checked_mul(price).ok_or(Error::Overflow)? / PRICE_SCALE;
+    let filled = raised.min(capacity);
+    Ok((price, sold, raised - filled))
+}
+
+/// Settles the sale, if there is one, as the campaign is finalized: fixes
+/// the clearing price, sends unsold tokens back to the creator and moves
+/// the unfilled raise to the refund pool. The caller stores the project.
+pub(crate) fn settle(env: &Env, project: &mut Project) -> Result<(), Error> {
+    let Some(mut sale) = load(env) else {
+        return Ok(());
+    };
+    if sale.clearing_price.is_some() {
+        return Ok(());
+    }
+    let sells = project.goal_met && project.state != ProjectState::Cancelled;
+    let (price, sold, unfilled) = if sells {
+        clear(&sale, project.raised)?
+    } else {
+        (0, 0, 0)
+    };
+    if unfilled > 0 {
+        project.surplus = project
+            .surplus
+            .checked_sub(unfilled)
+            .ok_or(Error::InsufficientFunds)?;
+        claims::credit_refund_pool(project, unfilled);
+    }
+    sale.clearing_price = Some(price);
+    sale.sold = sold;
+    sale.unfilled = unfilled;
+    save(env, &sale);
+
+    let unsold = sale.supply - sold;
+    if unsold > 0 {
+        pay_out(env, &sale.token, &project.creator, unsold)?;
+    }
+
+    events::publish(env, (symbol_short!("sale"),), (price, sold, unfilled));
+    Ok(())
+}
+
+fn allocation(env: &Env, project: &Project, sale: &TokenSale, backer: &Address) -> u128 {
+    if project.raised == 0 {
+        return 0;
+    }
+    backers::amount(env, backer) * sale.sold / project.raised
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Creator) Escrows `supply` of the project token `token` for sale at
+    /// a clearing price between `min_price` and `max_price`, both scaled by
+    /// `PRICE_SCALE`. The ceiling must let the supply fill the goal. Only
+    /// possible before the first contribution.
+    pub fn start_token_sale(
+        env: Env,
+        token: Address,
+        supply: u128,
+        min_price: u128,
+        max_price: u128,
+    ) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        let project = Self::get_project(&env)?;
+        auth::require_auth_for_amount(&env, &project.creator, supply);
+        if project.raised > 0 || load(&env).is_some() {
+            return Err(Error::TermsLocked);
+        }
+        // The ceiling must leave the milestones fully funded
+        let fills_goal = supply
+            .checked_mul(max_price)
+            .is_some_and(|ceiling| ceiling / PRICE_SCALE >= project.goal);
+        if token == project.token || min_price == 0 || min_price > max_price || !fills_goal {
+            return Err(Error::InvalidArgument);
+        }
+
+        collect(&env, &token, &project.creator, supply)?;
+        save(
+            &env,
+            &TokenSale {
+                token,
+                supply,
+                min_price,
+                max_price,
+                clearing_price: None,
+                sold: 0,
+                unfilled: 0,
+            },
+        );
+
+        events::publish(
+            &env,
+            (symbol_short!("sale"), symbol_short!("start")),
+            supply,
+        );
+        Ok(())
+    }
+
+    /// (Backer) Pays `backer` their share of the sold project tokens once
+    /// the sale has settled. Returns the tokens paid.
+    pub fn claim_sale_tokens(env: Env, backer: Address) -> Result<u128, Error> {
+        breaker::ensure_writable(&env)?;
+        backer.require_auth();
+        let project = Self::get_project(&env)?;
+        let sale = load(&env).ok_or(Error::FeatureDisabled)?;
+        if sale.clearing_price.is_none() {
+            return Err(Error::NotSettled);
+        }
+        let key = StateKey::SaleClaimed(backer.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(Error::AlreadyExists);
+        }
+        let amount = allocation(&env, &project, &sale, &backer);
+        if amount == 0 {
+            return Err(Error::NotEligible);
+        }
+
+        ttl::set_persistent(&env, &key, &amount);
+        pay_out(&env, &sale.token, &backer, amount)?;
+
+        events::publish(&env, (symbol_short!("sale"), backer), amount);
+        Ok(amount)
+    }
+
+    /// (View) Gets the project token sale, if there is one.
+    pub fn get_token_sale(env: Env) -> Option<TokenSale> {
+        load(&env)
+    }
+
+    /// (View) Gets the project tokens `backer` can claim, or, before the
+    /// sale settles, would be allocated if the campaign succeeded with
+    /// what it has raised so far.
+    pub fn get_sale_allocation(env: Env, backer: Address) -> Result<u128, Error> {
+        let project = Self::get_project(&env)?;
+        let mut sale = load(&env).ok_or(Error::FeatureDisabled)?;
+        if sale.clearing_price.is_none() {
+            (_, sale.sold, _) = clear(&sale, project.raised)?;
+        } else if env
+            .storage()
+            .persistent()
+            .has(&StateKey::SaleClaimed(backer.clone()))
+        {
+            return Ok(0);
+        }
+        Ok(allocation(&env, &project, &sale, &backer))
+    }
+}
@@ -0,0 +1,107 @@
+use soroban_sdk::{contractimpl, Address, Env, IntoVal, Val};
+
+use crate::{
+    backers, sources, votes, DataKey, Error, MilestoneFund, MilestoneFundClient, StateKey,
+    StorageKey,
+};
+
+// --- Entry Lifetimes ---
+// Entries are archived once their TTL runs out, and a campaign can run for
+// months. Every state-changing call pushes the instance (the project, its
+// settings and the contract code) out through the breaker check all of
+// them open with, and every persistent entry is written through
+// `set_persistent`, which pushes its TTL out too, with the same thresholds
+// everywhere. A campaign nobody touches for a while can be kept alive by
+// anyone: `bump_ttl` covers the instance and the campaign-wide entries,
+// `bump_backer_ttl` the entries kept for one backer.
+
+/// Ledgers (about 30 days) below which an entry's TTL is extended.
+const TTL_THRESHOLD: u32 = 518_400;
+/// Ledgers (about 90 days) an entry's TTL is extended to.
+const TTL_EXTEND_TO: u32 = 1_555_200;
+
+/// Pushes the TTL of the instance and the contract code out.
+pub(crate) fn extend_instance(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+/// Pushes the TTL of the persistent entry under `key` out.
+pub(crate) fn extend_persistent<K: IntoVal<Env, Val>>(env: &Env, key: &K) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+/// Stores `value` under `key` in persistent storage and pushes the entry's
+/// TTL out.
+pub(crate) fn set_persistent<K, V>(env: &Env, key: &K, value: &V)
+where
+    K: IntoVal<Env, Val>,
+    V: IntoVal<Env, Val>,
+{
+    env.storage().persistent().set(key, value);
+    extend_persistent(env, key);
+}
+
+/// Pushes the TTL of the persistent entry under `key` out, if there is one.
+fn extend_present<K: IntoVal<Env, Val>>(env: &Env, key: &K) {
+    if env.storage().persistent().has(key) {
+        extend_persistent(env, key);
+    }
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// Pushes the TTL of the instance, the contract code and the
+    /// campaign-wide persistent entries (the backer, voter and source
+    /// indexes, the watchers and each milestone's records) out, so a
+    /// long-running campaign stays live between calls. Callable by anyone.
+    pub fn bump_ttl(env: Env) {
+        extend_instance(&env);
+        let Ok(project) = Self::get_project(&env) else {
+            return;
+        };
+        backers::extend_index(&env);
+        sources::extend_index(&env);
+        extend_present(&env, &DataKey::Watchers);
+        extend_present(&env, &DataKey::StatsLog);
+        for index in 0..project.milestones.len() {
+            votes::extend_index(&env, index);
+            extend_present(&env, &DataKey::Evidence(index));
+            extend_present(&env, &DataKey::Stream(index));
+            extend_present(&env, &DataKey::Bounty(index));
+            extend_present(&env, &DataKey::VoteTally(index));
+            extend_present(&env, &StateKey::ReleaseMemo(index));
+            extend_present(&env, &StateKey::Payout(index));
+        }
+    }
+
+    /// Pushes the TTL of the persistent entries kept for `backer` (their
+    /// contribution, votes, claims and nonce) out. Callable by anyone.
+    pub fn bump_backer_ttl(env: Env, backer: Address) -> Result<(), Error> {
+        let project = Self::get_project(&env)?;
+        extend_instance(&env);
+        extend_present(&env, &DataKey::Backer(backer.clone()));
+        extend_present(&env, &DataKey::AuthNonce(backer.clone()));
+        extend_present(&env, &DataKey::Holding(backer.clone()));
+        extend_present(&env, &DataKey::VotesCast(backer.clone()));
+        extend_present(&env, &DataKey::PoolClaimed(backer.clone()));
+        extend_present(&env, &DataKey::RebateClaimed(backer.clone()));
+        extend_present(&env, &DataKey::RewardClaimed(backer.clone()));
+        extend_present(&env, &StorageKey::FirstSeen(backer.clone()));
+        extend_present(&env, &StorageKey::LastActive(backer.clone()));
+        extend_present(&env, &StorageKey::PersonOf(backer.clone()));
+        extend_present(&env, &StateKey::RemainderClaimed(backer.clone()));
+        extend_present(&env, &StateKey::SaleClaimed(backer.clone()));
+        extend_present(&env, &StateKey::FallbackDelegate(backer.clone()));
+        extend_present(&env, &StateKey::CoverageClaim(backer.clone()));
+        extend_present(&env, &StateKey::AnonymousContribution(backer.clone()));
+        for index in 0..project.milestones.len() {
+            extend_present(&env, &StorageKey::Vote(index, backer.clone()));
+            extend_present(&env, &StateKey::Participated(backer.clone(), index));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,343 @@
+use soroban_sdk::{
+    contractimpl, contracttype, symbol_short, Address, BytesN, Env, Map, String, Vec,
+};
+
+use crate::{
+    access, backers, breaker, escrow, events, factory, governance, lifecycle, save_project, ttl,
+    votes, DataKey, Error, Milestone, MilestoneEscrow, MilestoneFund, MilestoneFundClient, Project,
+    ProjectState, StateKey,
+};
+
+// --- Contract Upgrades ---
+// Live campaigns receive bug fixes by swapping the contract code in place.
+// The admin can upgrade on their own. Backers can also propose an upgrade
+// once the goal is met; it goes through when voters holding a two-thirds
+// supermajority of the weight raised when the proposal opened, and meeting
+// the quorum, have approved the same code hash, and the admin co-signs.
+// Weights are snapshotted when the proposal opens, so contributing while it
+// is open buys no say in it. The storage layout is
+// versioned: every project records the layout version it was written
+// with next to its data, and after new code is installed, `migrate` brings
+// the stored layout up to the version the code expects, one step at a
+// time. Until it has run, an entry whose type changed cannot be decoded,
+// so a step reads it with the type it was written with, kept under a
+// versioned name (e.g. `ProjectV0`), and writes it back in the current one.
+// Every other entrypoint refuses to run with `SchemaMismatch` in between,
+// so new code never misreads an old layout; only `upgrade` and `migrate`
+// stay open, so a mistaken upgrade can still be rolled back.
+
+/// Storage layout this code reads and writes.
+pub(crate) const STORAGE_VERSION: u32 = 1;
+
+/// Storage layout of the instance. Deployments from before the layout was
+/// versioned never recorded one and are at version 0.
+pub(crate) fn storage_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&StateKey::StorageVersion)
+        .unwrap_or(0)
+}
+
+/// Fails with `SchemaMismatch` while a project is stored in a layout
+/// other than the one this code reads, i.e. between an upgrade and the
+/// `migrate` call it needs. An instance holding no project has nothing to
+/// misread.
+pub(crate) fn ensure_current(env: &Env) -> Result<(), Error> {
+    if storage_version(env) != STORAGE_VERSION
+        && env.storage().instance().has(&DataKey::ProjectInfo)
+    {
+        return Err(Error::SchemaMismatch);
+    }
+    Ok(())
+}
+
+/// Records that the instance is laid out as this code writes it. Called
+/// wherever a project is first stored, and when a factory is set up.
+pub(crate) fn record_version(env: &Env) {
+    env.storage()
+        .instance()
+        .set(&StateKey::StorageVersion, &STORAGE_VERSION);
+}
+
+// Version 0, the layout before versioning: contributions lived in a map
+// on the project, and each milestone carried the map of its votes
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+struct MilestoneV0 {
+    title: String,
+    amount_to_release: u128,
+    is_complete: bool,
+    votes: Map<Address, bool>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+struct ProjectV0 {
+    creator: Address,
+    token: Address,
+    goal: u128,
+    raised: u128,
+    deadline: u64,
+    milestones: Vec<MilestoneV0>,
+    backers: Map<Address, u128>,
+    goal_met: bool,
+}
+
+/// Moves the version 0 backers map and milestone vote maps into their own
+/// entries and rewrites the rest of the project as its config, status and
+/// milestone sections. The escrow is rebuilt
+/// from the raised amount, with completed milestones booked as released.
+/// Version 0 had no admin and no governance settings: the project gets the
+/// default approval threshold and no quorum, and admin-only calls stay
+/// unavailable.
+fn migrate_v0(env: &Env) -> Result<(), Error> {
+    let legacy: ProjectV0 = env
+        .storage()
+        .instance()
+        .get(&DataKey::ProjectInfo)
+        .ok_or(Error::ProjectNotInitialized)?;
+    for (backer, amount) in legacy.backers.iter() {
+        backers::set(env, &backer, amount);
+    }
+
+    let mut milestones = Vec::new(env);
+    for milestone in legacy.milestones.iter() {
+        milestones.push_back(Milestone {
+            title: milestone.title,
+            amount_to_release: milestone.amount_to_release,
+            is_complete: milestone.is_complete,
+            is_rejected: false,
+            is_failed: false,
+            escrow: MilestoneEscrow::default(),
+            downscope_reason: None,
+        });
+    }
+    let mut project = Project {
+        creator: legacy.creator,
+        token: legacy.token,
+        goal: legacy.goal,
+        raised: legacy.raised,
+        deadline: legacy.deadline,
+        start_ledger: 0,
+        milestones,
+        goal_met: legacy.goal_met,
+        surplus: 0,
+        refund_pool: 0,
+        refund_pool_claimed: 0,
+        state: ProjectState::Funding,
+        approval_threshold_bps: governance::DEFAULT_APPROVAL_THRESHOLD_BPS,
+        quorum_bps: 0,
+    };
+    escrow::earmark(&mut project, legacy.raised)?;
+
+    for index in 0..legacy.milestones.len() {
+        let legacy_milestone = legacy.milestones.get_unchecked(index);
+        for (backer, approve) in legacy_milestone.votes.iter() {
+            votes::cast(env, index, &backer, approve);
+        }
+        let mut milestone = project.milestones.get_unchecked(index);
+        if milestone.is_complete {
+            milestone.escrow.released = milestone.escrow.allocated;
+            project.milestones.set(index, milestone);
+        }
+    }
+    lifecycle::advance(env, &mut project);
+    // The config section takes over the legacy entry's key
+    env.storage().instance().remove(&DataKey::ProjectInfo);
+    save_project(env, &project);
+    Ok(())
+}
+
+/// Rewrites the layout of version `from` as version `from + 1`. Every
+/// layout change adds its step here, matched on `from`.
+fn step(env: &Env, from: u32) -> Result<(), Error> {
+    match from {
+        0 => migrate_v0(env),
+        _ => Err(Error::InvalidArgument),
+    }
+}
+
+/// Ledgers (about 7 days) an upgrade proposal stays open for votes.
+const PROPOSAL_LEDGERS: u64 = 120_960;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeProposal {
+    pub wasm_hash: BytesN<32>,
+    pub round: u32,     // Increases with every proposal; votes are booked per round
+    pub opened_at: u64, // Ledger the first vote opened it
+    pub raised: u128,   // Raised weight when it opened, the base of its thresholds
+    pub approve_weight: u128,
+    pub executed: bool,
+}
+
+impl UpgradeProposal {
+    fn is_open(&self, now: u64) -> bool {
+        !self.executed && now < self.opened_at + PROPOSAL_LEDGERS
+    }
+}
+
+fn load_proposal(env: &Env) -> Option<UpgradeProposal> {
+    env.storage().instance().get(&StateKey::UpgradeProposal)
+}
+
+/// The open upgrade proposal, if any.
+fn open_proposal(env: &Env) -> Option<UpgradeProposal> {
+    let now = u64::from(env.ledger().sequence());
+    load_proposal(env).filter(|proposal| proposal.is_open(now))
+}
+
+/// Snapshots `backer`'s weight for the open upgrade proposal before their
+/// contribution changes, so every vote on it counts the weight held when it
+/// opened. Called before every write to the backer ledger; only the first
+/// change per proposal is kept.
+pub(crate) fn snapshot_weight(env: &Env, backer: &Address, amount: u128) {
+    let Some(proposal) = open_proposal(env) else {
+        return;
+    };
+    let key = StateKey::UpgradeWeight(backer.clone());
+    let snapshot: Option<(u32, u128)> = env.storage().persistent().get(&key);
+    if snapshot.is_some_and(|(round, _)| round == proposal.round) {
+        return;
+    }
+    ttl::set_persistent(env, &key, &(proposal.round, amount));
+}
+
+/// `backer`'s weight when `proposal` opened.
+fn weight_at_open(env: &Env, backer: &Address, proposal: &UpgradeProposal) -> u128 {
+    let snapshot: Option<(u32, u128)> = env
+        .storage()
+        .persistent()
+        .get(&StateKey::UpgradeWeight(backer.clone()));
+    match snapshot {
+        Some((round, amount)) if round == proposal.round => amount,
+        _ => backers::amount(env, backer),
+    }
+}
+
+fn install(env: &Env, wasm_hash: BytesN<32>) {
+    env.deployer()
+        .update_current_contract_wasm(wasm_hash.clone());
+    events::publish(env, (symbol_short!("upgrade"),), wasm_hash);
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Admin) Replaces the contract code with the uploaded `new_wasm_hash`.
+    /// Call `migrate` afterwards. Stays open while the stored layout does
+    /// not match, so a mistaken upgrade can be rolled back.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        breaker::ensure_live(&env)?;
+        Self::get_admin(env.clone())?.require_auth();
+        install(&env, new_wasm_hash);
+        Ok(())
+    }
+
+    /// (Backer) Approves upgrading the contract code to `new_wasm_hash`,
+    /// opening a proposal for it if none is open. Only possible once the
+    /// goal is met; each vote counts the backer's weight when the proposal
+    /// opened, and a proposal stays open for about 7 days.
+    pub fn vote_upgrade(env: Env, backer: Address, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        backer.require_auth();
+        let project = Self::get_project(&env)?;
+        if !project.goal_met {
+            return Err(Error::GoalNotMet);
+        }
+        if !backers::is_backer(&env, &backer) {
+            return Err(Error::NotABacker);
+        }
+        let mut proposal = match open_proposal(&env) {
+            Some(proposal) => proposal,
+            None => UpgradeProposal {
+                wasm_hash: new_wasm_hash.clone(),
+                round: load_proposal(&env).map_or(0, |previous| previous.round + 1),
+                opened_at: u64::from(env.ledger().sequence()),
+                raised: project.raised,
+                approve_weight: 0,
+                executed: false,
+            },
+        };
+        if proposal.wasm_hash != new_wasm_hash {
+            return Err(Error::InvalidArgument);
+        }
+        let vote_key = StateKey::UpgradeVote(backer.clone());
+        if env.storage().persistent().get(&vote_key) == Some(proposal.round) {
+            return Err(Error::AlreadyVoted);
+        }
+        let weight = weight_at_open(&env, &backer, &proposal);
+        if weight == 0 {
+            return Err(Error::NotEligible); // Joined after the proposal opened
+        }
+
+        proposal.approve_weight = proposal
+            .approve_weight
+            .checked_add(weight)
+            .ok_or(Error::Overflow)?;
+        ttl::set_persistent(&env, &vote_key, &proposal.round);
+        env.storage()
+            .instance()
+            .set(&StateKey::UpgradeProposal, &proposal);
+
+        events::publish(&env, (symbol_short!("upg_vote"), backer), new_wasm_hash);
+        Ok(())
+    }
+
+    /// (Admin) Replaces the contract code with `new_wasm_hash` once backers
+    /// holding a supermajority of the weight raised when the proposal opened
+    /// have approved it, and their turnout meets the quorum. The admin
+    /// co-signs, so backers alone cannot swap the code.
+    pub fn execute_upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        access::require_admin(&env)?;
+        let project = Self::get_project(&env)?;
+        if !project.goal_met {
+            return Err(Error::GoalNotMet);
+        }
+        let mut proposal = open_proposal(&env)
+            .filter(|proposal| proposal.wasm_hash == new_wasm_hash)
+            .ok_or(Error::NotFound)?;
+        if !governance::meets_quorum(&project, proposal.approve_weight, proposal.raised)?
+            || !governance::clears_supermajority(proposal.approve_weight, proposal.raised)?
+        {
+            return Err(Error::NotApproved);
+        }
+        proposal.executed = true;
+        env.storage()
+            .instance()
+            .set(&StateKey::UpgradeProposal, &proposal);
+        install(&env, new_wasm_hash);
+        Ok(())
+    }
+
+    /// (View) Gets the latest upgrade proposal, if any was opened.
+    pub fn get_upgrade_proposal(env: Env) -> Option<UpgradeProposal> {
+        load_proposal(&env)
+    }
+
+    /// Brings the stored layout up to the version this code expects.
+    /// Callable by anyone after an upgrade, which leaves every other
+    /// entrypoint failing with `SchemaMismatch` until it has run. Returns
+    /// the version reached.
+    pub fn migrate(env: Env) -> Result<u32, Error> {
+        breaker::ensure_live(&env)?;
+        let from = storage_version(&env);
+        if from > STORAGE_VERSION {
+            return Err(Error::SchemaMismatch);
+        }
+        // A factory hosts no project, so it has no project layout to migrate
+        if !factory::is_factory(&env) {
+            for version in from..STORAGE_VERSION {
+                step(&env, version)?;
+            }
+        }
+        record_version(&env);
+
+        events::publish(&env, (symbol_short!("migrated"),), (from, STORAGE_VERSION));
+        Ok(STORAGE_VERSION)
+    }
+
+    /// (View) Gets the version of the stored layout.
+    pub fn get_storage_version(env: Env) -> u32 {
+        storage_version(&env)
+    }
+}
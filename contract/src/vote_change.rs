@@ -0,0 +1,83 @@
+use soroban_sdk::{contractimpl, symbol_short, Address, Env};
+
+use crate::{
+    breaker, emergency, freeze, rebates, receipts, rejection, timelock, votes, voting_deadline,
+    Error, MilestoneFund, MilestoneFundClient, Project,
+};
+
+// --- Changing Votes ---
+// A vote is not final until its milestone is settled: backers who learn
+// something new from the creator can switch sides with `change_vote` or
+// withdraw with `revoke_vote`. Either moves the backer's weight in the
+// running tallies, restarts the release timelock if the milestone is no
+// longer carried, and a switch to a rejection can reject the milestone.
+// A withdrawn vote also stops counting towards the vote rebate.
+
+/// `backer`'s current vote on a milestone that is still open to voting.
+fn current_vote(
+    env: &Env,
+    project: &Project,
+    backer: &Address,
+    milestone_index: u32,
+) -> Result<bool, Error> {
+    emergency::ensure_not_paused(env)?;
+    if !project.goal_met {
+        return Err(Error::GoalNotMet);
+    }
+    let milestone = project
+        .milestones
+        .get(milestone_index)
+        .ok_or(Error::MilestoneInvalidIndex)?;
+    if milestone.is_rejected {
+        return Err(Error::MilestoneRejected);
+    }
+    if milestone.is_complete {
+        return Err(Error::MilestoneAlreadyCompleted);
+    }
+    freeze::ensure_not_frozen(env, milestone_index)?;
+    voting_deadline::ensure_open(env, milestone_index)?;
+    votes::get(env, milestone_index, backer).ok_or(Error::NotFound)
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Backer) Changes the backer's vote on a milestone to `approve`.
+    pub fn change_vote(
+        env: Env,
+        backer: Address,
+        milestone_index: u32,
+        approve: bool,
+    ) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        backer.require_auth();
+        let project = Self::get_project(&env)?;
+        if current_vote(&env, &project, &backer, milestone_index)? == approve {
+            return Err(Error::AlreadyVoted);
+        }
+
+        votes::change(&env, milestone_index, &backer, approve);
+        let action = symbol_short!("revote");
+        receipts::publish(&env, action, &project, milestone_index, &backer, approve)?;
+        timelock::note_vote(&env, &project, milestone_index)?;
+        if !approve {
+            rejection::reject_if_carried(&env, milestone_index)?;
+        }
+        Ok(())
+    }
+
+    /// (Backer) Withdraws the backer's vote on a milestone, so they can
+    /// vote afresh later.
+    pub fn revoke_vote(env: Env, backer: Address, milestone_index: u32) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        backer.require_auth();
+        let project = Self::get_project(&env)?;
+        let approve = current_vote(&env, &project, &backer, milestone_index)?;
+
+        votes::revoke(&env, milestone_index, &backer);
+        rebates::forget_participation(&env, &backer, milestone_index);
+        let action = symbol_short!("unvote");
+        receipts::publish(&env, action, &project, milestone_index, &backer, approve)?;
+        timelock::note_vote(&env, &project, milestone_index)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,273 @@
+use soroban_sdk::{contractimpl, Address, Env, Map, Vec};
+
+use crate::{backers, ttl, Error, MilestoneFund, MilestoneFundClient, StorageKey};
+
+// --- Milestone Votes ---
+// Votes are stored per (milestone, backer) in persistent storage, so
+// casting one costs the same however many backers have voted already.
+// Like the backer ledger, each milestone keeps a paged index of its voters
+// for tallies, and pruning a released milestone pops voters off its end.
+// The contribution weight of the approving and of the rejecting votes is
+// kept as a running total, so the common case of a release or a rejection
+// needs no walk over the voters.
+
+/// Voters per index page.
+const PAGE_SIZE: u32 = 100;
+
+fn page(env: &Env, milestone_index: u32, index: u32) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::VoterPage(milestone_index, index))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn save_page(env: &Env, milestone_index: u32, index: u32, page: &Vec<Address>) {
+    let key = StorageKey::VoterPage(milestone_index, index);
+    if page.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        ttl::set_persistent(env, &key, page);
+    }
+}
+
+/// `backer`'s vote on a milestone, if they cast one.
+pub(crate) fn get(env: &Env, milestone_index: u32, backer: &Address) -> Option<bool> {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::Vote(milestone_index, backer.clone()))
+}
+
+pub(crate) fn has_voted(env: &Env, milestone_index: u32, backer: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&StorageKey::Vote(milestone_index, backer.clone()))
+}
+
+/// Number of votes cast on a milestone.
+pub(crate) fn count(env: &Env, milestone_index: u32) -> u32 {
+    env.storage()
+        .instance()
+        .get(&StorageKey::VoteCount(milestone_index))
+        .unwrap_or(0)
+}
+
+fn set_count(env: &Env, milestone_index: u32, count: u32) {
+    env.storage()
+        .instance()
+        .set(&StorageKey::VoteCount(milestone_index), &count);
+}
+
+/// Contribution weight of the approving votes cast on a milestone.
+pub(crate) fn approved_weight(env: &Env, milestone_index: u32) -> u128 {
+    env.storage()
+        .instance()
+        .get(&StorageKey::ApprovedWeight(milestone_index))
+        .unwrap_or(0)
+}
+
+fn set_approved_weight(env: &Env, milestone_index: u32, weight: u128) {
+    env.storage()
+        .instance()
+        .set(&StorageKey::ApprovedWeight(milestone_index), &weight);
+}
+
+/// Contribution weight of the rejecting votes cast on a milestone.
+pub(crate) fn rejected_weight(env: &Env, milestone_index: u32) -> u128 {
+    env.storage()
+        .instance()
+        .get(&StorageKey::RejectedWeight(milestone_index))
+        .unwrap_or(0)
+}
+
+fn set_rejected_weight(env: &Env, milestone_index: u32, weight: u128) {
+    env.storage()
+        .instance()
+        .set(&StorageKey::RejectedWeight(milestone_index), &weight);
+}
+
+/// Records `backer`'s first vote on a milestone.
+pub(crate) fn cast(env: &Env, milestone_index: u32, backer: &Address, approve: bool) {
+    if approve {
+        let weight = approved_weight(env, milestone_index) + backers::amount(env, backer);
+        set_approved_weight(env, milestone_index, weight);
+    } else {
+        let weight = rejected_weight(env, milestone_index) + backers::amount(env, backer);
+        set_rejected_weight(env, milestone_index, weight);
+    }
+    let count = count(env, milestone_index);
+    let mut last = page(env, milestone_index, count / PAGE_SIZE);
+    last.push_back(backer.clone());
+    save_page(env, milestone_index, count / PAGE_SIZE, &last);
+    set_count(env, milestone_index, count + 1);
+    let key = StorageKey::Vote(milestone_index, backer.clone());
+    ttl::set_persistent(env, &key, &approve);
+}
+
+/// Changes `backer`'s vote on a milestone to `approve`, moving its weight
+/// between the running totals.
+pub(crate) fn change(env: &Env, milestone_index: u32, backer: &Address, approve: bool) {
+    let weight = backers::amount(env, backer);
+    if approve {
+        set_rejected_weight(
+            env,
+            milestone_index,
+            rejected_weight(env, milestone_index).saturating_sub(weight),
+        );
+        set_approved_weight(
+            env,
+            milestone_index,
+            approved_weight(env, milestone_index) + weight,
+        );
+    } else {
+        set_approved_weight(
+            env,
+            milestone_index,
+            approved_weight(env, milestone_index).saturating_sub(weight),
+        );
+        set_rejected_weight(
+            env,
+            milestone_index,
+            rejected_weight(env, milestone_index) + weight,
+        );
+    }
+    let key = StorageKey::Vote(milestone_index, backer.clone());
+    ttl::set_persistent(env, &key, &approve);
+}
+
+/// Withdraws `backer`'s vote on a milestone. The milestone's last voter
+/// takes the freed slot in the index, so the pages stay packed.
+pub(crate) fn revoke(env: &Env, milestone_index: u32, backer: &Address) {
+    let Some(approve) = get(env, milestone_index, backer) else {
+        return;
+    };
+    let weight = backers::amount(env, backer);
+    if approve {
+        set_approved_weight(
+            env,
+            milestone_index,
+            approved_weight(env, milestone_index).saturating_sub(weight),
+        );
+    } else {
+        set_rejected_weight(
+            env,
+            milestone_index,
+            rejected_weight(env, milestone_index).saturating_sub(weight),
+        );
+    }
+    env.storage()
+        .persistent()
+        .remove(&StorageKey::Vote(milestone_index, backer.clone()));
+
+    let count = count(env, milestone_index);
+    let last_index = (count - 1) / PAGE_SIZE;
+    let mut last = page(env, milestone_index, last_index);
+    let moved = last.pop_back_unchecked();
+    save_page(env, milestone_index, last_index, &last);
+    set_count(env, milestone_index, count - 1);
+    if moved == *backer {
+        return;
+    }
+    for index in 0..last_index + 1 {
+        let mut voters = page(env, milestone_index, index);
+        if let Some(slot) = voters.first_index_of(backer) {
+            voters.set(slot, moved);
+            save_page(env, milestone_index, index, &voters);
+            return;
+        }
+    }
+}
+
+/// Pushes the TTL of a milestone's voter index pages out.
+pub(crate) fn extend_index(env: &Env, milestone_index: u32) {
+    for index in 0..count(env, milestone_index).div_ceil(PAGE_SIZE) {
+        ttl::extend_persistent(env, &StorageKey::VoterPage(milestone_index, index));
+    }
+}
+
+/// Every vote cast on a milestone.
+pub(crate) fn all(env: &Env, milestone_index: u32) -> Map<Address, bool> {
+    let mut votes = Map::new(env);
+    for index in 0..count(env, milestone_index).div_ceil(PAGE_SIZE) {
+        for voter in page(env, milestone_index, index).iter() {
+            if let Some(approve) = get(env, milestone_index, &voter) {
+                votes.set(voter, approve);
+            }
+        }
+    }
+    votes
+}
+
+/// Voters on a milestone at positions `start..end` of its index, reading
+/// only the pages that hold them.
+pub(crate) fn voters(env: &Env, milestone_index: u32, start: u32, end: u32) -> Vec<Address> {
+    let mut voters = Vec::new(env);
+    let mut position = start;
+    while position < end {
+        let addresses = page(env, milestone_index, position / PAGE_SIZE);
+        let offset = position % PAGE_SIZE;
+        let taken = (end - position).min(PAGE_SIZE - offset);
+        voters.append(&addresses.slice(offset..offset + taken));
+        position += taken;
+    }
+    voters
+}
+
+/// Removes up to `batch` of a milestone's votes, latest first. Returns how
+/// many were removed.
+pub(crate) fn remove(env: &Env, milestone_index: u32, batch: u32) -> Result<u32, Error> {
+    let mut count = count(env, milestone_index);
+    let mut weight = approved_weight(env, milestone_index);
+    let mut rejected = rejected_weight(env, milestone_index);
+    let mut removed = 0;
+    while removed < batch && count > 0 {
+        let index = (count - 1) / PAGE_SIZE;
+        let mut last = page(env, milestone_index, index);
+        while removed < batch && !last.is_empty() {
+            let voter = last.pop_back_unchecked();
+            match get(env, milestone_index, &voter) {
+                Some(true) => {
+                    weight = weight
+                        .checked_sub(backers::amount(env, &voter))
+                        .ok_or(Error::Overflow)?
+                }
+                Some(false) => {
+                    rejected = rejected
+                        .checked_sub(backers::amount(env, &voter))
+                        .ok_or(Error::Overflow)?
+                }
+                None => {}
+            }
+            env.storage()
+                .persistent()
+                .remove(&StorageKey::Vote(milestone_index, voter));
+            removed += 1;
+            count -= 1;
+        }
+        save_page(env, milestone_index, index, &last);
+    }
+    set_count(env, milestone_index, count);
+    set_approved_weight(env, milestone_index, weight);
+    set_rejected_weight(env, milestone_index, rejected);
+    Ok(removed)
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (View) Gets the contribution weight of the approving votes cast on a
+    /// milestone, before delegation, weighting and inactivity are applied.
+    pub fn get_approved_weight(env: Env, milestone_index: u32) -> Result<u128, Error> {
+        if milestone_index >= Self::get_project(&env)?.milestones.len() {
+            return Err(Error::MilestoneInvalidIndex);
+        }
+        Ok(approved_weight(&env, milestone_index))
+    }
+
+    /// (View) Gets the contribution weight of the rejecting votes cast on
+    /// a milestone, before delegation, weighting and inactivity are applied.
+    pub fn get_rejected_weight(env: Env, milestone_index: u32) -> Result<u128, Error> {
+        if milestone_index >= Self::get_project(&env)?.milestones.len() {
+            return Err(Error::MilestoneInvalidIndex);
+        }
+        Ok(rejected_weight(&env, milestone_index))
+    }
+}
@@ -0,0 +1,101 @@
+use soroban_sdk::{contractimpl, symbol_short, Env};
+
+use crate::{
+    access, breaker, claims, events, is_approved, lifecycle, optimistic, save_project, stream,
+    watch, Error, MilestoneFund, MilestoneFundClient, StorageKey,
+};
+
+// --- Milestone Voting Deadlines ---
+// Without a deadline a milestone that never gathers approval holds its
+// escrow forever. The creator can give any milestone a ledger by which it
+// must be approved, set while the campaign is still raising like the
+// evidence deadlines. Votes on the milestone close at that ledger; if it
+// has not cleared the threshold and quorum by then, anyone can call
+// `expire_milestone`: it is marked failed and settled, and what its escrow
+// still holds goes to the refund pool. A milestone approved in time can
+// still be released afterwards.
+
+fn deadline(env: &Env, milestone_index: u32) -> Option<u64> {
+    env.storage()
+        .instance()
+        .get(&StorageKey::VotingDeadline(milestone_index))
+}
+
+fn is_past(env: &Env, milestone_index: u32) -> bool {
+    deadline(env, milestone_index).is_some_and(|at| u64::from(env.ledger().sequence()) > at)
+}
+
+/// Fails with `VotingClosed` once a milestone's voting deadline has passed.
+pub(crate) fn ensure_open(env: &Env, milestone_index: u32) -> Result<(), Error> {
+    if is_past(env, milestone_index) {
+        return Err(Error::VotingClosed);
+    }
+    Ok(())
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Creator) Sets the ledger by which a milestone must be approved.
+    /// Only possible while the campaign is still raising.
+    pub fn set_voting_deadline(env: Env, milestone_index: u32, deadline: u64) -> Result<(), Error> {
+        let project = access::require_creator(&env)?;
+        if project.goal_met {
+            return Err(Error::TermsLocked);
+        }
+        if project.milestones.get(milestone_index).is_none() {
+            return Err(Error::MilestoneInvalidIndex);
+        }
+        if deadline <= project.deadline {
+            return Err(Error::InvalidArgument);
+        }
+        env.storage()
+            .instance()
+            .set(&StorageKey::VotingDeadline(milestone_index), &deadline);
+        Ok(())
+    }
+
+    /// Fails a milestone that was not approved by its voting deadline,
+    /// moving what its escrow holds to the refund pool. Callable by anyone.
+    /// Returns the amount moved.
+    pub fn expire_milestone(env: Env, milestone_index: u32) -> Result<u128, Error> {
+        breaker::ensure_writable(&env)?;
+        let mut project = Self::get_project(&env)?;
+        let mut milestone = project
+            .milestones
+            .get(milestone_index)
+            .ok_or(Error::MilestoneInvalidIndex)?;
+        if milestone.is_complete {
+            return Err(Error::MilestoneAlreadyCompleted);
+        }
+        if stream::is_streaming(&env, milestone_index) {
+            return Err(Error::MilestoneStreaming);
+        }
+        if !is_past(&env, milestone_index) {
+            return Err(Error::NotDue);
+        }
+        // A failed campaign refunds in full through `claim_refund` instead
+        if !project.goal_met {
+            return Err(Error::GoalNotMet);
+        }
+        if is_approved(&env, &project, milestone_index)?
+            || optimistic::is_unchallenged(&env, &project, milestone_index)?
+        {
+            return Err(Error::NotEligible);
+        }
+
+        let remainder = claims::refund_milestone(&mut project, &mut milestone)?;
+        milestone.is_failed = true;
+        project.milestones.set(milestone_index, milestone);
+        lifecycle::advance(&env, &mut project);
+        save_project(&env, &project);
+
+        events::publish(&env, (symbol_short!("expire"), milestone_index), remainder);
+        watch::publish_transition(&env, symbol_short!("expired"), milestone_index);
+        Ok(remainder)
+    }
+
+    /// (View) Gets the ledger by which a milestone must be approved, if set.
+    pub fn get_voting_deadline(env: Env, milestone_index: u32) -> Option<u64> {
+        deadline(&env, milestone_index)
+    }
+}
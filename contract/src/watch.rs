@@ -0,0 +1,89 @@
+use soroban_sdk::{contractimpl, symbol_short, Address, Env, IntoVal, Map, Symbol, Val, Vec};
+
+use crate::{breaker, events, limits, ttl, DataKey, Error, MilestoneFund, MilestoneFundClient};
+
+// --- Watch Registry ---
+// Any address can bookmark the project. The watcher set lives in the
+// contract so notification services can fan out `watchable` events to
+// subscribers without keeping their own subscription store.
+
+pub(crate) fn load_watchers(env: &Env) -> Map<Address, bool> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Watchers)
+        .unwrap_or(Map::new(env))
+}
+
+fn save_watchers(env: &Env, watchers: &Map<Address, bool>) {
+    ttl::set_persistent(env, &DataKey::Watchers, watchers);
+}
+
+pub(crate) fn watcher_count(env: &Env) -> u32 {
+    load_watchers(env).len()
+}
+
+/// Publishes a project state transition tagged as watchable.
+///
+/// Topics are `("watchable", transition)` so subscribers can filter on the
+/// first topic alone and look up recipients via `get_watchers`.
+pub(crate) fn publish_transition<D: IntoVal<Env, Val>>(env: &Env, transition: Symbol, data: D) {
+    let topics = (symbol_short!("watchable"), transition);
+    events::publish(env, topics, data);
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// Registers `watcher` to receive notifications about this project.
+    pub fn watch(env: Env, watcher: Address) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        watcher.require_auth();
+        Self::get_project(&env)?;
+
+        let mut watchers = load_watchers(&env);
+        if watchers.contains_key(watcher.clone()) {
+            return Err(Error::AlreadyExists);
+        }
+        limits::ensure_room(
+            &env,
+            symbol_short!("watchers"),
+            watchers.len(),
+            limits::load(&env).max_watchers,
+        )?;
+        watchers.set(watcher.clone(), true);
+        save_watchers(&env, &watchers);
+
+        events::publish(&env, (symbol_short!("watch"), watcher), watchers.len());
+        Ok(())
+    }
+
+    /// Removes `watcher` from the project's watcher set.
+    pub fn unwatch(env: Env, watcher: Address) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        watcher.require_auth();
+
+        let mut watchers = load_watchers(&env);
+        if !watchers.contains_key(watcher.clone()) {
+            return Err(Error::NotFound);
+        }
+        watchers.remove(watcher.clone());
+        save_watchers(&env, &watchers);
+
+        events::publish(&env, (symbol_short!("unwatch"), watcher), watchers.len());
+        Ok(())
+    }
+
+    /// (View) Checks whether `watcher` is watching the project.
+    pub fn is_watching(env: Env, watcher: Address) -> bool {
+        load_watchers(&env).contains_key(watcher)
+    }
+
+    /// (View) Lists up to `limit` watchers starting at position `start`.
+    pub fn get_watchers(env: Env, start: u32, limit: u32) -> Vec<Address> {
+        let keys = load_watchers(&env).keys();
+        let end = start.saturating_add(limit).min(keys.len());
+        if start >= end {
+            return Vec::new(&env);
+        }
+        keys.slice(start..end)
+    }
+}
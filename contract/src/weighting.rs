@@ -0,0 +1,147 @@
+use soroban_sdk::{contractimpl, contracttype, Address, Env, Map};
+
+use crate::{access, backers, ttl, DataKey, Error, MilestoneFund, MilestoneFundClient, Project};
+
+// --- Vote Weighting ---
+// How much a backer's milestone vote counts. Every mode is computed here so
+// the tally code stays the same whatever the campaign picked:
+//
+// - `Contribution`: one unit of weight per unit contributed.
+// - `TimeWeighted`: the square root of amount x ledgers held, summed over
+//   each contribution from the ledger it was made. Early, patient backers
+//   count for more than a late whale, but a whale still outweighs a minnow.
+//
+// The mode can only be changed while the campaign is still raising.
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WeightingMode {
+    Contribution,
+    TimeWeighted,
+}
+
+// A backer's contributions folded into one accumulator: `accrued` is the
+// sum of amount x ledgers held up to `updated_at`
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Holding {
+    pub amount: u128,
+    pub accrued: u128,
+    pub updated_at: u64,
+}
+
+impl Holding {
+    /// Sum of amount x ledgers held over every contribution, as of `now`.
+    fn amount_ledgers(&self, now: u64) -> u128 {
+        let held = u128::from(now.saturating_sub(self.updated_at));
+        self.accrued
+            .saturating_add(self.amount.saturating_mul(held))
+    }
+}
+
+pub(crate) fn mode(env: &Env) -> WeightingMode {
+    env.storage()
+        .instance()
+        .get(&DataKey::WeightingMode)
+        .unwrap_or(WeightingMode::Contribution)
+}
+
+fn load_holding(env: &Env, backer: &Address) -> Holding {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Holding(backer.clone()))
+        .unwrap_or_default()
+}
+
+/// Adds a contribution of `amount` made now to `backer`'s holding.
+pub(crate) fn record(env: &Env, backer: &Address, amount: u128) {
+    let now = u64::from(env.ledger().sequence());
+    let mut holding = load_holding(env, backer);
+    holding.accrued = holding.amount_ledgers(now);
+    holding.amount += amount;
+    holding.updated_at = now;
+    ttl::set_persistent(env, &DataKey::Holding(backer.clone()), &holding);
+}
+
+/// Takes `amount` withdrawn now out of `backer`'s holding, shrinking the
+/// accrued amount x ledgers in proportion.
+pub(crate) fn withdraw(env: &Env, backer: &Address, amount: u128) {
+    let now = u64::from(env.ledger().sequence());
+    let mut holding = load_holding(env, backer);
+    if holding.amount == 0 {
+        return;
+    }
+    let remaining = holding.amount.saturating_sub(amount);
+    holding.accrued = holding.amount_ledgers(now) / holding.amount * remaining;
+    holding.amount = remaining;
+    holding.updated_at = now;
+    ttl::set_persistent(env, &DataKey::Holding(backer.clone()), &holding);
+}
+
+fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    let mut x = n;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+/// Vote weight of `backer` under the campaign's mode.
+pub(crate) fn weight(env: &Env, backer: &Address) -> u128 {
+    match mode(env) {
+        WeightingMode::Contribution => backers::amount(env, backer),
+        WeightingMode::TimeWeighted => {
+            let now = u64::from(env.ledger().sequence());
+            isqrt(load_holding(env, backer).amount_ledgers(now))
+        }
+    }
+}
+
+/// Weight of the `true` votes in `votes` and the weight of every backer.
+pub(crate) fn tally(env: &Env, project: &Project, votes: &Map<Address, bool>) -> (u128, u128) {
+    if mode(env) == WeightingMode::Contribution {
+        let mut yes: u128 = 0;
+        for (backer, approve) in votes.iter() {
+            if approve {
+                yes += backers::amount(env, &backer);
+            }
+        }
+        return (yes, project.raised);
+    }
+
+    let mut yes: u128 = 0;
+    let mut total: u128 = 0;
+    for backer in backers::all(env).iter() {
+        let weight = weight(env, &backer);
+        if votes.get(backer).unwrap_or(false) {
+            yes += weight;
+        }
+        total += weight;
+    }
+    (yes, total)
+}
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Creator) Sets how milestone votes are weighted. Only possible while
+    /// the campaign is still raising.
+    pub fn set_weighting_mode(env: Env, mode: WeightingMode) -> Result<(), Error> {
+        let project = access::require_creator(&env)?;
+        if project.goal_met {
+            return Err(Error::TermsLocked);
+        }
+        env.storage().instance().set(&DataKey::WeightingMode, &mode);
+        Ok(())
+    }
+
+    /// (View) Gets the current vote weight of `backer`.
+    pub fn get_vote_weight(env: Env, backer: Address) -> Result<u128, Error> {
+        Self::get_project(&env)?;
+        Ok(weight(&env, &backer))
+    }
+}
@@ -0,0 +1,47 @@
+use soroban_sdk::{contractimpl, symbol_short, Address, Env};
+
+use crate::{
+    backers, breaker, escrow, events, lifecycle, pay_out, personhood, platform, save_project,
+    weighting, Error, MilestoneFund, MilestoneFundClient, ProjectState,
+};
+
+// --- Pledge Withdrawal ---
+// A pledge only binds once the goal is hit. Until then a backer can take
+// part or all of it back: the tokens come out of the earmarks they went
+// into and `raised` drops, so the goal has to be met by pledges that stay.
+
+#[contractimpl]
+impl MilestoneFund {
+    /// (Backer) Takes `amount` of the backer's pledge back while the
+    /// campaign is still raising. Withdrawing all of it leaves the backer
+    /// on the books with nothing pledged, as a refund does.
+    pub fn withdraw(env: Env, backer: Address, amount: u128) -> Result<(), Error> {
+        breaker::ensure_writable(&env)?;
+        backer.require_auth();
+        let mut project = Self::get_project(&env)?;
+        lifecycle::advance(&env, &mut project);
+        if project.state != ProjectState::Funding {
+            return Err(Error::FundingIsClosed);
+        }
+        let pledged = backers::contribution(&env, &backer).ok_or(Error::NotABacker)?;
+        if amount == 0 || amount > pledged {
+            return Err(Error::InvalidArgument);
+        }
+        if breaker::trip_on_divergence(&env, &project)? {
+            return Ok(());
+        }
+
+        backers::set(&env, &backer, pledged - amount);
+        weighting::withdraw(&env, &backer, amount);
+        personhood::release(&env, &backer, amount);
+        project.raised -= amount;
+        escrow::unearmark(&mut project, amount)?;
+        save_project(&env, &project);
+
+        pay_out(&env, &project.token, &backer, amount)?;
+        platform::report(&env, &project);
+
+        events::publish(&env, (symbol_short!("withdraw"), backer), amount);
+        Ok(())
+    }
+}